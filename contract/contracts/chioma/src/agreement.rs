@@ -1,14 +1,90 @@
 //! Agreement management logic for the Chioma/Rental contract.
-use soroban_sdk::{Address, Env, String, Vec};
+use soroban_sdk::{symbol_short, vec, Address, Env, IntoVal, String, Symbol, Vec};
 
-use crate::errors::RentalError;
+use crate::audit;
+use crate::errors::{LedgerError, RentalError};
 use crate::events;
 use crate::rate_limit;
-use crate::storage::DataKey;
-use crate::types::{AgreementStatus, PaymentSplit, RentAgreement};
+use crate::storage::{DataKey, IndexKey};
+use crate::types::{AgreementStatus, PaymentSplit, PropertyDetails, RentAgreement};
 
 const TTL_THRESHOLD: u32 = 500000;
 const TTL_BUMP: u32 = 500000;
+const MAX_TOKEN_DECIMALS: u32 = 18;
+/// Categories an agreement may be filed under.
+const CATEGORIES: [&str; 3] = ["residential", "commercial", "short_term"];
+/// Attribute trait naming an agreement's category at creation.
+const CATEGORY_TRAIT: &str = "category";
+/// Category of agreements created without a category attribute.
+const DEFAULT_CATEGORY: &str = "residential";
+/// Most agreement ids accepted by a single batch lookup.
+pub(crate) const MAX_BATCH_SIZE: u32 = 50;
+/// Length of one rent period (30 days) in seconds.
+pub(crate) const RENT_PERIOD_SECONDS: u64 = 30 * 86_400;
+
+/// Whether the agreement lifecycle allows a move from `from` to `to`.
+pub(crate) fn can_transition(from: AgreementStatus, to: AgreementStatus) -> bool {
+    use AgreementStatus::*;
+    matches!(
+        (from, to),
+        (Draft, Pending)
+            | (Draft, Active)
+            | (Draft, Cancelled)
+            | (Pending, Active)
+            | (Pending, Cancelled)
+            | (Active, Completed)
+            | (Active, Terminated)
+            | (Active, Disputed)
+            | (Disputed, Active)
+            | (Disputed, Terminated)
+            | (Disputed, ResolutionPending)
+            | (ResolutionPending, Active)
+            | (ResolutionPending, Terminated)
+    )
+}
+
+/// Move an agreement to `to`, rejecting edges the lifecycle does not allow.
+pub(crate) fn transition(
+    env: &Env,
+    agreement: &mut RentAgreement,
+    to: AgreementStatus,
+) -> Result<(), RentalError> {
+    if !can_transition(agreement.status.clone(), to.clone()) {
+        return Err(RentalError::InvalidStatusTransition);
+    }
+    crate::stats::record_status_change(env, &agreement.status, &to);
+    if let Some(property_id) = agreement.property_id.clone() {
+        track_active_for_property(env, property_id, &agreement.agreement_id, &to);
+    }
+    agreement.status = to;
+    Ok(())
+}
+
+/// Point a property at the agreement entering Active, and drop the pointer
+/// once that agreement completes or is terminated.
+fn track_active_for_property(
+    env: &Env,
+    property_id: String,
+    agreement_id: &String,
+    to: &AgreementStatus,
+) {
+    let key = DataKey::PropertyActiveAgreement(property_id);
+    match to {
+        AgreementStatus::Active => {
+            env.storage().persistent().set(&key, agreement_id);
+            env.storage()
+                .persistent()
+                .extend_ttl(&key, TTL_THRESHOLD, TTL_BUMP);
+        }
+        AgreementStatus::Completed | AgreementStatus::Terminated => {
+            let current: Option<String> = env.storage().persistent().get(&key);
+            if current.as_ref() == Some(agreement_id) {
+                env.storage().persistent().remove(&key);
+            }
+        }
+        _ => {}
+    }
+}
 
 /// Validate agreement parameters
 ///
@@ -36,10 +112,17 @@ pub fn validate_agreement_params(
         return Err(RentalError::InvalidDate);
     }
 
+    if crate::policy::get_policy(env).require_future_start && *start_date < now {
+        return Err(RentalError::StartInPast);
+    }
+
     if *agent_commission_rate > 100 {
         return Err(RentalError::InvalidCommissionRate);
     }
 
+    crate::policy::check_tick(env, *monthly_rent)?;
+    crate::policy::check_duration(env, *start_date, *end_date)?;
+
     Ok(())
 }
 
@@ -47,7 +130,7 @@ pub fn validate_agreement_params(
 #[allow(clippy::too_many_arguments)]
 pub fn create_agreement(env: &Env, input: crate::types::AgreementInput) -> Result<(), RentalError> {
     // Tenant MUST authorize creation
-    input.tenant.require_auth();
+    authorize_creation(env, &input);
 
     // Rate limiting check
     rate_limit::check_rate_limit(env, &input.tenant, "create_agreement")?;
@@ -55,6 +138,15 @@ pub fn create_agreement(env: &Env, input: crate::types::AgreementInput) -> Resul
     create_agreement_internal(env, input)
 }
 
+/// The tenant always authorizes creation; the landlord does too when the
+/// policy requires it.
+fn authorize_creation(env: &Env, input: &crate::types::AgreementInput) {
+    input.tenant.require_auth();
+    if crate::policy::get_policy(env).require_landlord_auth {
+        input.landlord.require_auth();
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 fn create_agreement_internal(
     env: &Env,
@@ -70,13 +162,24 @@ fn create_agreement_internal(
         &input.terms.agent_commission_rate,
     )?;
 
+    if input.token_decimals > MAX_TOKEN_DECIMALS {
+        return Err(RentalError::InvalidInput);
+    }
+
+    // The commission band only applies to agreements with an agent
+    if input.agent.is_some() {
+        crate::policy::check_commission_rate(env, input.terms.agent_commission_rate)?;
+    }
+
+    crate::allowlist::check_tenant_allowed(env, &input.landlord, &input.tenant)?;
+
+    let category = category_from_attributes(env, &input.attributes)?;
     let agreement_id = input.agreement_id.clone();
 
-    // Check for duplicate agreement_id
-    if env
-        .storage()
-        .persistent()
-        .has(&DataKey::Agreement(agreement_id.clone()))
+    // Check for duplicate agreement_id, including closed ones still indexed
+    let storage = env.storage().persistent();
+    if storage.has(&DataKey::Agreement(agreement_id.clone()))
+        || storage.has(&IndexKey::ClosedAgreement(agreement_id.clone()))
     {
         return Err(RentalError::AgreementAlreadyExists);
     }
@@ -95,11 +198,30 @@ fn create_agreement_internal(
         status: AgreementStatus::Draft,
         total_rent_paid: 0,
         payment_count: 0,
+        created_at: env.ledger().timestamp(),
         signed_at: None,
+        completed_at: None,
         payment_token: input.payment_token.clone(),
+        token_decimals: input.token_decimals,
+        deposit_refundable: true,
+        early_termination_fee: 0,
+        dual_release_approval: false,
+        apply_deposit_to_last: false,
+        auto_activate_on_funding: false,
+        break_requested: false,
+        break_requested_at: None,
+        emit_amounts: true,
+        guarantor: None,
+        public: true,
+        proration_basis: crate::types::ProrationBasis::ThirtyDay,
+        category,
+        allocation_policy: crate::types::AllocationPolicy::ArrearsFirst,
+        periods_paid_ahead: Vec::new(env),
+        commission_reserve: 0,
         next_payment_due: input.terms.start_date,
         metadata_uri: input.metadata_uri,
         attributes: input.attributes,
+        property_id: None,
     };
 
     // Store agreement
@@ -112,17 +234,31 @@ fn create_agreement_internal(
         TTL_BUMP,
     );
 
-    // Update counter
+    // Update counter and creation-order index
     let mut count: u32 = env
         .storage()
         .instance()
         .get(&DataKey::AgreementCount)
         .unwrap_or(0);
+    env.storage()
+        .persistent()
+        .set(&DataKey::AgreementIndex(count), &agreement_id);
+    env.storage()
+        .persistent()
+        .extend_ttl(&DataKey::AgreementIndex(count), TTL_THRESHOLD, TTL_BUMP);
     count += 1;
     env.storage()
         .instance()
         .set(&DataKey::AgreementCount, &count);
     env.storage().instance().extend_ttl(TTL_THRESHOLD, TTL_BUMP);
+    crate::stats::record_agreement_created(env, &agreement.landlord, &agreement_id);
+
+    if let Some(agent) = &agreement.agent {
+        record_agent(env, agent);
+    }
+
+    audit::record(env, &agreement_id, symbol_short!("created"));
+    notify_create_hook(env, &agreement_id);
 
     // Emit event with topics for indexing
     events::agreement_created(
@@ -140,6 +276,93 @@ fn create_agreement_internal(
     Ok(())
 }
 
+/// Call `on_agreement_created(agreement_id)` on the configured create hook, if any.
+fn notify_create_hook(env: &Env, agreement_id: &String) {
+    let hook: Address = match env.storage().instance().get(&DataKey::CreateHook) {
+        Some(hook) => hook,
+        None => return,
+    };
+
+    env.invoke_contract::<()>(
+        &hook,
+        &Symbol::new(env, "on_agreement_created"),
+        vec![env, agreement_id.into_val(env)],
+    );
+}
+
+/// Create a new Draft from an existing agreement's terms for a different tenant
+///
+/// Copies rent, deposit, dates, commission, agent, token and metadata. The
+/// source landlord must authorize; the usual creation checks still apply.
+pub fn clone_agreement(
+    env: &Env,
+    source_id: String,
+    new_id: String,
+    new_tenant: Address,
+) -> Result<(), RentalError> {
+    let source: RentAgreement = load_agreement(env, &source_id)?;
+
+    source.landlord.require_auth();
+
+    create_agreement_internal(
+        env,
+        crate::types::AgreementInput {
+            agreement_id: new_id,
+            landlord: source.landlord,
+            tenant: new_tenant,
+            agent: source.agent,
+            terms: crate::types::AgreementTerms {
+                monthly_rent: source.monthly_rent,
+                security_deposit: source.security_deposit,
+                start_date: source.start_date,
+                end_date: source.end_date,
+                agent_commission_rate: source.agent_commission_rate,
+            },
+            payment_token: source.payment_token,
+            token_decimals: source.token_decimals,
+            metadata_uri: source.metadata_uri,
+            attributes: source.attributes,
+        },
+    )
+}
+
+/// Create an agreement and activate it immediately, skipping signatures and
+/// the deposit. Its 12-period term starts at the current ledger time.
+#[cfg(feature = "testutils")]
+pub(crate) fn seed_active_agreement(
+    env: &Env,
+    agreement_id: String,
+    landlord: Address,
+    tenant: Address,
+    monthly_rent: i128,
+    payment_token: Address,
+) -> Result<(), RentalError> {
+    let start_date = env.ledger().timestamp();
+    create_agreement_internal(
+        env,
+        crate::types::AgreementInput {
+            agreement_id: agreement_id.clone(),
+            landlord,
+            tenant,
+            agent: None,
+            terms: crate::types::AgreementTerms {
+                monthly_rent,
+                security_deposit: 0,
+                start_date,
+                end_date: start_date + 12 * RENT_PERIOD_SECONDS,
+                agent_commission_rate: 0,
+            },
+            payment_token,
+            token_decimals: 7,
+            metadata_uri: String::from_str(env, ""),
+            attributes: Vec::new(env),
+        },
+    )?;
+
+    let mut agreement = load_agreement(env, &agreement_id)?;
+    activate(env, &mut agreement)
+}
+
 /// Sign an agreement as the tenant
 pub fn sign_agreement(env: &Env, tenant: Address, agreement_id: String) -> Result<(), RentalError> {
     // Tenant MUST authorize signing
@@ -149,11 +372,7 @@ pub fn sign_agreement(env: &Env, tenant: Address, agreement_id: String) -> Resul
     rate_limit::check_rate_limit(env, &tenant, "sign_agreement")?;
 
     // Retrieve the agreement
-    let mut agreement: RentAgreement = env
-        .storage()
-        .persistent()
-        .get(&DataKey::Agreement(agreement_id.clone()))
-        .ok_or(RentalError::AgreementNotFound)?;
+    let mut agreement: RentAgreement = load_agreement(env, &agreement_id)?;
 
     // Validate caller is the intended tenant
     if agreement.tenant != tenant {
@@ -165,20 +384,32 @@ pub fn sign_agreement(env: &Env, tenant: Address, agreement_id: String) -> Resul
         return Err(RentalError::InvalidState);
     }
 
+    activate(env, &mut agreement)
+}
+
+/// Move a Pending agreement to Active on the tenant's behalf, recording the
+/// signing time. Caller must have checked the tenant's authorization.
+pub(crate) fn activate(env: &Env, agreement: &mut RentAgreement) -> Result<(), RentalError> {
     // Validate agreement has not expired
     let current_time = env.ledger().timestamp();
     if current_time > agreement.end_date {
         return Err(RentalError::Expired);
     }
 
+    // A property cannot carry two overlapping active leases
+    if let Some(property_id) = agreement.property_id.clone() {
+        ensure_property_available(env, &property_id, agreement)?;
+    }
+
     // Update agreement status and record signing time
-    agreement.status = AgreementStatus::Active;
+    transition(env, agreement, AgreementStatus::Active)?;
     agreement.signed_at = Some(current_time);
 
     // Save updated agreement
+    let agreement_id = agreement.agreement_id.clone();
     env.storage()
         .persistent()
-        .set(&DataKey::Agreement(agreement_id.clone()), &agreement);
+        .set(&DataKey::Agreement(agreement_id.clone()), &*agreement);
     env.storage().persistent().extend_ttl(
         &DataKey::Agreement(agreement_id.clone()),
         TTL_THRESHOLD,
@@ -186,11 +417,13 @@ pub fn sign_agreement(env: &Env, tenant: Address, agreement_id: String) -> Resul
     );
     env.storage().instance().extend_ttl(TTL_THRESHOLD, TTL_BUMP);
 
+    audit::record(env, &agreement_id, symbol_short!("signed"));
+
     // Emit event with topics for indexing
     events::agreement_signed(
         env,
         agreement_id,
-        tenant,
+        agreement.tenant.clone(),
         agreement.landlord.clone(),
         current_time,
     );
@@ -198,29 +431,56 @@ pub fn sign_agreement(env: &Env, tenant: Address, agreement_id: String) -> Resul
     Ok(())
 }
 
-/// Submit a draft agreement for tenant signature (Draft → Pending)
-pub fn submit_agreement(
+/// Reject activation if another active agreement on the property overlaps the given one
+fn ensure_property_available(
+    env: &Env,
+    property_id: &String,
+    agreement: &RentAgreement,
+) -> Result<(), RentalError> {
+    for other_id in get_property_agreements(env, property_id.clone()).iter() {
+        if other_id == agreement.agreement_id {
+            continue;
+        }
+        if let Some(other) = get_agreement(env, other_id) {
+            if other.status == AgreementStatus::Active
+                && other.start_date < agreement.end_date
+                && agreement.start_date < other.end_date
+            {
+                return Err(RentalError::PropertyAlreadyLeased);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Link a Draft or Pending agreement to a property registry id (landlord only)
+pub fn link_property(
     env: &Env,
     landlord: Address,
     agreement_id: String,
+    property_id: String,
 ) -> Result<(), RentalError> {
     landlord.require_auth();
 
-    let mut agreement: RentAgreement = env
-        .storage()
-        .persistent()
-        .get(&DataKey::Agreement(agreement_id.clone()))
-        .ok_or(RentalError::AgreementNotFound)?;
+    let mut agreement: RentAgreement = load_agreement(env, &agreement_id)?;
 
     if agreement.landlord != landlord {
         return Err(RentalError::Unauthorized);
     }
 
-    if agreement.status != AgreementStatus::Draft {
+    if agreement.status != AgreementStatus::Draft && agreement.status != AgreementStatus::Pending {
+        return Err(RentalError::InvalidState);
+    }
+
+    if agreement.property_id.is_some() {
         return Err(RentalError::InvalidState);
     }
 
-    agreement.status = AgreementStatus::Pending;
+    if property_id.is_empty() {
+        return Err(RentalError::InvalidInput);
+    }
+
+    agreement.property_id = Some(property_id.clone());
 
     env.storage()
         .persistent()
@@ -231,237 +491,1029 @@ pub fn submit_agreement(
         TTL_BUMP,
     );
 
-    events::agreement_submitted(env, agreement_id, landlord, agreement.tenant.clone());
+    let key = DataKey::PropertyAgreements(property_id);
+    let mut ids = env
+        .storage()
+        .persistent()
+        .get::<DataKey, Vec<String>>(&key)
+        .unwrap_or(Vec::new(env));
+    ids.push_back(agreement_id);
+    env.storage().persistent().set(&key, &ids);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_BUMP);
 
     Ok(())
 }
 
-/// Cancel an agreement while in Draft or Pending state
-pub fn cancel_agreement(
+/// Set the fee owed for ending a Draft or Pending agreement early (landlord only)
+pub fn set_early_termination_fee(
     env: &Env,
-    caller: Address,
     agreement_id: String,
+    fee: i128,
 ) -> Result<(), RentalError> {
-    caller.require_auth();
+    let mut agreement: RentAgreement = load_agreement(env, &agreement_id)?;
 
-    let mut agreement: RentAgreement = env
-        .storage()
-        .persistent()
-        .get(&DataKey::Agreement(agreement_id.clone()))
-        .ok_or(RentalError::AgreementNotFound)?;
-
-    // Only landlord can cancel
-    if agreement.landlord != caller {
-        return Err(RentalError::Unauthorized);
-    }
+    agreement.landlord.require_auth();
 
-    // Only in Draft or Pending states
     if agreement.status != AgreementStatus::Draft && agreement.status != AgreementStatus::Pending {
         return Err(RentalError::InvalidState);
     }
 
-    agreement.status = AgreementStatus::Cancelled;
+    if fee < 0 {
+        return Err(RentalError::InvalidAmount);
+    }
 
+    agreement.early_termination_fee = fee;
     env.storage()
         .persistent()
         .set(&DataKey::Agreement(agreement_id.clone()), &agreement);
     env.storage().persistent().extend_ttl(
-        &DataKey::Agreement(agreement_id.clone()),
+        &DataKey::Agreement(agreement_id),
         TTL_THRESHOLD,
         TTL_BUMP,
     );
-
-    events::agreement_cancelled(env, agreement_id, caller, agreement.tenant.clone());
-
     Ok(())
 }
 
-/// Retrieve a rent agreement by its unique identifier
-pub fn get_agreement(env: &Env, agreement_id: String) -> Option<RentAgreement> {
-    env.storage()
-        .persistent()
-        .get(&DataKey::Agreement(agreement_id))
-}
-
-/// Check whether a rent agreement exists for the given identifier
-pub fn has_agreement(env: &Env, agreement_id: String) -> bool {
-    env.storage()
-        .persistent()
-        .has(&DataKey::Agreement(agreement_id))
-}
-
-/// Returns the total number of rent agreements created
-pub fn get_agreement_count(env: &Env) -> u32 {
-    env.storage()
-        .instance()
-        .get(&DataKey::AgreementCount)
-        .unwrap_or(0)
-}
-
-pub fn get_payment_split(
+/// Choose how rent is pro-rated for partial periods (landlord only, before activation)
+pub fn set_proration_basis(
     env: &Env,
     agreement_id: String,
-    month: u32,
-) -> Result<PaymentSplit, RentalError> {
-    env.storage()
-        .persistent()
-        .get(&DataKey::PaymentRecord(agreement_id, month))
-        .ok_or(RentalError::AgreementNotFound)
-}
-
-/// Get all payments for an agreement
-pub fn get_payment_history(env: &Env, agreement_id: String) -> Vec<PaymentSplit> {
-    let mut history = Vec::new(env);
-    let agreement: RentAgreement = match env
-        .storage()
-        .persistent()
-        .get(&DataKey::Agreement(agreement_id.clone()))
-    {
-        Some(a) => a,
-        None => return history,
-    };
+    basis: crate::types::ProrationBasis,
+) -> Result<(), RentalError> {
+    let mut agreement = load_agreement(env, &agreement_id)?;
+    agreement.landlord.require_auth();
 
-    for i in 1..=agreement.payment_count {
-        if let Some(payment) = env
-            .storage()
-            .persistent()
-            .get(&DataKey::PaymentRecord(agreement_id.clone(), i))
-        {
-            history.push_back(payment);
-        }
+    if agreement.status != AgreementStatus::Draft && agreement.status != AgreementStatus::Pending {
+        return Err(RentalError::InvalidState);
     }
-    history
+
+    agreement.proration_basis = basis;
+    env.storage()
+        .persistent()
+        .set(&DataKey::Agreement(agreement_id), &agreement);
+    Ok(())
 }
 
-/// Update metadata for an agreement
-pub fn update_metadata(
+/// Choose which period payments made in arrears are credited to (landlord
+/// only, before activation).
+pub fn set_allocation_policy(
     env: &Env,
     agreement_id: String,
-    metadata_uri: String,
-    attributes: Vec<crate::types::Attribute>,
+    policy: crate::types::AllocationPolicy,
 ) -> Result<(), RentalError> {
-    let mut agreement: RentAgreement = env
-        .storage()
-        .persistent()
-        .get(&DataKey::Agreement(agreement_id.clone()))
-        .ok_or(RentalError::AgreementNotFound)?;
-
+    let mut agreement = load_agreement(env, &agreement_id)?;
     agreement.landlord.require_auth();
 
-    agreement.metadata_uri = metadata_uri;
-    agreement.attributes = attributes;
+    if agreement.status != AgreementStatus::Draft && agreement.status != AgreementStatus::Pending {
+        return Err(RentalError::InvalidState);
+    }
 
+    agreement.allocation_policy = policy;
     env.storage()
         .persistent()
         .set(&DataKey::Agreement(agreement_id), &agreement);
     Ok(())
 }
 
-/// Create a new agreement with a specific payment token
-#[allow(clippy::too_many_arguments)]
-pub fn create_agreement_with_token(
-    env: &Env,
-    input: crate::types::AgreementInput,
-) -> Result<String, RentalError> {
-    input.tenant.require_auth();
+/// Correct the agent commission rate before activation (landlord and tenant).
+///
+/// The new rate is checked like one given at creation.
+pub fn set_commission(env: &Env, agreement_id: String, new_rate: u32) -> Result<(), RentalError> {
+    let mut agreement = load_agreement(env, &agreement_id)?;
+    agreement.landlord.require_auth();
+    agreement.tenant.require_auth();
 
-    // Check if token is supported
-    if !crate::multi_token::is_token_supported(env.clone(), input.payment_token.clone())? {
-        return Err(RentalError::TokenNotSupported);
+    if agreement.status != AgreementStatus::Draft && agreement.status != AgreementStatus::Pending {
+        return Err(RentalError::InvalidState);
     }
 
-    let agreement_id = input.agreement_id.clone();
+    if new_rate > 100 {
+        return Err(RentalError::InvalidCommissionRate);
+    }
+    if agreement.agent.is_some() {
+        crate::policy::check_commission_rate(env, new_rate)?;
+    }
 
-    create_agreement_internal(env, input)?;
+    agreement.agent_commission_rate = new_rate;
+    env.storage()
+        .persistent()
+        .set(&DataKey::Agreement(agreement_id), &agreement);
+    Ok(())
+}
 
-    // Store the token mapping explicitly if needed, but it's already in RentAgreement
-    // Wait, create_agreement_internal already set the agreement.
-    // We just need the extra DataKey::AgreementToken if the frontend relies on it.
-    let agreement: RentAgreement = env
-        .storage()
+/// Choose whether rent payment events publish the paid amount (landlord only)
+pub fn set_emit_amounts(env: &Env, agreement_id: String, emit: bool) -> Result<(), RentalError> {
+    let mut agreement = load_agreement(env, &agreement_id)?;
+    agreement.landlord.require_auth();
+
+    agreement.emit_amounts = emit;
+    env.storage()
         .persistent()
-        .get(&DataKey::Agreement(agreement_id.clone()))
-        .unwrap();
+        .set(&DataKey::Agreement(agreement_id), &agreement);
+    Ok(())
+}
 
-    env.storage().persistent().set(
-        &DataKey::AgreementToken(agreement_id.clone()),
-        &agreement.payment_token,
-    );
+/// Show or hide the agreement from listings for non-parties (landlord only)
+pub fn set_public(env: &Env, agreement_id: String, public: bool) -> Result<(), RentalError> {
+    let mut agreement = load_agreement(env, &agreement_id)?;
+    agreement.landlord.require_auth();
 
-    Ok(agreement_id)
+    agreement.public = public;
+    env.storage()
+        .persistent()
+        .set(&DataKey::Agreement(agreement_id), &agreement);
+    Ok(())
 }
 
-/// Get the payment token for an agreement
-pub fn get_agreement_token(env: &Env, agreement_id: String) -> Result<Address, RentalError> {
+/// Get the ids of all agreements linked to a property
+pub fn get_property_agreements(env: &Env, property_id: String) -> Vec<String> {
     env.storage()
         .persistent()
-        .get(&DataKey::AgreementToken(agreement_id))
-        .ok_or(RentalError::AgreementNotFound)
+        .get(&DataKey::PropertyAgreements(property_id))
+        .unwrap_or(Vec::new(env))
 }
 
-/// Make a payment for an agreement using a specific token
-pub fn make_payment_with_token(
-    env: &Env,
-    agreement_id: String,
-    amount: i128,
-    token: Address,
-) -> Result<(), RentalError> {
-    let mut agreement: RentAgreement = env
-        .storage()
+/// Get the id of the property's Active agreement, if it has one
+pub fn get_property_active_agreement(env: &Env, property_id: String) -> Option<String> {
+    env.storage()
         .persistent()
-        .get(&DataKey::Agreement(agreement_id.clone()))
-        .ok_or(RentalError::AgreementNotFound)?;
+        .get(&DataKey::PropertyActiveAgreement(property_id))
+}
+
+/// Amend the monthly rent of an Active agreement.
+///
+/// Landlord and tenant must both authorize. When the new rent exceeds the
+/// configured co-authorization threshold, the agent must authorize as well.
+pub fn amend_rent(env: &Env, agreement_id: String, new_rent: i128) -> Result<(), RentalError> {
+    let mut agreement: RentAgreement = load_agreement(env, &agreement_id)?;
 
     if agreement.status != AgreementStatus::Active {
         return Err(RentalError::AgreementNotActive);
     }
 
+    if new_rent <= 0 {
+        return Err(RentalError::InvalidAmount);
+    }
+    crate::policy::check_tick(env, new_rent)?;
+
+    agreement.landlord.require_auth();
     agreement.tenant.require_auth();
 
-    // Convert amount to the agreement's base token if they differ
-    let amount_in_base = if token != agreement.payment_token {
-        crate::multi_token::convert_amount(
-            env.clone(),
-            token.clone(),
-            agreement.payment_token.clone(),
-            amount,
-        )?
+    let threshold = crate::policy::get_policy(env).coauth_threshold;
+    if threshold > 0 && new_rent > threshold {
+        match &agreement.agent {
+            Some(agent) => agent.require_auth(),
+            None => return Err(RentalError::InvalidParties),
+        }
+    }
+
+    let old_rent = agreement.monthly_rent;
+    crate::policy::check_rent_increase(env, old_rent, new_rent)?;
+    agreement.monthly_rent = new_rent;
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::Agreement(agreement_id.clone()), &agreement);
+    env.storage().persistent().extend_ttl(
+        &DataKey::Agreement(agreement_id.clone()),
+        TTL_THRESHOLD,
+        TTL_BUMP,
+    );
+
+    audit::record(env, &agreement_id, symbol_short!("amended"));
+    events::rent_amended(env, agreement_id, old_rent, new_rent);
+
+    Ok(())
+}
+
+/// Look up an agreement's linked property in the configured property registry
+///
+/// Returns `None` if the agreement, its property link, the registry address or
+/// the registry record is missing.
+pub fn get_agreement_property(env: &Env, agreement_id: String) -> Option<PropertyDetails> {
+    let property_id = get_agreement(env, agreement_id)?.property_id?;
+    let registry: Address = env.storage().instance().get(&DataKey::PropertyRegistry)?;
+
+    // Cross-contract call to the property registry
+    env.invoke_contract(
+        &registry,
+        &Symbol::new(env, "get_property"),
+        vec![env, property_id.into_val(env)],
+    )
+}
+
+/// Submit a draft agreement for tenant signature (Draft → Pending)
+pub fn submit_agreement(
+    env: &Env,
+    landlord: Address,
+    agreement_id: String,
+) -> Result<(), RentalError> {
+    landlord.require_auth();
+
+    let mut agreement: RentAgreement = load_agreement(env, &agreement_id)?;
+
+    if agreement.landlord != landlord {
+        return Err(RentalError::Unauthorized);
+    }
+
+    if agreement.status != AgreementStatus::Draft {
+        return Err(RentalError::InvalidState);
+    }
+
+    transition(env, &mut agreement, AgreementStatus::Pending)?;
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::Agreement(agreement_id.clone()), &agreement);
+    env.storage().persistent().extend_ttl(
+        &DataKey::Agreement(agreement_id.clone()),
+        TTL_THRESHOLD,
+        TTL_BUMP,
+    );
+
+    audit::record(env, &agreement_id, symbol_short!("submitted"));
+    events::agreement_submitted(env, agreement_id, landlord, agreement.tenant.clone());
+
+    Ok(())
+}
+
+/// Cancel an agreement while in Draft or Pending state, returning any rent or
+/// deposit already held to the tenant
+pub fn cancel_agreement(
+    env: &Env,
+    caller: Address,
+    agreement_id: String,
+) -> Result<(), RentalError> {
+    caller.require_auth();
+
+    let mut agreement: RentAgreement = load_agreement(env, &agreement_id)?;
+
+    // Only landlord can cancel
+    if agreement.landlord != caller {
+        return Err(RentalError::Unauthorized);
+    }
+
+    // Only in Draft or Pending states
+    if agreement.status != AgreementStatus::Draft && agreement.status != AgreementStatus::Pending {
+        return Err(RentalError::InvalidState);
+    }
+
+    // Make the tenant whole for any rent or deposit already held
+    crate::escrow::refund_rent(env, &agreement);
+    crate::deposit::refund_on_cancel(env, &agreement);
+
+    transition(env, &mut agreement, AgreementStatus::Cancelled)?;
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::Agreement(agreement_id.clone()), &agreement);
+    env.storage().persistent().extend_ttl(
+        &DataKey::Agreement(agreement_id.clone()),
+        TTL_THRESHOLD,
+        TTL_BUMP,
+    );
+
+    audit::record(env, &agreement_id, symbol_short!("cancelled"));
+    events::agreement_cancelled(env, agreement_id, caller, agreement.tenant.clone());
+
+    Ok(())
+}
+
+/// Terminate an Active agreement whose unpaid periods have reached the
+/// configured arrears threshold (landlord only)
+pub fn enforce_arrears(env: &Env, agreement_id: String) -> Result<(), RentalError> {
+    let mut agreement = load_agreement(env, &agreement_id)?;
+    agreement.landlord.require_auth();
+
+    if agreement.status != AgreementStatus::Active {
+        return Err(RentalError::AgreementNotActive);
+    }
+
+    let threshold = crate::policy::get_policy(env).max_arrears_periods;
+    let arrears = crate::billing::get_arrears(env, agreement_id.clone())?;
+    if threshold == 0 || arrears < threshold {
+        return Err(RentalError::ArrearsThresholdNotReached);
+    }
+
+    transition(env, &mut agreement, AgreementStatus::Terminated)?;
+    env.storage()
+        .persistent()
+        .set(&DataKey::Agreement(agreement_id.clone()), &agreement);
+    env.storage().persistent().extend_ttl(
+        &DataKey::Agreement(agreement_id.clone()),
+        TTL_THRESHOLD,
+        TTL_BUMP,
+    );
+    crate::escrow::release_on_end(env, &agreement_id)?;
+
+    audit::record(env, &agreement_id, symbol_short!("arrears"));
+    events::agreement_terminated(env, &agreement, arrears);
+
+    Ok(())
+}
+
+/// Ask to break an Active lease early; takes effect once the landlord approves
+pub fn request_break(env: &Env, agreement_id: String, tenant: Address) -> Result<(), RentalError> {
+    tenant.require_auth();
+
+    let mut agreement = load_agreement(env, &agreement_id)?;
+    if agreement.tenant != tenant {
+        return Err(RentalError::NotTenant);
+    }
+    if agreement.status != AgreementStatus::Active {
+        return Err(RentalError::AgreementNotActive);
+    }
+
+    agreement.break_requested = true;
+    agreement.break_requested_at = Some(env.ledger().timestamp());
+    env.storage()
+        .persistent()
+        .set(&DataKey::Agreement(agreement_id.clone()), &agreement);
+
+    audit::record(env, &agreement_id, symbol_short!("brk_req"));
+    events::break_requested(env, agreement_id, tenant);
+    Ok(())
+}
+
+/// Approve a tenant's break request, terminating the lease with `penalty`
+/// recorded as the early-termination fee the tenant owes. Prepaid rent for
+/// periods not yet due is returned to the tenant.
+pub fn approve_break(
+    env: &Env,
+    agreement_id: String,
+    landlord: Address,
+    penalty: i128,
+) -> Result<(), RentalError> {
+    landlord.require_auth();
+
+    let mut agreement = load_agreement(env, &agreement_id)?;
+    if agreement.landlord != landlord {
+        return Err(RentalError::Unauthorized);
+    }
+    if agreement.status != AgreementStatus::Active {
+        return Err(RentalError::AgreementNotActive);
+    }
+    if !agreement.break_requested {
+        return Err(RentalError::InvalidState);
+    }
+    if penalty < 0 {
+        return Err(RentalError::InvalidAmount);
+    }
+
+    transition(env, &mut agreement, AgreementStatus::Terminated)?;
+    agreement.break_requested = false;
+    agreement.break_requested_at = None;
+    agreement.early_termination_fee = penalty;
+    env.storage()
+        .persistent()
+        .set(&DataKey::Agreement(agreement_id.clone()), &agreement);
+    crate::escrow::release_on_end(env, &agreement_id)?;
+
+    audit::record(env, &agreement_id, symbol_short!("broken"));
+    events::lease_broken(env, &agreement, penalty);
+    Ok(())
+}
+
+/// Terminate a lease whose break request has served the configured notice
+/// period, without landlord approval. The agreement's early-termination fee
+/// stands as the tenant's penalty.
+///
+/// Anyone may call, while the agreement is Active. Fails with
+/// `NoticePeriodActive` while notice is still running, and `InvalidState` when
+/// no notice period is configured.
+pub fn finalize_break(env: &Env, agreement_id: String) -> Result<(), RentalError> {
+    let mut agreement = load_agreement(env, &agreement_id)?;
+    if agreement.status != AgreementStatus::Active {
+        return Err(RentalError::AgreementNotActive);
+    }
+    let requested_at = match agreement.break_requested_at {
+        Some(requested_at) if agreement.break_requested => requested_at,
+        _ => return Err(RentalError::InvalidState),
+    };
+
+    let notice_period = crate::policy::get_policy(env).notice_period;
+    if notice_period == 0 {
+        return Err(RentalError::InvalidState);
+    }
+    if env.ledger().timestamp() < requested_at.saturating_add(notice_period) {
+        return Err(RentalError::NoticePeriodActive);
+    }
+
+    transition(env, &mut agreement, AgreementStatus::Terminated)?;
+    agreement.break_requested = false;
+    agreement.break_requested_at = None;
+    env.storage()
+        .persistent()
+        .set(&DataKey::Agreement(agreement_id.clone()), &agreement);
+    crate::escrow::release_on_end(env, &agreement_id)?;
+
+    audit::record(env, &agreement_id, symbol_short!("broken"));
+    events::lease_broken(env, &agreement, agreement.early_termination_fee);
+    Ok(())
+}
+
+/// Delete a settled Completed or Cancelled agreement and its per-agreement
+/// records (landlord or tenant).
+///
+/// Fails with `LedgerError::BalanceRemaining` while any escrow, deposit or
+/// prepaid rent is still held. The id is tombstoned with the agreement's final status, so it
+/// cannot be created again; the creation-order and landlord index slots keep
+/// pointing at it and scans skip ids whose agreement no longer exists.
+pub fn close_agreement(
+    env: &Env,
+    agreement_id: String,
+    caller: Address,
+) -> Result<(), soroban_sdk::Error> {
+    caller.require_auth();
+
+    let agreement = load_agreement(env, &agreement_id)?;
+    if caller != agreement.landlord && caller != agreement.tenant {
+        return Err(RentalError::Unauthorized.into());
+    }
+    if agreement.status != AgreementStatus::Completed
+        && agreement.status != AgreementStatus::Cancelled
+    {
+        return Err(RentalError::InvalidState.into());
+    }
+    if get_escrow_balance(env, agreement_id.clone()) != 0
+        || !get_escrow_tokens(env, &agreement_id).is_empty()
+        || crate::deposit::get_deposit_held(env, agreement_id.clone()) != 0
+        || crate::escrow::get_remaining_prepaid(env, agreement_id.clone()) != 0
+    {
+        return Err(LedgerError::BalanceRemaining.into());
+    }
+
+    let storage = env.storage().persistent();
+    for i in 1..=agreement.payment_count {
+        storage.remove(&DataKey::PaymentRecord(agreement_id.clone(), i));
+    }
+    storage.remove(&DataKey::AuditLog(agreement_id.clone()));
+    storage.remove(&DataKey::AgreementToken(agreement_id.clone()));
+    storage.remove(&DataKey::DisbursementApprovals(agreement_id.clone()));
+    storage.remove(&DataKey::Resolution(agreement_id.clone()));
+    storage.remove(&DataKey::DisputeRaisedAt(agreement_id.clone()));
+    storage.remove(&DataKey::DisputeEscalated(agreement_id.clone()));
+    storage.remove(&DataKey::DepositHeld(agreement_id.clone()));
+    storage.remove(&DataKey::DepositInterestConfig(agreement_id.clone()));
+    storage.remove(&DataKey::DepositInterest(agreement_id.clone()));
+    storage.remove(&DataKey::EscrowBalance(agreement_id.clone()));
+    storage.remove(&DataKey::Prepaid(agreement_id.clone()));
+    storage.remove(&DataKey::Agreement(agreement_id.clone()));
+
+    let tombstone = IndexKey::ClosedAgreement(agreement_id.clone());
+    storage.set(&tombstone, &agreement.status);
+    storage.extend_ttl(&tombstone, TTL_THRESHOLD, TTL_BUMP);
+    crate::stats::record_agreement_closed(env);
+
+    if let Some(property_id) = agreement.property_id.clone() {
+        let active = DataKey::PropertyActiveAgreement(property_id.clone());
+        if storage.get::<DataKey, String>(&active).as_ref() == Some(&agreement_id) {
+            storage.remove(&active);
+        }
+
+        let key = DataKey::PropertyAgreements(property_id);
+        if let Some(mut ids) = storage.get::<DataKey, Vec<String>>(&key) {
+            if let Some(index) = ids.first_index_of(&agreement_id) {
+                ids.remove(index);
+            }
+            if ids.is_empty() {
+                storage.remove(&key);
+            } else {
+                storage.set(&key, &ids);
+            }
+        }
+    }
+
+    events::agreement_closed(env, agreement_id.clone(), caller);
+    // The closing event is the agreement's last
+    storage.remove(&DataKey::EventSeq(agreement_id));
+    Ok(())
+}
+
+/// Final status of an agreement removed by `close_agreement`, if it was.
+pub fn get_closed_status(env: &Env, agreement_id: String) -> Option<AgreementStatus> {
+    env.storage()
+        .persistent()
+        .get(&IndexKey::ClosedAgreement(agreement_id))
+}
+
+/// Cancel up to `limit` Draft agreements created before `older_than`, returning how many
+pub fn expire_stale_drafts(env: &Env, older_than: u64, limit: u32) -> u32 {
+    let count = get_agreement_count(env);
+    let mut expired: u32 = 0;
+
+    for i in 0..count {
+        if expired >= limit {
+            break;
+        }
+
+        let agreement_id: String = match env.storage().persistent().get(&DataKey::AgreementIndex(i))
+        {
+            Some(id) => id,
+            None => continue,
+        };
+
+        let mut agreement = match get_agreement(env, agreement_id.clone()) {
+            Some(a) => a,
+            None => continue,
+        };
+
+        if agreement.status != AgreementStatus::Draft || agreement.created_at >= older_than {
+            continue;
+        }
+
+        if transition(env, &mut agreement, AgreementStatus::Cancelled).is_err() {
+            continue;
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::Agreement(agreement_id.clone()), &agreement);
+
+        audit::record(env, &agreement_id, symbol_short!("expired"));
+        events::agreement_cancelled(
+            env,
+            agreement_id,
+            agreement.landlord.clone(),
+            agreement.tenant.clone(),
+        );
+        expired += 1;
+    }
+
+    expired
+}
+
+/// Rent currently held in escrow for an agreement, in its payment token
+pub fn get_escrow_balance(env: &Env, agreement_id: String) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::EscrowBalance(agreement_id))
+        .unwrap_or(0)
+}
+
+pub(crate) fn set_escrow_balance(env: &Env, agreement_id: &String, amount: i128) {
+    let key = DataKey::EscrowBalance(agreement_id.clone());
+    if amount == 0 {
+        env.storage().persistent().remove(&key);
+        return;
+    }
+    env.storage().persistent().set(&key, &amount);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_BUMP);
+}
+
+/// Tokens other than its payment token that an agreement holds rent in.
+pub(crate) fn get_escrow_tokens(env: &Env, agreement_id: &String) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&IndexKey::EscrowTokens(agreement_id.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Rent held for an agreement in `token`, a supported token other than its
+/// payment token.
+pub fn get_token_escrow_balance(env: &Env, agreement_id: String, token: Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&IndexKey::TokenEscrow(agreement_id, token))
+        .unwrap_or(0)
+}
+
+pub(crate) fn set_token_escrow_balance(
+    env: &Env,
+    agreement_id: &String,
+    token: &Address,
+    amount: i128,
+) {
+    let storage = env.storage().persistent();
+    let key = IndexKey::TokenEscrow(agreement_id.clone(), token.clone());
+    let tokens_key = IndexKey::EscrowTokens(agreement_id.clone());
+    let mut tokens = get_escrow_tokens(env, agreement_id);
+
+    if amount == 0 {
+        storage.remove(&key);
+        if let Some(index) = tokens.first_index_of(token) {
+            tokens.remove(index);
+        }
     } else {
-        amount
+        storage.set(&key, &amount);
+        storage.extend_ttl(&key, TTL_THRESHOLD, TTL_BUMP);
+        if !tokens.contains(token) {
+            tokens.push_back(token.clone());
+        }
+    }
+
+    if tokens.is_empty() {
+        storage.remove(&tokens_key);
+    } else {
+        storage.set(&tokens_key, &tokens);
+        storage.extend_ttl(&tokens_key, TTL_THRESHOLD, TTL_BUMP);
+    }
+}
+
+/// Load an agreement, failing with `AgreementNotFound` when it does not exist.
+pub(crate) fn load_agreement(
+    env: &Env,
+    agreement_id: &String,
+) -> Result<RentAgreement, RentalError> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Agreement(agreement_id.clone()))
+        .ok_or(RentalError::AgreementNotFound)
+}
+
+/// Store an agreement and extend its TTL.
+pub(crate) fn save_agreement(env: &Env, agreement: &RentAgreement) {
+    let key = DataKey::Agreement(agreement.agreement_id.clone());
+    env.storage().persistent().set(&key, agreement);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_BUMP);
+}
+
+/// Retrieve a rent agreement by its unique identifier
+pub fn get_agreement(env: &Env, agreement_id: String) -> Option<RentAgreement> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Agreement(agreement_id))
+}
+
+/// Look up several agreements at once; results align with `ids`, `None` where missing
+pub fn get_agreements_batch(
+    env: &Env,
+    ids: Vec<String>,
+) -> Result<Vec<Option<RentAgreement>>, RentalError> {
+    if ids.len() > MAX_BATCH_SIZE {
+        return Err(RentalError::InvalidInput);
+    }
+
+    let mut agreements = Vec::new(env);
+    for id in ids.iter() {
+        agreements.push_back(get_agreement(env, id));
+    }
+    Ok(agreements)
+}
+
+/// Agreements in creation order, optionally only those with `status`.
+///
+/// Scans at most `limit` index slots (capped at 50) from `start`. Private
+/// agreements appear only when `viewer` is their landlord, tenant or agent.
+pub fn list_agreements(
+    env: &Env,
+    viewer: Option<Address>,
+    status: Option<AgreementStatus>,
+    start: u32,
+    limit: u32,
+) -> Vec<RentAgreement> {
+    let end = start
+        .saturating_add(limit.min(MAX_BATCH_SIZE))
+        .min(get_agreement_count(env));
+    let mut agreements = Vec::new(env);
+
+    for i in start..end {
+        let agreement_id: String = match env.storage().persistent().get(&DataKey::AgreementIndex(i))
+        {
+            Some(id) => id,
+            None => continue,
+        };
+        let agreement = match get_agreement(env, agreement_id) {
+            Some(agreement) => agreement,
+            None => continue,
+        };
+        if status
+            .as_ref()
+            .is_some_and(|status| *status != agreement.status)
+        {
+            continue;
+        }
+        let visible = agreement.public
+            || viewer.as_ref().is_some_and(|viewer| {
+                *viewer == agreement.landlord
+                    || *viewer == agreement.tenant
+                    || agreement.agent.as_ref() == Some(viewer)
+            });
+        if visible {
+            agreements.push_back(agreement);
+        }
+    }
+    agreements
+}
+
+/// Read the category from a `category` attribute, defaulting to residential.
+///
+/// Fails with `InvalidInput` for a category outside the supported set.
+fn category_from_attributes(
+    env: &Env,
+    attributes: &Vec<crate::types::Attribute>,
+) -> Result<String, RentalError> {
+    let category_trait = String::from_str(env, CATEGORY_TRAIT);
+    let category = match attributes
+        .iter()
+        .find(|attribute| attribute.trait_type == category_trait)
+    {
+        Some(attribute) => attribute.value,
+        None => return Ok(String::from_str(env, DEFAULT_CATEGORY)),
     };
 
-    if amount_in_base < agreement.monthly_rent {
-        return Err(RentalError::InsufficientPayment);
+    if CATEGORIES
+        .iter()
+        .any(|known| category == String::from_str(env, known))
+    {
+        Ok(category)
+    } else {
+        Err(RentalError::InvalidInput)
     }
+}
 
-    // Transfer tokens from tenant to contract (escrow)
-    let client = soroban_sdk::token::Client::new(env, &token);
-    client.transfer(&agreement.tenant, env.current_contract_address(), &amount);
+/// Page through public agreement ids in creation order, keeping those filed
+/// under `category`.
+///
+/// Scans at most `MAX_BATCH_SIZE` index slots per call starting at `start`.
+pub fn get_agreements_by_category(
+    env: &Env,
+    category: String,
+    start: u32,
+    limit: u32,
+) -> Vec<String> {
+    let end = start
+        .saturating_add(limit.min(MAX_BATCH_SIZE))
+        .min(get_agreement_count(env));
+    let mut ids = Vec::new(env);
+
+    for i in start..end {
+        let agreement_id: String = match env.storage().persistent().get(&DataKey::AgreementIndex(i))
+        {
+            Some(id) => id,
+            None => continue,
+        };
+        if let Some(agreement) = get_agreement(env, agreement_id.clone()) {
+            if agreement.public && agreement.category == category {
+                ids.push_back(agreement_id);
+            }
+        }
+    }
+    ids
+}
+
+/// Check whether a rent agreement exists for the given identifier
+pub fn has_agreement(env: &Env, agreement_id: String) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::Agreement(agreement_id))
+}
+
+fn record_agent(env: &Env, agent: &Address) {
+    let mut agents = get_agents(env);
+    if agents.contains(agent) {
+        return;
+    }
+    agents.push_back(agent.clone());
+    env.storage().persistent().set(&DataKey::AgentsSet, &agents);
+    env.storage()
+        .persistent()
+        .extend_ttl(&DataKey::AgentsSet, TTL_THRESHOLD, TTL_BUMP);
+}
+
+/// Distinct agent addresses seen on created agreements, in first-seen order
+pub fn get_agents(env: &Env) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AgentsSet)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Returns the total number of rent agreements created
+pub fn get_agreement_count(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::AgreementCount)
+        .unwrap_or(0)
+}
+
+pub fn get_payment_split(
+    env: &Env,
+    agreement_id: String,
+    month: u32,
+) -> Result<PaymentSplit, RentalError> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PaymentRecord(agreement_id, month))
+        .ok_or(RentalError::AgreementNotFound)
+}
+
+/// Get all payments for an agreement
+pub fn get_payment_history(env: &Env, agreement_id: String) -> Vec<PaymentSplit> {
+    let mut history = Vec::new(env);
+    let agreement: RentAgreement = match env
+        .storage()
+        .persistent()
+        .get(&DataKey::Agreement(agreement_id.clone()))
+    {
+        Some(a) => a,
+        None => return history,
+    };
+
+    for i in 1..=agreement.payment_count {
+        if let Some(payment) = env
+            .storage()
+            .persistent()
+            .get(&DataKey::PaymentRecord(agreement_id.clone(), i))
+        {
+            history.push_back(payment);
+        }
+    }
+    history
+}
+
+/// Update metadata for an agreement
+pub fn update_metadata(
+    env: &Env,
+    agreement_id: String,
+    metadata_uri: String,
+    attributes: Vec<crate::types::Attribute>,
+) -> Result<(), RentalError> {
+    let mut agreement: RentAgreement = load_agreement(env, &agreement_id)?;
 
-    // Update agreement state
-    agreement.total_rent_paid += amount_in_base;
+    agreement.landlord.require_auth();
+
+    agreement.metadata_uri = metadata_uri;
+    agreement.attributes = attributes;
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::Agreement(agreement_id), &agreement);
+    Ok(())
+}
+
+/// Create a new agreement with a specific payment token
+#[allow(clippy::too_many_arguments)]
+pub fn create_agreement_with_token(
+    env: &Env,
+    input: crate::types::AgreementInput,
+) -> Result<String, RentalError> {
+    authorize_creation(env, &input);
+
+    // Check if token is supported
+    if !crate::multi_token::is_token_supported(env.clone(), input.payment_token.clone())? {
+        return Err(RentalError::TokenNotSupported);
+    }
+
+    let agreement_id = input.agreement_id.clone();
+
+    create_agreement_internal(env, input)?;
+
+    // Store the token mapping explicitly if needed, but it's already in RentAgreement
+    // Wait, create_agreement_internal already set the agreement.
+    // We just need the extra DataKey::AgreementToken if the frontend relies on it.
+    let agreement: RentAgreement = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Agreement(agreement_id.clone()))
+        .unwrap();
+
+    env.storage().persistent().set(
+        &DataKey::AgreementToken(agreement_id.clone()),
+        &agreement.payment_token,
+    );
+
+    Ok(agreement_id)
+}
+
+/// Number of rent periods in an agreement's term, rounding a partial final period up
+pub(crate) fn total_periods(agreement: &RentAgreement) -> u32 {
+    let term = agreement.end_date.saturating_sub(agreement.start_date);
+    term.div_ceil(RENT_PERIOD_SECONDS).max(1) as u32
+}
+
+/// Get `(periods_elapsed, total_periods, percent_complete)` for an agreement
+pub fn get_progress(env: &Env, agreement_id: String) -> Result<(u32, u32, u32), RentalError> {
+    let agreement = load_agreement(env, &agreement_id)?;
+    let total = total_periods(&agreement);
+    let now = env.ledger().timestamp();
+
+    if agreement.status == AgreementStatus::Completed || now >= agreement.end_date {
+        return Ok((total, total, 100));
+    }
+
+    let elapsed = if now <= agreement.start_date {
+        0
+    } else {
+        (((now - agreement.start_date) / RENT_PERIOD_SECONDS) as u32).min(total)
+    };
+
+    Ok((elapsed, total, elapsed * 100 / total))
+}
+
+/// Get the ledger timestamp at which an agreement was created
+pub fn get_created_at(env: &Env, agreement_id: String) -> Result<u64, RentalError> {
+    load_agreement(env, &agreement_id).map(|agreement| agreement.created_at)
+}
+
+/// Get the decimal places of an agreement's payment token
+pub fn get_token_decimals(env: &Env, agreement_id: String) -> Result<u32, RentalError> {
+    load_agreement(env, &agreement_id).map(|agreement| agreement.token_decimals)
+}
+
+/// Get the payment token for an agreement
+pub fn get_agreement_token(env: &Env, agreement_id: String) -> Result<Address, RentalError> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AgreementToken(agreement_id))
+        .ok_or(RentalError::AgreementNotFound)
+}
+
+/// Credit `amount` of rent, in the agreement's payment token, to the
+/// agreement and keep its payment record. The caller moves the funds into
+/// escrow and saves the agreement.
+pub(crate) fn record_rent_payment(
+    env: &Env,
+    agreement: &mut RentAgreement,
+    amount: i128,
+    token: &Address,
+) {
+    crate::billing::allocate_payment(env, agreement, amount);
+    agreement.total_rent_paid += amount;
     agreement.payment_count += 1;
+    crate::stats::record_payment(env, &agreement.payment_token, amount);
 
     // Simple split for now: 100% to landlord
     let split = PaymentSplit {
-        landlord_amount: amount_in_base,
+        landlord_amount: amount,
         platform_amount: 0,
         token: token.clone(),
         payment_date: env.ledger().timestamp(),
         payer: agreement.tenant.clone(),
     };
 
-    let record_key = DataKey::PaymentRecord(agreement_id.clone(), agreement.payment_count);
+    let record_key =
+        DataKey::PaymentRecord(agreement.agreement_id.clone(), agreement.payment_count);
     env.storage().persistent().set(&record_key, &split);
     env.storage()
         .persistent()
         .extend_ttl(&record_key, TTL_THRESHOLD, TTL_BUMP);
+}
+
+/// Make a payment for an agreement using a specific token
+pub fn make_payment_with_token(
+    env: &Env,
+    agreement_id: String,
+    amount: i128,
+    token: Address,
+) -> Result<(), RentalError> {
+    if amount <= 0 {
+        return Err(RentalError::InvalidAmount);
+    }
+
+    let mut agreement: RentAgreement = load_agreement(env, &agreement_id)?;
 
+    if agreement.status != AgreementStatus::Active {
+        return Err(RentalError::AgreementNotActive);
+    }
+
+    agreement.tenant.require_auth();
+
+    // Convert amount to the agreement's base token if they differ
+    let amount_in_base = if token != agreement.payment_token {
+        crate::multi_token::convert_amount(
+            env.clone(),
+            token.clone(),
+            agreement.payment_token.clone(),
+            amount,
+        )?
+    } else {
+        amount
+    };
+
+    if amount_in_base < agreement.monthly_rent {
+        return Err(RentalError::InsufficientPayment);
+    }
+
+    // Prepaid rent covers any due period before this payment is applied
+    crate::escrow::draw_prepaid(env, &mut agreement);
+
+    // Transfer tokens from tenant to contract (escrow)
+    let client = soroban_sdk::token::Client::new(env, &token);
+    client.transfer(&agreement.tenant, env.current_contract_address(), &amount);
+
+    // Rent in another supported token is held, and later paid out, in that token
+    if token == agreement.payment_token {
+        let escrowed = get_escrow_balance(env, agreement_id.clone());
+        set_escrow_balance(env, &agreement_id, escrowed + amount);
+    } else {
+        let escrowed = get_token_escrow_balance(env, agreement_id.clone(), token.clone());
+        set_token_escrow_balance(env, &agreement_id, &token, escrowed + amount);
+    }
+
+    record_rent_payment(env, &mut agreement, amount_in_base, &token);
     env.storage()
         .persistent()
         .set(&DataKey::Agreement(agreement_id.clone()), &agreement);
 
-    events::payment_made_with_token(env, agreement_id, token, amount);
+    audit::record(env, &agreement_id, symbol_short!("paid"));
+    let published = if agreement.emit_amounts { amount } else { 0 };
+    events::payment_made_with_token(env, agreement_id, agreement.payment_count, token, published);
 
     Ok(())
 }
@@ -474,25 +1526,13 @@ pub fn release_escrow_with_token(
 ) -> Result<(), RentalError> {
     // For simplicity, we assume escrow_id is the agreement_id
     let agreement_id = escrow_id.clone();
-    let agreement: RentAgreement = env
-        .storage()
-        .persistent()
-        .get(&DataKey::Agreement(agreement_id))
-        .ok_or(RentalError::AgreementNotFound)?;
+    let mut agreement: RentAgreement = load_agreement(env, &agreement_id)?;
 
     // Only landlord can release? Or admin?
     // Let's assume landlord for this implementation
     agreement.landlord.require_auth();
 
-    let contract_addr = env.current_contract_address();
-    let client = soroban_sdk::token::Client::new(env, &token);
-    let balance = client.balance(&contract_addr);
-
-    if balance > 0 {
-        client.transfer(&contract_addr, &agreement.landlord, &balance);
-    }
-
-    events::escrow_released_with_token(env, escrow_id, token, balance);
+    crate::escrow::disburse_rent_in(env, &mut agreement, &token)?;
 
     Ok(())
 }
@@ -0,0 +1,64 @@
+//! Per-landlord tenant allowlists for agreement creation.
+
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::errors::RentalError;
+use crate::storage::DataKey;
+
+const TTL_THRESHOLD: u32 = 500000;
+const TTL_BUMP: u32 = 500000;
+
+/// Get a landlord's tenant allowlist. An empty list means any tenant is accepted.
+pub fn get_tenant_allowlist(env: &Env, landlord: Address) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::TenantAllowlist(landlord))
+        .unwrap_or(Vec::new(env))
+}
+
+fn save_tenant_allowlist(env: &Env, landlord: &Address, allowlist: &Vec<Address>) {
+    let key = DataKey::TenantAllowlist(landlord.clone());
+    if allowlist.is_empty() {
+        env.storage().persistent().remove(&key);
+        return;
+    }
+    env.storage().persistent().set(&key, allowlist);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_BUMP);
+}
+
+/// Add a tenant to the landlord's allowlist (landlord only).
+pub fn add_allowed_tenant(env: &Env, landlord: Address, tenant: Address) {
+    landlord.require_auth();
+
+    let mut allowlist = get_tenant_allowlist(env, landlord.clone());
+    if !allowlist.contains(&tenant) {
+        allowlist.push_back(tenant);
+        save_tenant_allowlist(env, &landlord, &allowlist);
+    }
+}
+
+/// Remove a tenant from the landlord's allowlist (landlord only).
+pub fn remove_allowed_tenant(env: &Env, landlord: Address, tenant: Address) {
+    landlord.require_auth();
+
+    let mut allowlist = get_tenant_allowlist(env, landlord.clone());
+    if let Some(index) = allowlist.first_index_of(&tenant) {
+        allowlist.remove(index);
+        save_tenant_allowlist(env, &landlord, &allowlist);
+    }
+}
+
+/// Reject a tenant missing from the landlord's non-empty allowlist.
+pub(crate) fn check_tenant_allowed(
+    env: &Env,
+    landlord: &Address,
+    tenant: &Address,
+) -> Result<(), RentalError> {
+    let allowlist = get_tenant_allowlist(env, landlord.clone());
+    if !allowlist.is_empty() && !allowlist.contains(tenant) {
+        return Err(RentalError::TenantNotAllowed);
+    }
+    Ok(())
+}
@@ -0,0 +1,154 @@
+//! Refundable application fees paid before an agreement exists.
+
+use soroban_sdk::{token, Address, Env, String};
+
+use crate::errors::RentalError;
+use crate::events;
+use crate::storage::DataKey;
+use crate::types::{ApplicationFee, RentAgreement};
+
+const TTL_THRESHOLD: u32 = 500000;
+const TTL_BUMP: u32 = 500000;
+/// How long after the last payment the applicant may reclaim an unused fee.
+pub const APPLICATION_FEE_TIMEOUT: u64 = 30 * 86_400;
+
+/// Get the application fee held for an applicant and property, if any.
+pub fn get_application_fee(
+    env: &Env,
+    applicant: Address,
+    property_id: String,
+) -> Option<ApplicationFee> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ApplicationFee(applicant, property_id))
+}
+
+fn save_fee(env: &Env, applicant: &Address, property_id: &String, fee: &ApplicationFee) {
+    let key = DataKey::ApplicationFee(applicant.clone(), property_id.clone());
+    if fee.amount == 0 {
+        env.storage().persistent().remove(&key);
+        return;
+    }
+    env.storage().persistent().set(&key, fee);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_BUMP);
+}
+
+/// Escrow an application fee from `applicant` for `landlord`'s property.
+///
+/// Further payments for the same property add to the held fee, provided they
+/// go to the same landlord in the same token.
+pub fn pay_application_fee(
+    env: &Env,
+    applicant: Address,
+    landlord: Address,
+    property_id: String,
+    token: Address,
+    amount: i128,
+) -> Result<(), RentalError> {
+    applicant.require_auth();
+
+    if amount <= 0 {
+        return Err(RentalError::InvalidAmount);
+    }
+
+    let mut fee = get_application_fee(env, applicant.clone(), property_id.clone()).unwrap_or(
+        ApplicationFee {
+            landlord: landlord.clone(),
+            token: token.clone(),
+            amount: 0,
+            paid_at: 0,
+        },
+    );
+    if fee.landlord != landlord || fee.token != token {
+        return Err(RentalError::InvalidState);
+    }
+
+    token::Client::new(env, &token).transfer(&applicant, env.current_contract_address(), &amount);
+
+    fee.amount += amount;
+    fee.paid_at = env.ledger().timestamp();
+    save_fee(env, &applicant, &property_id, &fee);
+
+    events::application_fee_paid(env, applicant, property_id, amount);
+    Ok(())
+}
+
+/// Return an unused application fee to the applicant (landlord only).
+///
+/// Returns the amount refunded.
+pub fn refund_application_fee(
+    env: &Env,
+    landlord: Address,
+    applicant: Address,
+    property_id: String,
+) -> Result<i128, RentalError> {
+    landlord.require_auth();
+
+    let fee = get_application_fee(env, applicant.clone(), property_id.clone())
+        .ok_or(RentalError::ApplicationFeeNotFound)?;
+    if fee.landlord != landlord {
+        return Err(RentalError::Unauthorized);
+    }
+
+    Ok(pay_back(env, applicant, property_id, &fee))
+}
+
+/// Reclaim an application fee the landlord has not used or refunded
+/// (applicant only), once `APPLICATION_FEE_TIMEOUT` has passed since it was
+/// last paid into. A fee credited to an agreement is no longer held.
+///
+/// Returns the amount refunded.
+pub fn reclaim_application_fee(
+    env: &Env,
+    applicant: Address,
+    property_id: String,
+) -> Result<i128, RentalError> {
+    applicant.require_auth();
+
+    let fee = get_application_fee(env, applicant.clone(), property_id.clone())
+        .ok_or(RentalError::ApplicationFeeNotFound)?;
+    if env.ledger().timestamp() < fee.paid_at.saturating_add(APPLICATION_FEE_TIMEOUT) {
+        return Err(RentalError::CooldownNotMet);
+    }
+
+    Ok(pay_back(env, applicant, property_id, &fee))
+}
+
+fn pay_back(env: &Env, applicant: Address, property_id: String, fee: &ApplicationFee) -> i128 {
+    token::Client::new(env, &fee.token).transfer(
+        &env.current_contract_address(),
+        &applicant,
+        &fee.amount,
+    );
+    env.storage().persistent().remove(&DataKey::ApplicationFee(
+        applicant.clone(),
+        property_id.clone(),
+    ));
+
+    events::application_fee_refunded(env, applicant, property_id, fee.amount);
+    fee.amount
+}
+
+/// Consume the tenant's application fee for the agreement's property, up to
+/// `limit`, so it can count toward the deposit. The fee must have been paid to
+/// the same landlord in the agreement's token. Returns the amount credited.
+pub(crate) fn take_credit(env: &Env, agreement: &RentAgreement, limit: i128) -> i128 {
+    let property_id = match agreement.property_id.clone() {
+        Some(property_id) => property_id,
+        None => return 0,
+    };
+    let mut fee = match get_application_fee(env, agreement.tenant.clone(), property_id.clone()) {
+        Some(fee) => fee,
+        None => return 0,
+    };
+    if fee.landlord != agreement.landlord || fee.token != agreement.payment_token {
+        return 0;
+    }
+
+    let credit = fee.amount.min(limit);
+    fee.amount -= credit;
+    save_fee(env, &agreement.tenant, &property_id, &fee);
+    credit
+}
@@ -0,0 +1,35 @@
+//! Lightweight on-chain audit trail of agreement lifecycle changes.
+
+use soroban_sdk::{Env, String, Symbol, Vec};
+
+use crate::storage::DataKey;
+
+/// Number of most recent entries kept per agreement.
+pub const AUDIT_LOG_WINDOW: u32 = 20;
+
+const TTL_THRESHOLD: u32 = 500000;
+const TTL_BUMP: u32 = 500000;
+
+/// Append a `(timestamp, action)` entry, dropping the oldest beyond the window.
+pub(crate) fn record(env: &Env, agreement_id: &String, action: Symbol) {
+    let key = DataKey::AuditLog(agreement_id.clone());
+    let mut log = get_audit_log(env, agreement_id.clone());
+
+    log.push_back((env.ledger().timestamp(), action));
+    while log.len() > AUDIT_LOG_WINDOW {
+        log.pop_front();
+    }
+
+    env.storage().persistent().set(&key, &log);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_BUMP);
+}
+
+/// Get the recorded lifecycle entries for an agreement, oldest first.
+pub fn get_audit_log(env: &Env, agreement_id: String) -> Vec<(u64, Symbol)> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AuditLog(agreement_id))
+        .unwrap_or(Vec::new(env))
+}
@@ -0,0 +1,240 @@
+//! Rent schedule arithmetic: dues, arrears and payoff figures.
+
+use soroban_sdk::{Address, Env, String, Vec};
+
+use crate::agreement::{
+    get_agreement, get_agreement_count, load_agreement, total_periods, MAX_BATCH_SIZE,
+    RENT_PERIOD_SECONDS,
+};
+use crate::errors::{LedgerError, RentalError};
+use crate::storage::DataKey;
+use crate::types::{
+    AgreementStatus, AllocationPolicy, PaymentSplit, ProrationBasis, RentAgreement,
+};
+
+/// Number of rent periods that have fallen due by `now` (the first is due at start).
+pub(crate) fn periods_due(agreement: &RentAgreement, now: u64) -> u32 {
+    if now < agreement.start_date {
+        return 0;
+    }
+    let due = (now - agreement.start_date) / RENT_PERIOD_SECONDS + 1;
+    (due as u32).min(total_periods(agreement))
+}
+
+/// Number of rent periods that have fallen due but remain unpaid.
+pub fn get_arrears(env: &Env, agreement_id: String) -> Result<u32, RentalError> {
+    let agreement = load_agreement(env, &agreement_id)?;
+    let now = env.ledger().timestamp();
+    let due = periods_due(&agreement, now);
+    // Prepaid rent covering a due period counts as paid, drawn or not
+    let prepaid = crate::escrow::get_prepaid(env, &agreement.agreement_id)
+        .map(|prepaid| crate::escrow::prepaid_draw(&agreement, &prepaid, now).0)
+        .unwrap_or(0);
+    let paid = agreement.total_rent_paid / agreement.monthly_rent + prepaid as i128;
+    Ok((due as i128 - paid).max(0) as u32)
+}
+
+/// Whether `period` is covered once `credits` whole periods have been paid.
+///
+/// Periods paid ahead count wherever they fall; every other credit fills the
+/// lowest period not already paid ahead.
+fn is_period_paid(agreement: &RentAgreement, credits: u32, period: u32) -> bool {
+    if agreement.periods_paid_ahead.contains(period) {
+        return true;
+    }
+    let mut in_order = credits.saturating_sub(agreement.periods_paid_ahead.len());
+    for earlier in 1..period {
+        if in_order == 0 {
+            return false;
+        }
+        if !agreement.periods_paid_ahead.contains(earlier) {
+            in_order -= 1;
+        }
+    }
+    in_order > 0
+}
+
+fn paid_periods(agreement: &RentAgreement) -> u32 {
+    (agreement.total_rent_paid / agreement.monthly_rent) as u32
+}
+
+/// Credit the whole periods `amount` completes according to the agreement's
+/// allocation policy. Call before adding `amount` to `total_rent_paid`.
+///
+/// Under `CurrentFirst` a period that completes while older ones are unpaid
+/// goes to the current period, if that is still open.
+pub(crate) fn allocate_payment(env: &Env, agreement: &mut RentAgreement, amount: i128) {
+    if agreement.allocation_policy != AllocationPolicy::CurrentFirst {
+        return;
+    }
+
+    let credits = paid_periods(agreement);
+    let completed = ((agreement.total_rent_paid + amount) / agreement.monthly_rent) as u32;
+    let current = periods_due(agreement, env.ledger().timestamp());
+    for credit in credits..completed {
+        let behind = (1..current).any(|period| !is_period_paid(agreement, credit, period));
+        if behind && !is_period_paid(agreement, credit, current) {
+            agreement.periods_paid_ahead.push_back(current);
+        }
+    }
+}
+
+/// Number of unpaid periods older than the current one.
+///
+/// Unlike `get_arrears`, this depends on the allocation policy: paying the
+/// current period first leaves the older ones overdue.
+pub fn get_overdue_periods(env: &Env, agreement_id: String) -> Result<u32, RentalError> {
+    let agreement = load_agreement(env, &agreement_id)?;
+    let current = periods_due(&agreement, env.ledger().timestamp());
+    let credits = paid_periods(&agreement);
+    Ok((1..current)
+        .filter(|period| !is_period_paid(&agreement, credits, *period))
+        .count() as u32)
+}
+
+/// Amount the tenant owes right now.
+///
+/// Rent for every period due so far, including the current one, less rent
+/// already paid and any prepaid balance waiting to be drawn. Never negative.
+pub fn get_outstanding_balance(env: &Env, agreement_id: String) -> Result<i128, RentalError> {
+    let agreement = load_agreement(env, &agreement_id)?;
+
+    let due = periods_due(&agreement, env.ledger().timestamp());
+    let due_so_far = due as i128 * agreement.monthly_rent;
+    let prepaid = crate::escrow::get_remaining_prepaid(env, agreement_id);
+
+    Ok((due_so_far - agreement.total_rent_paid - prepaid).max(0))
+}
+
+/// Monthly rent, quoted in the agreement's payment token, expressed in
+/// `pay_token` at the admin-set exchange rate between the two.
+pub fn amount_due_in(
+    env: &Env,
+    agreement_id: String,
+    pay_token: Address,
+) -> Result<i128, RentalError> {
+    let agreement = load_agreement(env, &agreement_id)?;
+    crate::multi_token::convert_amount(
+        env.clone(),
+        agreement.payment_token,
+        pay_token,
+        agreement.monthly_rent,
+    )
+}
+
+/// Rent for `days` of a partial period, on the agreement's pro-ration basis.
+pub fn prorate(agreement: &RentAgreement, days: u32) -> i128 {
+    match agreement.proration_basis {
+        ProrationBasis::ThirtyDay => agreement.monthly_rent * days as i128 / 30,
+        ProrationBasis::Actual => agreement.monthly_rent * 12 * days as i128 / 365,
+    }
+}
+
+/// Charge for a first period of only `days` days, e.g. a lease starting
+/// mid-cycle. A full period or more is charged the whole monthly rent.
+pub fn get_first_period_charge(
+    env: &Env,
+    agreement_id: String,
+    days: u32,
+) -> Result<i128, RentalError> {
+    let agreement = load_agreement(env, &agreement_id)?;
+    if days as u64 * 86_400 >= RENT_PERIOD_SECONDS {
+        return Ok(agreement.monthly_rent);
+    }
+    Ok(prorate(&agreement, days))
+}
+
+/// Single figure a tenant must pay to settle the lease early.
+///
+/// Outstanding arrears plus remaining scheduled rent plus the early-termination
+/// fee, less any credit from rent paid ahead of schedule.
+pub fn get_payoff_quote(env: &Env, agreement_id: String) -> Result<i128, RentalError> {
+    let agreement = load_agreement(env, &agreement_id)?;
+    let now = env.ledger().timestamp();
+
+    let due = periods_due(&agreement, now);
+    let due_so_far = due as i128 * agreement.monthly_rent;
+    let arrears = (due_so_far - agreement.total_rent_paid).max(0);
+    let credit = (agreement.total_rent_paid - due_so_far).max(0);
+    let remaining = (total_periods(&agreement) - due) as i128 * agreement.monthly_rent;
+
+    Ok((arrears + remaining + agreement.early_termination_fee - credit).max(0))
+}
+
+/// Due date of the first period not yet covered by rent paid, if any remain.
+pub(crate) fn next_due_date(agreement: &RentAgreement) -> Option<u64> {
+    let paid = (agreement.total_rent_paid / agreement.monthly_rent) as u64;
+    if paid >= total_periods(agreement) as u64 {
+        return None;
+    }
+    Some(agreement.start_date + paid * RENT_PERIOD_SECONDS)
+}
+
+/// Active agreements whose next rent falls due within `within` seconds from
+/// now, as `(agreement_id, due_date)`.
+///
+/// Scans at most `limit` agreements (capped at 50) from index `start`, so a
+/// keeper pages through with increasing `start`.
+pub fn get_due_reminders(env: &Env, within: u64, start: u32, limit: u32) -> Vec<(String, u64)> {
+    let now = env.ledger().timestamp();
+    let horizon = now.saturating_add(within);
+    let end = start
+        .saturating_add(limit.min(MAX_BATCH_SIZE))
+        .min(get_agreement_count(env));
+    let mut reminders = Vec::new(env);
+
+    for i in start..end {
+        let agreement_id: String = match env.storage().persistent().get(&DataKey::AgreementIndex(i))
+        {
+            Some(id) => id,
+            None => continue,
+        };
+        let agreement = match get_agreement(env, agreement_id.clone()) {
+            Some(agreement) if agreement.status == AgreementStatus::Active => agreement,
+            _ => continue,
+        };
+        if let Some(due) = next_due_date(&agreement) {
+            if due >= now && due <= horizon {
+                reminders.push_back((agreement_id, due));
+            }
+        }
+    }
+    reminders
+}
+
+/// Check an agreement's payment records against its `total_rent_paid`, one
+/// page at a time.
+///
+/// Sums at most `limit` records (capped at 50) from index `start` onto
+/// `carried`, the sum returned for the pages before, and returns the new sum.
+/// On the page holding the last record, fails with
+/// `LedgerError::ReconciliationMismatch` when the full sum differs from
+/// `total_rent_paid`.
+pub fn reconcile(
+    env: &Env,
+    agreement_id: String,
+    start: u32,
+    limit: u32,
+    carried: i128,
+) -> Result<i128, soroban_sdk::Error> {
+    let agreement = load_agreement(env, &agreement_id)?;
+    let end = start
+        .saturating_add(limit.min(MAX_BATCH_SIZE))
+        .min(agreement.payment_count);
+
+    let mut recorded = carried;
+    for i in start..end {
+        let payment: Option<PaymentSplit> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::PaymentRecord(agreement_id.clone(), i + 1));
+        if let Some(payment) = payment {
+            recorded += payment.landlord_amount + payment.platform_amount;
+        }
+    }
+
+    if end == agreement.payment_count && recorded != agreement.total_rent_paid {
+        return Err(LedgerError::ReconciliationMismatch.into());
+    }
+    Ok(recorded)
+}
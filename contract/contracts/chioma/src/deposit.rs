@@ -0,0 +1,471 @@
+//! Security deposit custody and settlement for Chioma rental agreements.
+
+use soroban_sdk::{symbol_short, token, Address, Env, String};
+
+use crate::agreement::{self, load_agreement, save_agreement};
+use crate::audit;
+use crate::errors::RentalError;
+use crate::events;
+use crate::storage::DataKey;
+use crate::types::{AgreementStatus, DepositCustody, PaymentSplit, RentAgreement};
+
+const TTL_THRESHOLD: u32 = 500000;
+const TTL_BUMP: u32 = 500000;
+
+/// Get the custody record for an agreement's held deposit, if any.
+pub fn get_deposit_custody(env: &Env, agreement_id: String) -> Option<DepositCustody> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::DepositHeld(agreement_id))
+}
+
+/// Amount of security deposit currently held for an agreement.
+pub fn get_deposit_held(env: &Env, agreement_id: String) -> i128 {
+    get_deposit_custody(env, agreement_id)
+        .map(|custody| custody.amount)
+        .unwrap_or(0)
+}
+
+/// Get the configured deposit vault, if deposits are not self-custodied.
+pub fn get_vault(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::VaultAddr)
+}
+
+/// Route future deposits to `vault`, or back to self-custody when `None`.
+pub fn set_vault(env: &Env, vault: Option<Address>) {
+    match vault {
+        Some(vault) => env.storage().instance().set(&DataKey::VaultAddr, &vault),
+        None => env.storage().instance().remove(&DataKey::VaultAddr),
+    }
+    env.storage().instance().extend_ttl(TTL_THRESHOLD, TTL_BUMP);
+}
+
+/// Pay up to `limit` of the held deposit to `recipient` from wherever it is
+/// custodied, keeping any rest held. Returns the amount paid.
+fn pay_from_deposit(
+    env: &Env,
+    agreement: &RentAgreement,
+    recipient: &Address,
+    limit: i128,
+) -> i128 {
+    let key = DataKey::DepositHeld(agreement.agreement_id.clone());
+    let mut custody = match get_deposit_custody(env, agreement.agreement_id.clone()) {
+        Some(custody) => custody,
+        None => return 0,
+    };
+    let amount = custody.amount.min(limit);
+
+    // A vault releases through the allowance it has granted this contract
+    let client = token::Client::new(env, &agreement.payment_token);
+    let contract = env.current_contract_address();
+    if custody.custodian == contract {
+        client.transfer(&contract, recipient, &amount);
+    } else {
+        client.transfer_from(&contract, &custody.custodian, recipient, &amount);
+    }
+
+    custody.amount -= amount;
+    if custody.amount == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &custody);
+    }
+
+    amount
+}
+
+/// Pay out the whole held deposit to `recipient`.
+fn pay_out_deposit(env: &Env, agreement: &RentAgreement, recipient: &Address) -> i128 {
+    pay_from_deposit(env, agreement, recipient, i128::MAX)
+}
+
+/// Cover the final period's unpaid rent from the held deposit, paying the
+/// landlord and recording it as a rent payment. Returns the amount applied.
+fn apply_deposit_to_rent(env: &Env, agreement: &mut RentAgreement) -> i128 {
+    let scheduled = agreement::total_periods(agreement) as i128 * agreement.monthly_rent;
+    let outstanding = (scheduled - agreement.total_rent_paid).clamp(0, agreement.monthly_rent);
+    if outstanding == 0 {
+        return 0;
+    }
+
+    let landlord = agreement.landlord.clone();
+    let applied = pay_from_deposit(env, agreement, &landlord, outstanding);
+    if applied == 0 {
+        return 0;
+    }
+
+    agreement.total_rent_paid += applied;
+    agreement.payment_count += 1;
+    crate::stats::record_payment(env, &agreement.payment_token, applied);
+    let record_key =
+        DataKey::PaymentRecord(agreement.agreement_id.clone(), agreement.payment_count);
+    env.storage().persistent().set(
+        &record_key,
+        &PaymentSplit {
+            landlord_amount: applied,
+            platform_amount: 0,
+            token: agreement.payment_token.clone(),
+            payment_date: env.ledger().timestamp(),
+            payer: agreement.tenant.clone(),
+        },
+    );
+    env.storage()
+        .persistent()
+        .extend_ttl(&record_key, TTL_THRESHOLD, TTL_BUMP);
+
+    applied
+}
+
+/// Mark whether an agreement's deposit is refundable (landlord only, before activation).
+pub fn set_deposit_refundable(
+    env: &Env,
+    agreement_id: String,
+    refundable: bool,
+) -> Result<(), RentalError> {
+    let mut agreement = load_agreement(env, &agreement_id)?;
+    agreement.landlord.require_auth();
+
+    if agreement.status != AgreementStatus::Draft && agreement.status != AgreementStatus::Pending {
+        return Err(RentalError::InvalidState);
+    }
+
+    agreement.deposit_refundable = refundable;
+    save_agreement(env, &agreement);
+    Ok(())
+}
+
+/// Apply the deposit to the final period's rent at settlement (landlord only,
+/// before activation).
+pub fn set_apply_deposit_to_last(
+    env: &Env,
+    agreement_id: String,
+    enabled: bool,
+) -> Result<(), RentalError> {
+    let mut agreement = load_agreement(env, &agreement_id)?;
+    agreement.landlord.require_auth();
+
+    if agreement.status != AgreementStatus::Draft && agreement.status != AgreementStatus::Pending {
+        return Err(RentalError::InvalidState);
+    }
+
+    agreement.apply_deposit_to_last = enabled;
+    save_agreement(env, &agreement);
+    Ok(())
+}
+
+/// Activate the agreement as soon as the tenant funds the deposit (landlord
+/// only, before activation).
+pub fn set_auto_activate_on_funding(
+    env: &Env,
+    agreement_id: String,
+    enabled: bool,
+) -> Result<(), RentalError> {
+    let mut agreement = load_agreement(env, &agreement_id)?;
+    agreement.landlord.require_auth();
+
+    if agreement.status != AgreementStatus::Draft && agreement.status != AgreementStatus::Pending {
+        return Err(RentalError::InvalidState);
+    }
+
+    agreement.auto_activate_on_funding = enabled;
+    save_agreement(env, &agreement);
+    Ok(())
+}
+
+/// Back a deposit-free agreement with a guarantor (landlord and guarantor,
+/// before activation).
+pub fn set_guarantor(
+    env: &Env,
+    agreement_id: String,
+    guarantor: Address,
+) -> Result<(), RentalError> {
+    let mut agreement = load_agreement(env, &agreement_id)?;
+    agreement.landlord.require_auth();
+    guarantor.require_auth();
+
+    if agreement.status != AgreementStatus::Draft && agreement.status != AgreementStatus::Pending {
+        return Err(RentalError::InvalidState);
+    }
+    if agreement.security_deposit != 0 {
+        return Err(RentalError::InvalidState);
+    }
+
+    agreement.guarantor = Some(guarantor);
+    save_agreement(env, &agreement);
+    Ok(())
+}
+
+/// Collect overdue rent from the guarantor of a deposit-free agreement
+/// (landlord only).
+///
+/// The guarantor must have approved this contract to spend `amount` of the
+/// agreement's token. The charge is paid straight to the landlord, recorded
+/// as a rent payment, and may not exceed the outstanding balance.
+pub fn charge_guarantor(
+    env: &Env,
+    agreement_id: String,
+    token: Address,
+    amount: i128,
+) -> Result<(), RentalError> {
+    let mut agreement = load_agreement(env, &agreement_id)?;
+    agreement.landlord.require_auth();
+
+    let guarantor = match (&agreement.guarantor, agreement.security_deposit) {
+        (Some(guarantor), 0) => guarantor.clone(),
+        _ => return Err(RentalError::InvalidState),
+    };
+    if agreement.status != AgreementStatus::Active
+        && agreement.status != AgreementStatus::Terminated
+    {
+        return Err(RentalError::AgreementNotActive);
+    }
+    if token != agreement.payment_token {
+        return Err(RentalError::TokenNotSupported);
+    }
+    let outstanding = crate::billing::get_outstanding_balance(env, agreement_id.clone())?;
+    if amount <= 0 || amount > outstanding {
+        return Err(RentalError::InvalidAmount);
+    }
+
+    token::Client::new(env, &token).transfer_from(
+        &env.current_contract_address(),
+        &guarantor,
+        &agreement.landlord,
+        &amount,
+    );
+
+    agreement.total_rent_paid += amount;
+    agreement.payment_count += 1;
+    crate::stats::record_payment(env, &agreement.payment_token, amount);
+    let record_key = DataKey::PaymentRecord(agreement_id.clone(), agreement.payment_count);
+    env.storage().persistent().set(
+        &record_key,
+        &PaymentSplit {
+            landlord_amount: amount,
+            platform_amount: 0,
+            token,
+            payment_date: env.ledger().timestamp(),
+            payer: guarantor.clone(),
+        },
+    );
+    env.storage()
+        .persistent()
+        .extend_ttl(&record_key, TTL_THRESHOLD, TTL_BUMP);
+    save_agreement(env, &agreement);
+
+    audit::record(env, &agreement_id, symbol_short!("guar_chg"));
+    events::guarantor_charged(env, agreement_id, guarantor, amount);
+    Ok(())
+}
+
+/// Transfer the agreement's security deposit from the tenant into contract custody.
+///
+/// Any application fee the tenant paid the landlord for the agreement's
+/// property is credited toward the deposit first. A Pending agreement set to
+/// auto-activate becomes Active once funded.
+pub fn deposit_security(env: &Env, agreement_id: String) -> Result<(), RentalError> {
+    let mut agreement = load_agreement(env, &agreement_id)?;
+    agreement.tenant.require_auth();
+
+    if agreement.status != AgreementStatus::Pending && agreement.status != AgreementStatus::Active {
+        return Err(RentalError::InvalidState);
+    }
+
+    if agreement.security_deposit <= 0 {
+        return Err(RentalError::InvalidAmount);
+    }
+    crate::policy::check_min_deposit(env, agreement.security_deposit, agreement.token_decimals)?;
+
+    if get_deposit_held(env, agreement_id.clone()) > 0 {
+        return Err(RentalError::InvalidState);
+    }
+
+    // Deposits go to the configured vault, or stay on the contract itself. An
+    // application fee the tenant paid for this property counts toward it.
+    let contract = env.current_contract_address();
+    let custodian = get_vault(env).unwrap_or(contract.clone());
+    let client = token::Client::new(env, &agreement.payment_token);
+    let credit = crate::application::take_credit(env, &agreement, agreement.security_deposit);
+    if agreement.security_deposit > credit {
+        client.transfer(
+            &agreement.tenant,
+            &custodian,
+            &(agreement.security_deposit - credit),
+        );
+    }
+    if credit > 0 && custodian != contract {
+        client.transfer(&contract, &custodian, &credit);
+    }
+
+    let key = DataKey::DepositHeld(agreement_id.clone());
+    env.storage().persistent().set(
+        &key,
+        &DepositCustody {
+            amount: agreement.security_deposit,
+            custodian,
+        },
+    );
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_BUMP);
+
+    events::deposit_received(
+        env,
+        agreement_id,
+        agreement.tenant.clone(),
+        agreement.security_deposit,
+    );
+
+    if agreement.status == AgreementStatus::Pending && agreement.auto_activate_on_funding {
+        crate::agreement::activate(env, &mut agreement)?;
+    }
+    Ok(())
+}
+
+/// Sweep a non-refundable deposit to the landlord once the agreement has
+/// ended; a refundable one stays held for `release_deposit`. Returns the
+/// amount swept.
+pub(crate) fn sweep_on_end(env: &Env, agreement_id: &String) -> Result<i128, RentalError> {
+    let agreement = load_agreement(env, agreement_id)?;
+    if agreement.deposit_refundable {
+        return Ok(0);
+    }
+
+    let swept = pay_out_deposit(env, &agreement, &agreement.landlord);
+    if swept > 0 {
+        events::deposit_released(env, agreement_id.clone(), agreement.landlord, swept);
+    }
+    Ok(swept)
+}
+
+/// Return the whole held deposit to the tenant of an agreement cancelled
+/// before it began, refundable or not. Returns the amount returned.
+pub(crate) fn refund_on_cancel(env: &Env, agreement: &RentAgreement) -> i128 {
+    let refunded = pay_out_deposit(env, agreement, &agreement.tenant);
+    if refunded > 0 {
+        events::deposit_released(
+            env,
+            agreement.agreement_id.clone(),
+            agreement.tenant.clone(),
+            refunded,
+        );
+    }
+    refunded
+}
+
+/// Return a refundable deposit held for an ended agreement to the tenant. A
+/// Completed agreement's deposit waits out the inspection period first.
+fn return_deposit(env: &Env, agreement: &RentAgreement) -> Result<i128, RentalError> {
+    if !agreement.deposit_refundable {
+        return Err(RentalError::InvalidState);
+    }
+    if agreement.status != AgreementStatus::Completed
+        && agreement.status != AgreementStatus::Terminated
+    {
+        return Err(RentalError::InvalidState);
+    }
+
+    if let Some(completed_at) = agreement.completed_at {
+        let inspection_period = crate::policy::get_policy(env).inspection_period;
+        if env.ledger().timestamp() < completed_at.saturating_add(inspection_period) {
+            return Err(RentalError::InspectionPending);
+        }
+    }
+
+    let released = pay_out_deposit(env, agreement, &agreement.tenant);
+    if released > 0 {
+        events::deposit_released(
+            env,
+            agreement.agreement_id.clone(),
+            agreement.tenant.clone(),
+            released,
+        );
+    }
+    Ok(released)
+}
+
+/// Return a refundable deposit to the tenant once the agreement has ended
+/// (landlord only).
+///
+/// Once the agreement is Completed, release waits out the inspection period.
+pub fn release_deposit(env: &Env, agreement_id: String) -> Result<i128, RentalError> {
+    let agreement = load_agreement(env, &agreement_id)?;
+    agreement.landlord.require_auth();
+    return_deposit(env, &agreement)
+}
+
+/// Claim back a refundable deposit once the agreement has ended and any
+/// inspection period has passed (tenant only), so the deposit does not stay
+/// held when the landlord never releases it.
+pub fn claim_deposit_refund(env: &Env, agreement_id: String) -> Result<i128, RentalError> {
+    let agreement = load_agreement(env, &agreement_id)?;
+    agreement.tenant.require_auth();
+    return_deposit(env, &agreement)
+}
+
+/// Complete an Active agreement whose term has ended and settle its funds.
+///
+/// When the agreement applies its deposit to the last month, the deposit first
+/// covers any unpaid final-period rent, paid to the landlord. The rest of a
+/// refundable deposit goes back to the tenant; a non-refundable one is swept
+/// to the landlord. While an inspection period is configured, a refundable
+/// deposit stays held for `release_deposit` instead. Rent still held in escrow
+/// is disbursed to the landlord, as is prepaid rent for periods already due;
+/// any prepaid rent left over goes back to the tenant. Either party may
+/// settle. Emits a consolidated settlement event.
+pub fn settle_agreement(
+    env: &Env,
+    caller: Address,
+    agreement_id: String,
+) -> Result<(), RentalError> {
+    caller.require_auth();
+
+    let mut agreement = load_agreement(env, &agreement_id)?;
+
+    if caller != agreement.landlord && caller != agreement.tenant {
+        return Err(RentalError::Unauthorized);
+    }
+
+    if agreement.status != AgreementStatus::Active {
+        return Err(RentalError::AgreementNotActive);
+    }
+
+    if env.ledger().timestamp() < agreement.end_date {
+        return Err(RentalError::InvalidState);
+    }
+
+    if agreement.apply_deposit_to_last && apply_deposit_to_rent(env, &mut agreement) > 0 {
+        audit::record(env, &agreement_id, symbol_short!("dep_rent"));
+    }
+
+    let recipient = if agreement.deposit_refundable {
+        agreement.tenant.clone()
+    } else {
+        agreement.landlord.clone()
+    };
+    let inspecting =
+        agreement.deposit_refundable && crate::policy::get_policy(env).inspection_period > 0;
+    let deposit_returned = if inspecting {
+        0
+    } else {
+        pay_out_deposit(env, &agreement, &recipient)
+    };
+
+    crate::escrow::draw_prepaid(env, &mut agreement);
+    let escrow_disbursed = crate::escrow::pay_out_rent(env, &agreement);
+
+    agreement::transition(env, &mut agreement, AgreementStatus::Completed)?;
+    agreement.completed_at = Some(env.ledger().timestamp());
+    save_agreement(env, &agreement);
+    crate::escrow::release_on_end(env, &agreement_id)?;
+
+    audit::record(env, &agreement_id, symbol_short!("settled"));
+    events::agreement_settled(
+        env,
+        &agreement,
+        recipient,
+        deposit_returned,
+        escrow_disbursed,
+    );
+    Ok(())
+}
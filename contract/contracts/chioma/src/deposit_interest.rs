@@ -39,7 +39,8 @@ fn periods_per_year(freq: &CompoundingFrequency) -> u64 {
 /// Set (or overwrite) the interest configuration for an agreement.
 ///
 /// Calling this for the first time also initialises the `DepositInterest`
-/// record using the agreement's `security_deposit` as principal.
+/// record using the agreement's `security_deposit` as principal. Overwriting
+/// keeps any interest grace already set.
 pub fn set_deposit_interest_config(
     env: Env,
     agreement_id: String,
@@ -51,11 +52,15 @@ pub fn set_deposit_interest_config(
         return Err(RentalError::InvalidAmount);
     }
 
+    let interest_grace = get_deposit_interest_config(env.clone(), agreement_id.clone())
+        .map(|config| config.interest_grace)
+        .unwrap_or(0);
     let config = DepositInterestConfig {
         agreement_id: agreement_id.clone(),
         annual_rate,
         compounding_frequency,
         interest_recipient,
+        interest_grace,
     };
 
     env.storage().persistent().set(
@@ -70,11 +75,7 @@ pub fn set_deposit_interest_config(
         .has(&DataKey::DepositInterest(agreement_id.clone()))
     {
         // Read principal from the agreement.
-        let agreement = env
-            .storage()
-            .persistent()
-            .get::<DataKey, crate::types::RentAgreement>(&DataKey::Agreement(agreement_id.clone()))
-            .ok_or(RentalError::AgreementNotFound)?;
+        let agreement = crate::agreement::load_agreement(&env, &agreement_id)?;
 
         let principal = agreement.security_deposit;
         if principal <= 0 {
@@ -88,6 +89,7 @@ pub fn set_deposit_interest_config(
             total_with_interest: principal,
             last_accrual_date: env.ledger().timestamp(),
             accrual_history: Vec::new(&env),
+            held_since: env.ledger().timestamp(),
         };
 
         env.storage()
@@ -110,6 +112,26 @@ pub fn get_deposit_interest_config(
         .ok_or(RentalError::InterestConfigNotFound)
 }
 
+/// Set how long the deposit must be held before interest starts accruing.
+///
+/// The grace runs from when interest tracking began for the agreement;
+/// interest then accrues from the end of the grace, not from the start.
+pub fn set_interest_grace(
+    env: Env,
+    agreement_id: String,
+    interest_grace: u64,
+) -> Result<(), RentalError> {
+    let mut config = get_deposit_interest_config(env.clone(), agreement_id.clone())?;
+    config.interest_grace = interest_grace;
+    env.storage().persistent().set(
+        &DataKey::DepositInterestConfig(agreement_id.clone()),
+        &config,
+    );
+
+    events::interest_config_set(&env, agreement_id, config.annual_rate);
+    Ok(())
+}
+
 // ─── Calculation ──────────────────────────────────────────────────────────────
 
 /// Calculate accrued interest without mutating state.
@@ -132,11 +154,18 @@ pub fn calculate_accrued_interest(env: Env, escrow_id: String) -> Result<i128, R
     Ok(compute_interest(
         &config,
         di.total_with_interest, // use running balance for compounding
-        di.last_accrual_date,
+        accrual_start(&config, &di),
         env.ledger().timestamp(),
     ))
 }
 
+/// The point interest accrues from: the last accrual, but never earlier than
+/// the end of the interest grace.
+fn accrual_start(config: &DepositInterestConfig, di: &DepositInterest) -> u64 {
+    di.last_accrual_date
+        .max(di.held_since.saturating_add(config.interest_grace))
+}
+
 /// Core arithmetic (shared by calculate and accrue).
 ///
 /// Returns the raw interest amount for the elapsed time.
@@ -181,7 +210,12 @@ pub fn accrue_interest(env: Env, escrow_id: String) -> Result<InterestAccrual, R
         .ok_or(RentalError::InterestConfigNotFound)?;
 
     let now = env.ledger().timestamp();
-    let amount = compute_interest(&config, di.total_with_interest, di.last_accrual_date, now);
+    let amount = compute_interest(
+        &config,
+        di.total_with_interest,
+        accrual_start(&config, &di),
+        now,
+    );
 
     let accrual = InterestAccrual {
         accrued_at: now,
@@ -271,11 +305,7 @@ pub fn distribute_interest(env: Env, escrow_id: String) -> Result<(), RentalErro
         return Ok(());
     }
 
-    let agreement = env
-        .storage()
-        .persistent()
-        .get::<DataKey, crate::types::RentAgreement>(&DataKey::Agreement(escrow_id.clone()))
-        .ok_or(RentalError::AgreementNotFound)?;
+    let agreement = crate::agreement::load_agreement(&env, &escrow_id)?;
 
     let (tenant_share, landlord_share) = match config.interest_recipient {
         InterestRecipient::Tenant => (total, 0_i128),
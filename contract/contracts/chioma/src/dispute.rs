@@ -0,0 +1,235 @@
+//! Agreement disputes and the acknowledgment step that closes them.
+
+use soroban_sdk::{symbol_short, Address, Env, String};
+
+use crate::agreement::{load_agreement, save_agreement, transition};
+use crate::audit;
+use crate::errors::RentalError;
+use crate::events;
+use crate::storage::DataKey;
+use crate::types::{AgreementStatus, DisputeResolution, RentAgreement};
+
+const TTL_THRESHOLD: u32 = 500000;
+const TTL_BUMP: u32 = 500000;
+
+/// Get the pending resolution of a disputed agreement, if any.
+pub fn get_resolution(env: &Env, agreement_id: String) -> Option<DisputeResolution> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Resolution(agreement_id))
+}
+
+/// Put an Active agreement into dispute (landlord or tenant), dropping any
+/// pending break request.
+pub fn raise_dispute(env: &Env, agreement_id: String, caller: Address) -> Result<(), RentalError> {
+    caller.require_auth();
+    let mut agreement = load_agreement(env, &agreement_id)?;
+
+    if caller != agreement.landlord && caller != agreement.tenant {
+        return Err(RentalError::Unauthorized);
+    }
+    if agreement.status != AgreementStatus::Active {
+        return Err(RentalError::AgreementNotActive);
+    }
+
+    transition(env, &mut agreement, AgreementStatus::Disputed)?;
+    // A pending break is settled by the dispute's outcome instead
+    agreement.break_requested = false;
+    agreement.break_requested_at = None;
+    save_agreement(env, &agreement);
+
+    let key = DataKey::DisputeRaisedAt(agreement_id.clone());
+    env.storage()
+        .persistent()
+        .set(&key, &env.ledger().timestamp());
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_BUMP);
+
+    audit::record(env, &agreement_id, symbol_short!("disputed"));
+    events::dispute_raised(env, agreement_id, caller);
+    Ok(())
+}
+
+/// Whether an open dispute has been escalated to the admin.
+pub fn is_escalated(env: &Env, agreement_id: String) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::DisputeEscalated(agreement_id))
+}
+
+/// Flag a dispute for the admin once it has gone unresolved for the
+/// configured escalation window (landlord or tenant). The agent can no
+/// longer arbitrate it.
+pub fn escalate_dispute(
+    env: &Env,
+    agreement_id: String,
+    caller: Address,
+) -> Result<(), RentalError> {
+    caller.require_auth();
+    let agreement = load_agreement(env, &agreement_id)?;
+
+    if caller != agreement.landlord && caller != agreement.tenant {
+        return Err(RentalError::Unauthorized);
+    }
+    if agreement.status != AgreementStatus::Disputed || is_escalated(env, agreement_id.clone()) {
+        return Err(RentalError::InvalidState);
+    }
+
+    let window = crate::policy::get_policy(env).dispute_escalation_window;
+    let raised_at: u64 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::DisputeRaisedAt(agreement_id.clone()))
+        .ok_or(RentalError::InvalidState)?;
+    if window == 0 || env.ledger().timestamp() < raised_at.saturating_add(window) {
+        return Err(RentalError::InvalidState);
+    }
+
+    let key = DataKey::DisputeEscalated(agreement_id.clone());
+    env.storage().persistent().set(&key, &true);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_BUMP);
+
+    audit::record(env, &agreement_id, symbol_short!("escalated"));
+    events::dispute_escalated(env, agreement_id, caller);
+    Ok(())
+}
+
+/// Decide a dispute as the agreement's agent, who arbitrates it until a party
+/// escalates it to the admin.
+pub fn arbitrate_dispute(
+    env: &Env,
+    agreement_id: String,
+    agent: Address,
+    terminate: bool,
+) -> Result<(), RentalError> {
+    agent.require_auth();
+    let agreement = load_agreement(env, &agreement_id)?;
+
+    if agreement.agent.as_ref() != Some(&agent) {
+        return Err(RentalError::Unauthorized);
+    }
+    // Escalated disputes are left to the admin
+    if is_escalated(env, agreement_id.clone()) {
+        return Err(RentalError::InvalidState);
+    }
+
+    resolve_dispute(env, agreement_id, terminate)
+}
+
+/// Decide a dispute, resuming or terminating the agreement once both parties
+/// acknowledge. Caller must have checked admin or arbiter authorization.
+pub fn resolve_dispute(
+    env: &Env,
+    agreement_id: String,
+    terminate: bool,
+) -> Result<(), RentalError> {
+    let mut agreement = load_agreement(env, &agreement_id)?;
+
+    let outcome = if terminate {
+        AgreementStatus::Terminated
+    } else {
+        AgreementStatus::Active
+    };
+    transition(env, &mut agreement, AgreementStatus::ResolutionPending)?;
+    save_agreement(env, &agreement);
+    env.storage()
+        .persistent()
+        .remove(&DataKey::DisputeRaisedAt(agreement_id.clone()));
+    env.storage()
+        .persistent()
+        .remove(&DataKey::DisputeEscalated(agreement_id.clone()));
+
+    let key = DataKey::Resolution(agreement_id.clone());
+    env.storage().persistent().set(
+        &key,
+        &DisputeResolution {
+            outcome: outcome.clone(),
+            landlord_acknowledged: false,
+            tenant_acknowledged: false,
+        },
+    );
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_BUMP);
+
+    audit::record(env, &agreement_id, symbol_short!("resolved"));
+    events::dispute_resolved(env, agreement_id, outcome);
+    Ok(())
+}
+
+/// Acknowledge a dispute's resolution as the landlord or tenant.
+///
+/// The second acknowledgment applies the outcome; until then the agreement
+/// stays in `ResolutionPending` and accepts no payments.
+pub fn acknowledge_resolution(
+    env: &Env,
+    agreement_id: String,
+    caller: Address,
+) -> Result<(), RentalError> {
+    caller.require_auth();
+    let mut agreement = load_agreement(env, &agreement_id)?;
+
+    if agreement.status != AgreementStatus::ResolutionPending {
+        return Err(RentalError::InvalidState);
+    }
+    let key = DataKey::Resolution(agreement_id.clone());
+    let mut resolution: DisputeResolution = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .ok_or(RentalError::InvalidState)?;
+
+    if caller == agreement.landlord {
+        resolution.landlord_acknowledged = true;
+    } else if caller == agreement.tenant {
+        resolution.tenant_acknowledged = true;
+    } else {
+        return Err(RentalError::Unauthorized);
+    }
+    events::resolution_acknowledged(env, agreement_id.clone(), caller);
+
+    if !(resolution.landlord_acknowledged && resolution.tenant_acknowledged) {
+        env.storage().persistent().set(&key, &resolution);
+        return Ok(());
+    }
+
+    apply_resolution(env, &mut agreement, resolution.outcome)?;
+    audit::record(env, &agreement_id, symbol_short!("res_ack"));
+    Ok(())
+}
+
+/// Apply a pending resolution's outcome without waiting for the remaining
+/// acknowledgments, so a party cannot hold the agreement in
+/// `ResolutionPending` by never acknowledging. Caller must have checked admin
+/// authorization.
+pub fn finalize_resolution(env: &Env, agreement_id: String) -> Result<(), RentalError> {
+    let mut agreement = load_agreement(env, &agreement_id)?;
+    if agreement.status != AgreementStatus::ResolutionPending {
+        return Err(RentalError::InvalidState);
+    }
+    let resolution = get_resolution(env, agreement_id.clone()).ok_or(RentalError::InvalidState)?;
+
+    apply_resolution(env, &mut agreement, resolution.outcome)?;
+    audit::record(env, &agreement_id, symbol_short!("res_final"));
+    Ok(())
+}
+
+fn apply_resolution(
+    env: &Env,
+    agreement: &mut RentAgreement,
+    outcome: AgreementStatus,
+) -> Result<(), RentalError> {
+    let agreement_id = agreement.agreement_id.clone();
+    transition(env, agreement, outcome.clone())?;
+    save_agreement(env, agreement);
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Resolution(agreement_id.clone()));
+    if outcome == AgreementStatus::Terminated {
+        crate::escrow::release_on_end(env, &agreement_id)?;
+    }
+    Ok(())
+}
@@ -8,7 +8,8 @@ use soroban_sdk::{contracterror, Env, String, Vec};
 pub enum RentalError {
     // Already existed
     AlreadyInitialized = 1,
-    InvalidAdmin = 2,
+    // 2 was InvalidAdmin; retired rather than reused so older clients never
+    // misread it
     InvalidConfig = 3,
     AgreementAlreadyExists = 4,
     InvalidAmount = 5,
@@ -28,14 +29,12 @@ pub enum RentalError {
     AlreadyPaused = 23,
     NotPaused = 24,
     InterestConfigNotFound = 25,
-    InterestAlreadyInitialized = 26,
     NoPrincipal = 27,
+    NotInitialized = 28,
 
-    // Payment errors
-    PaymentInsufficientFunds = 201,
-    PaymentAlreadyProcessed = 202,
-    PaymentFailed = 203,
-    PaymentInvalidAmount = 204,
+    // Payment errors: 201 and 203 were PaymentInsufficientFunds and
+    // PaymentFailed, never raised; retired to free variant slots under the
+    // 50-case limit of a contract error enum
 
     // Timelock errors (reusing range 301-304, replacing unused dispute codes)
     TimelockNotFound = 301,
@@ -43,24 +42,32 @@ pub enum RentalError {
     TimelockAlreadyCancelled = 303,
     TimelockEtaNotReached = 304,
 
-    // Escrow errors
-    EscrowNotFound = 401,
-    EscrowAlreadyReleased = 402,
-    EscrowInsufficientFunds = 403,
-    EscrowTimeoutNotReached = 404,
+    // Escrow errors: 401 and 402 were EscrowNotFound and
+    // EscrowAlreadyReleased, never raised; retired like 201 and 203
 
     // Authorization & State
-    InsufficientPermissions = 501,
-    AdminOnly = 502,
-    InvalidTransition = 601,
+    InvalidStatusTransition = 601,
     InvalidInput = 701,
-    InvalidAddress = 702,
 
     // Rate limiting & Generic
     RateLimitExceeded = 801,
     CooldownNotMet = 802,
     InternalError = 901,
     TimelockDelayTooShort = 902,
+    Reentrancy = 903,
+
+    // Lease errors (range 1000+, replacing unused permission codes)
+    PropertyAlreadyLeased = 1001,
+    InvalidParties = 1002,
+    TenantNotAllowed = 1003,
+    IncreaseTooLarge = 1004,
+    InspectionPending = 1005,
+    DurationTooShort = 1006,
+    ArrearsThresholdNotReached = 1007,
+    ApplicationFeeNotFound = 1008,
+    NoticePeriodActive = 1009,
+    InvalidTick = 1010,
+    StartInPast = 1011,
 
     // Multi-sig errors (using range 1100-1105 only)
     MultiSigNotInitialized = 1100,
@@ -71,11 +78,22 @@ pub enum RentalError {
     AlreadyApproved = 1105,
 }
 
+/// Errors added once `RentalError` reached the 50-case limit of a contract
+/// error enum. Functions that can raise them return `soroban_sdk::Error`,
+/// which carries a code from either enum.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum LedgerError {
+    BalanceRemaining = 1201,
+    ReconciliationMismatch = 1202,
+}
+
 impl RentalError {
     pub fn message(&self, env: &Env) -> String {
         let msg = match self {
             RentalError::AlreadyInitialized => "Contract already initialized.",
-            RentalError::InvalidAdmin => "Invalid admin address provided.",
+            RentalError::NotInitialized => "Contract has not been initialized.",
             RentalError::InvalidConfig => "Invalid configuration parameter.",
             RentalError::AgreementAlreadyExists => "Agreement already exists for the given ID.",
             RentalError::InvalidAmount => "Invalid amount provided for the operation.",
@@ -105,18 +123,8 @@ impl RentalError {
             RentalError::InterestConfigNotFound => {
                 "Interest configuration for the agreement not found."
             }
-            RentalError::InterestAlreadyInitialized => {
-                "Deposit interest is already initialized for this agreement."
-            }
             RentalError::NoPrincipal => "No security deposit found to accrue interest on.",
 
-            RentalError::PaymentInsufficientFunds => {
-                "Insufficient funds. Please ensure you have enough balance."
-            }
-            RentalError::PaymentAlreadyProcessed => "This payment has already been processed.",
-            RentalError::PaymentFailed => "Payment transfer failed. Check permissions and balance.",
-            RentalError::PaymentInvalidAmount => "The payment amount is invalid or zero.",
-
             RentalError::TimelockNotFound => "Timelock action not found.",
             RentalError::TimelockAlreadyExecuted => {
                 "This timelock action has already been executed."
@@ -126,20 +134,10 @@ impl RentalError {
             }
             RentalError::TimelockEtaNotReached => "The timelock ETA has not been reached yet.",
 
-            RentalError::EscrowNotFound => "Escrow account not found for this agreement.",
-            RentalError::EscrowAlreadyReleased => "Escrow funds have already been released.",
-            RentalError::EscrowInsufficientFunds => {
-                "Insufficient funds in escrow for this withdrawal."
+            RentalError::InvalidStatusTransition => {
+                "Agreement cannot move to the requested status from its current one."
             }
-            RentalError::EscrowTimeoutNotReached => "Escrow period has not yet expired.",
-
-            RentalError::InsufficientPermissions => {
-                "Insufficient permissions to perform this action."
-            }
-            RentalError::AdminOnly => "This operation is restricted to contract administrators.",
-            RentalError::InvalidTransition => "Invalid state transition for the current record.",
             RentalError::InvalidInput => "Invalid input data provided to the function.",
-            RentalError::InvalidAddress => "A provided address is invalid or malformed.",
 
             RentalError::RateLimitExceeded => "Rate limit exceeded. Please wait before retrying.",
             RentalError::CooldownNotMet => "Operation cooldown period has not yet met.",
@@ -147,6 +145,29 @@ impl RentalError {
             RentalError::TimelockDelayTooShort => {
                 "The specified delay is below the minimum required for this action type."
             }
+            RentalError::Reentrancy => "A payout is already in progress; reentry is not allowed.",
+
+            RentalError::PropertyAlreadyLeased => {
+                "The property already has an active lease overlapping these dates."
+            }
+            RentalError::InvalidParties => {
+                "The agreement is missing a party required for this operation."
+            }
+            RentalError::TenantNotAllowed => "The tenant is not on the landlord's allowlist.",
+            RentalError::IncreaseTooLarge => "The rent increase exceeds the configured cap.",
+            RentalError::InspectionPending => {
+                "The deposit is held until the post-move-out inspection period ends."
+            }
+            RentalError::DurationTooShort => "The lease term is shorter than the minimum allowed.",
+            RentalError::ArrearsThresholdNotReached => {
+                "Unpaid periods have not reached the termination threshold."
+            }
+            RentalError::ApplicationFeeNotFound => {
+                "No application fee is held for this applicant and property."
+            }
+            RentalError::NoticePeriodActive => "The termination notice period has not yet elapsed.",
+            RentalError::InvalidTick => "The rent is not a multiple of the configured tick size.",
+            RentalError::StartInPast => "The lease must not start before it is created.",
 
             RentalError::MultiSigNotInitialized => {
                 "Multi-sig has not been initialized for this contract."
@@ -0,0 +1,620 @@
+//! Escrowed rent disbursement, and prepaid rent drawn down as periods fall due.
+
+use soroban_sdk::{symbol_short, token, Address, Env, String, Vec};
+
+use crate::agreement::{
+    get_agreement, get_escrow_balance, get_escrow_tokens, get_token_escrow_balance, load_agreement,
+    save_agreement, set_escrow_balance, set_token_escrow_balance, total_periods,
+};
+use crate::audit;
+use crate::billing::periods_due;
+use crate::errors::RentalError;
+use crate::events;
+use crate::storage::DataKey;
+use crate::types::{AgreementStatus, EscrowBreakdown, PaymentSplit, PrepaidRent, RentAgreement};
+
+const TTL_THRESHOLD: u32 = 500000;
+const TTL_BUMP: u32 = 500000;
+
+/// Require landlord and tenant approval before escrowed rent is disbursed
+/// (landlord only, before activation).
+pub fn set_dual_release_approval(
+    env: &Env,
+    agreement_id: String,
+    enabled: bool,
+) -> Result<(), RentalError> {
+    let mut agreement = load_agreement(env, &agreement_id)?;
+    agreement.landlord.require_auth();
+
+    if agreement.status != AgreementStatus::Draft && agreement.status != AgreementStatus::Pending {
+        return Err(RentalError::InvalidState);
+    }
+
+    agreement.dual_release_approval = enabled;
+    save_agreement(env, &agreement);
+    Ok(())
+}
+
+/// Parties that have approved disbursing an agreement's escrowed rent.
+pub fn get_disbursement_approvals(env: &Env, agreement_id: String) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::DisbursementApprovals(agreement_id))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Record the landlord's or tenant's approval to disburse escrowed rent.
+pub fn approve_disbursement(
+    env: &Env,
+    agreement_id: String,
+    caller: Address,
+) -> Result<(), RentalError> {
+    caller.require_auth();
+
+    let agreement = load_agreement(env, &agreement_id)?;
+    if caller != agreement.landlord && caller != agreement.tenant {
+        return Err(RentalError::Unauthorized);
+    }
+
+    let mut approvals = get_disbursement_approvals(env, agreement_id.clone());
+    if approvals.contains(&caller) {
+        return Err(RentalError::AlreadyApproved);
+    }
+    approvals.push_back(caller);
+
+    let key = DataKey::DisbursementApprovals(agreement_id);
+    env.storage().persistent().set(&key, &approvals);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_BUMP);
+    Ok(())
+}
+
+/// Fail unless both parties approved, when the agreement requires it.
+pub(crate) fn check_disbursement_approved(
+    env: &Env,
+    agreement: &RentAgreement,
+) -> Result<(), RentalError> {
+    if !agreement.dual_release_approval {
+        return Ok(());
+    }
+
+    let approvals = get_disbursement_approvals(env, agreement.agreement_id.clone());
+    if !approvals.contains(&agreement.landlord) || !approvals.contains(&agreement.tenant) {
+        return Err(RentalError::InsufficientApprovals);
+    }
+    Ok(())
+}
+
+pub(crate) fn clear_disbursement_approvals(env: &Env, agreement_id: &String) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::DisbursementApprovals(agreement_id.clone()));
+}
+
+/// Agent's commission on `total` rent, unless a funded commission reserve
+/// already covers it.
+fn agent_cut(agreement: &RentAgreement, total: i128) -> i128 {
+    match &agreement.agent {
+        Some(_) if agreement.commission_reserve == 0 => {
+            total * agreement.agent_commission_rate as i128 / 100
+        }
+        _ => 0,
+    }
+}
+
+/// Take every escrowed rent balance of an agreement, as `(token, amount)`
+/// with its payment token first, leaving the balances cleared.
+fn take_escrowed_rent(env: &Env, agreement: &RentAgreement) -> Vec<(Address, i128)> {
+    let agreement_id = &agreement.agreement_id;
+    let mut held = Vec::new(env);
+
+    let total = get_escrow_balance(env, agreement_id.clone());
+    set_escrow_balance(env, agreement_id, 0);
+    held.push_back((agreement.payment_token.clone(), total));
+
+    for token in get_escrow_tokens(env, agreement_id).iter() {
+        let amount = get_token_escrow_balance(env, agreement_id.clone(), token.clone());
+        set_token_escrow_balance(env, agreement_id, &token, 0);
+        held.push_back((token, amount));
+    }
+    held
+}
+
+/// Pay out an agreement's escrowed rent, in each token it was paid in: the
+/// agent's commission to the agent and the rest to the landlord. The balances
+/// and approvals are cleared before any transfer, so a reentrant call finds
+/// nothing. Every rent payout goes through here. Returns the amount paid out
+/// in the payment token.
+pub(crate) fn pay_out_rent(env: &Env, agreement: &RentAgreement) -> i128 {
+    clear_disbursement_approvals(env, &agreement.agreement_id);
+    let held = take_escrowed_rent(env, agreement);
+
+    let contract = env.current_contract_address();
+    let mut paid = 0;
+    for (token, total) in held.iter() {
+        if total <= 0 {
+            continue;
+        }
+        let agent_amount = agent_cut(agreement, total);
+        let client = token::Client::new(env, &token);
+        if let Some(agent) = agreement.agent.as_ref().filter(|_| agent_amount > 0) {
+            client.transfer(&contract, agent, &agent_amount);
+        }
+        client.transfer(&contract, &agreement.landlord, &(total - agent_amount));
+
+        if token == agreement.payment_token {
+            paid = total;
+        } else {
+            events::escrow_released_with_token(env, agreement.agreement_id.clone(), token, total);
+        }
+    }
+    paid
+}
+
+/// Return an agreement's escrowed rent to the tenant, in each token it was
+/// paid in. Returns the amount refunded in the payment token.
+pub(crate) fn refund_rent(env: &Env, agreement: &RentAgreement) -> i128 {
+    clear_disbursement_approvals(env, &agreement.agreement_id);
+    let held = take_escrowed_rent(env, agreement);
+
+    let contract = env.current_contract_address();
+    let mut refunded = 0;
+    for (token, amount) in held.iter() {
+        if amount <= 0 {
+            continue;
+        }
+        token::Client::new(env, &token).transfer(&contract, &agreement.tenant, &amount);
+        if token == agreement.payment_token {
+            refunded = amount;
+        }
+        events::escrow_refunded(
+            env,
+            agreement.agreement_id.clone(),
+            agreement.tenant.clone(),
+            token,
+            amount,
+        );
+    }
+    refunded
+}
+
+/// Fail with `CooldownNotMet` while the latest rent payment is inside the
+/// configured escrow dispute window, and `InvalidState` while the agreement
+/// is in dispute. The window restarts with each payment into escrow.
+fn check_dispute_window(env: &Env, agreement: &RentAgreement) -> Result<(), RentalError> {
+    if agreement.status == AgreementStatus::Disputed
+        || agreement.status == AgreementStatus::ResolutionPending
+    {
+        return Err(RentalError::InvalidState);
+    }
+
+    let window = crate::policy::get_policy(env).escrow_dispute_window;
+    let last_paid: Option<PaymentSplit> = env.storage().persistent().get(&DataKey::PaymentRecord(
+        agreement.agreement_id.clone(),
+        agreement.payment_count,
+    ));
+    match last_paid {
+        Some(split) if env.ledger().timestamp() < split.payment_date.saturating_add(window) => {
+            Err(RentalError::CooldownNotMet)
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Disburse an agreement's escrowed rent once any required approvals are in
+/// and its dispute window has passed, after drawing prepaid rent for periods
+/// now due.
+pub(crate) fn disburse_rent(env: &Env, agreement: &mut RentAgreement) -> Result<i128, RentalError> {
+    check_disbursement_approved(env, agreement)?;
+    if draw_prepaid(env, agreement) > 0 {
+        save_agreement(env, agreement);
+    }
+    check_dispute_window(env, agreement)?;
+
+    let amount = pay_out_rent(env, agreement);
+    events::escrow_released_with_token(
+        env,
+        agreement.agreement_id.clone(),
+        agreement.payment_token.clone(),
+        amount,
+    );
+    Ok(amount)
+}
+
+/// Disburse an agreement's escrowed rent as `disburse_rent` does, returning
+/// the amount paid out in `token`. Fails with `TokenNotSupported` unless
+/// `token` is the agreement's payment token or one it holds rent in.
+pub(crate) fn disburse_rent_in(
+    env: &Env,
+    agreement: &mut RentAgreement,
+    token: &Address,
+) -> Result<i128, RentalError> {
+    if *token == agreement.payment_token {
+        return disburse_rent(env, agreement);
+    }
+
+    let held = get_token_escrow_balance(env, agreement.agreement_id.clone(), token.clone());
+    if held == 0 {
+        return Err(RentalError::TokenNotSupported);
+    }
+    disburse_rent(env, agreement)?;
+    Ok(held)
+}
+
+/// Disburse an agreement's escrowed rent to the landlord, less the agent's
+/// commission.
+///
+/// Either party may call. When dual approval is required, both the landlord
+/// and tenant must have approved first; approvals are consumed. Returns the
+/// amount disbursed.
+pub fn disburse(env: &Env, agreement_id: String, caller: Address) -> Result<i128, RentalError> {
+    caller.require_auth();
+
+    let mut agreement = load_agreement(env, &agreement_id)?;
+    if caller != agreement.landlord && caller != agreement.tenant {
+        return Err(RentalError::Unauthorized);
+    }
+
+    disburse_rent(env, &mut agreement)
+}
+
+/// Pay out every undisbursed rent payment in one batch (landlord only): the
+/// agent's commission on the combined amount in one transfer and the rest to
+/// the landlord in another, per token the rent was paid in. Returns the total
+/// disbursed in `token`.
+pub fn disburse_accumulated(
+    env: &Env,
+    agreement_id: String,
+    token: Address,
+) -> Result<i128, RentalError> {
+    let mut agreement = load_agreement(env, &agreement_id)?;
+    agreement.landlord.require_auth();
+
+    disburse_rent_in(env, &mut agreement, &token)
+}
+
+/// Disburse the escrowed rent of a page of `landlord`'s agreements holding
+/// rent in `token`, skipping any that are disputed or still inside their
+/// dispute window. Covers at most `limit` agreements (capped at 50) from index
+/// `start`. Returns the total swept.
+pub fn sweep_disbursable(
+    env: &Env,
+    landlord: Address,
+    token: Address,
+    start: u32,
+    limit: u32,
+) -> i128 {
+    landlord.require_auth();
+
+    let mut total: i128 = 0;
+    for agreement_id in crate::stats::get_landlord_agreements(env, &landlord, start, limit).iter() {
+        let mut agreement = match get_agreement(env, agreement_id.clone()) {
+            Some(agreement) => agreement,
+            None => continue,
+        };
+
+        if let Ok(amount) = disburse_rent_in(env, &mut agreement, &token) {
+            total += amount;
+        }
+    }
+
+    total
+}
+
+/// Funds held by the contract for an agreement, by earmark.
+pub fn get_escrow_breakdown(
+    env: &Env,
+    agreement_id: String,
+) -> Result<EscrowBreakdown, RentalError> {
+    let agreement = load_agreement(env, &agreement_id)?;
+
+    // Deposits routed to a vault are not in this contract's custody
+    let deposit = crate::deposit::get_deposit_custody(env, agreement_id.clone())
+        .filter(|custody| custody.custodian == env.current_contract_address())
+        .map(|custody| custody.amount)
+        .unwrap_or(0);
+    let rent = get_escrow_balance(env, agreement_id.clone());
+    let prepaid_rent = get_remaining_prepaid(env, agreement_id);
+
+    Ok(EscrowBreakdown {
+        deposit,
+        rent,
+        prepaid_rent,
+        commission_reserve: agreement.commission_reserve,
+        total: deposit + rent + prepaid_rent + agreement.commission_reserve,
+    })
+}
+
+pub(crate) fn get_prepaid(env: &Env, agreement_id: &String) -> Option<PrepaidRent> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Prepaid(agreement_id.clone()))
+}
+
+/// Prepaid rent escrowed for an agreement but not yet disbursed to the landlord.
+pub fn get_remaining_prepaid(env: &Env, agreement_id: String) -> i128 {
+    get_prepaid(env, &agreement_id)
+        .map(|prepaid| prepaid.amount)
+        .unwrap_or(0)
+}
+
+fn save_prepaid(env: &Env, agreement_id: &String, prepaid: &PrepaidRent) {
+    let key = DataKey::Prepaid(agreement_id.clone());
+    if prepaid.amount == 0 {
+        env.storage().persistent().remove(&key);
+        return;
+    }
+    env.storage().persistent().set(&key, prepaid);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_BUMP);
+}
+
+/// Pull `months` of rent from the tenant into escrow, earmarked for the
+/// periods after those already due.
+///
+/// Returns the amount escrowed. Further prepayments extend the earmarked run.
+pub fn prepay_rent(
+    env: &Env,
+    agreement_id: String,
+    token: Address,
+    months: u32,
+) -> Result<i128, RentalError> {
+    let agreement = load_agreement(env, &agreement_id)?;
+
+    if agreement.status != AgreementStatus::Active {
+        return Err(RentalError::AgreementNotActive);
+    }
+
+    if token != agreement.payment_token {
+        return Err(RentalError::TokenNotSupported);
+    }
+
+    let mut prepaid = get_prepaid(env, &agreement_id).unwrap_or(PrepaidRent {
+        amount: 0,
+        next_period: periods_due(&agreement, env.ledger().timestamp()) + 1,
+    });
+
+    // Only periods left in the lease can be prepaid
+    let covered = (prepaid.amount / agreement.monthly_rent) as u32;
+    if months == 0 {
+        return Err(RentalError::InvalidAmount);
+    }
+    let last_covered = prepaid
+        .next_period
+        .checked_add(covered)
+        .and_then(|period| period.checked_add(months - 1))
+        .ok_or(RentalError::InvalidAmount)?;
+    if last_covered > total_periods(&agreement) {
+        return Err(RentalError::InvalidAmount);
+    }
+
+    agreement.tenant.require_auth();
+
+    let amount = agreement
+        .monthly_rent
+        .checked_mul(months as i128)
+        .ok_or(RentalError::InvalidAmount)?;
+    token::Client::new(env, &token).transfer(
+        &agreement.tenant,
+        env.current_contract_address(),
+        &amount,
+    );
+
+    prepaid.amount += amount;
+    save_prepaid(env, &agreement_id, &prepaid);
+
+    audit::record(env, &agreement_id, symbol_short!("prepaid"));
+    let published = if agreement.emit_amounts { amount } else { 0 };
+    events::rent_prepaid(
+        env,
+        agreement_id,
+        agreement.tenant.clone(),
+        months,
+        published,
+    );
+    Ok(amount)
+}
+
+/// How many periods the prepaid balance covers right now, and the period its
+/// earmark moves on to. An earmarked period that is due is drawn only while
+/// rent paid falls short of the periods due; one the tenant already paid
+/// directly is skipped, leaving the funds for the next period.
+pub(crate) fn prepaid_draw(
+    agreement: &RentAgreement,
+    prepaid: &PrepaidRent,
+    now: u64,
+) -> (u32, u32) {
+    let due = periods_due(agreement, now);
+    let mut paid = (agreement.total_rent_paid / agreement.monthly_rent) as u32;
+    let mut amount = prepaid.amount;
+    let mut next_period = prepaid.next_period;
+    let mut periods = 0u32;
+    while next_period <= due && amount >= agreement.monthly_rent {
+        next_period += 1;
+        if paid >= due {
+            continue;
+        }
+        amount -= agreement.monthly_rent;
+        paid += 1;
+        periods += 1;
+    }
+    (periods, next_period)
+}
+
+/// Move prepaid rent for each earmarked period now due into the agreement's
+/// rent escrow, recording each as a rent payment. The caller saves the
+/// agreement. Returns the number of periods drawn.
+pub(crate) fn draw_prepaid(env: &Env, agreement: &mut RentAgreement) -> u32 {
+    let agreement_id = agreement.agreement_id.clone();
+    let mut prepaid = match get_prepaid(env, &agreement_id) {
+        Some(prepaid) => prepaid,
+        None => return 0,
+    };
+
+    let (periods, next_period) = prepaid_draw(agreement, &prepaid, env.ledger().timestamp());
+    prepaid.next_period = next_period;
+    if periods == 0 {
+        save_prepaid(env, &agreement_id, &prepaid);
+        return 0;
+    }
+
+    let token = agreement.payment_token.clone();
+    for _ in 0..periods {
+        crate::agreement::record_rent_payment(env, agreement, agreement.monthly_rent, &token);
+    }
+    let amount = agreement.monthly_rent * periods as i128;
+    prepaid.amount -= amount;
+    save_prepaid(env, &agreement_id, &prepaid);
+    let escrowed = get_escrow_balance(env, agreement_id.clone());
+    set_escrow_balance(env, &agreement_id, escrowed + amount);
+
+    audit::record(env, &agreement_id, symbol_short!("paid"));
+    let published = if agreement.emit_amounts { amount } else { 0 };
+    events::prepaid_disbursed(
+        env,
+        agreement_id,
+        agreement.landlord.clone(),
+        periods,
+        published,
+    );
+    periods
+}
+
+/// Draw prepaid rent for every earmarked period now due into the agreement's
+/// rent escrow, where it is disbursed like any other rent payment.
+///
+/// Anyone may call this; funds only ever move into the agreement's escrow.
+/// Each period drawn is recorded as a rent payment. Returns the number of
+/// periods drawn.
+pub fn disburse_prepaid_rent(env: &Env, agreement_id: String) -> Result<u32, RentalError> {
+    let mut agreement = load_agreement(env, &agreement_id)?;
+    let periods = draw_prepaid(env, &mut agreement);
+    if periods > 0 {
+        save_agreement(env, &agreement);
+    }
+    Ok(periods)
+}
+
+/// Draw prepaid rent for periods already due into escrow, then return
+/// whatever is left to the tenant. Used when a lease ends so no prepaid rent stays held.
+/// Returns the amount refunded.
+pub(crate) fn refund_prepaid(env: &Env, agreement_id: &String) -> Result<i128, RentalError> {
+    disburse_prepaid_rent(env, agreement_id.clone())?;
+
+    let prepaid = match get_prepaid(env, agreement_id) {
+        Some(prepaid) => prepaid,
+        None => return Ok(0),
+    };
+    let agreement = load_agreement(env, agreement_id)?;
+
+    token::Client::new(env, &agreement.payment_token).transfer(
+        &env.current_contract_address(),
+        &agreement.tenant,
+        &prepaid.amount,
+    );
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Prepaid(agreement_id.clone()));
+
+    audit::record(env, agreement_id, symbol_short!("prep_back"));
+    let published = if agreement.emit_amounts {
+        prepaid.amount
+    } else {
+        0
+    };
+    events::prepaid_refunded(env, agreement_id.clone(), agreement.tenant, published);
+    Ok(prepaid.amount)
+}
+
+/// Release the funds held against an agreement that has just ended: prepaid
+/// rent, any commission reserve and a non-refundable deposit. Every path into
+/// Completed or Terminated calls this after saving the agreement.
+pub(crate) fn release_on_end(env: &Env, agreement_id: &String) -> Result<(), RentalError> {
+    refund_prepaid(env, agreement_id)?;
+    settle_commission_reserve(env, agreement_id)?;
+    crate::deposit::sweep_on_end(env, agreement_id)?;
+    Ok(())
+}
+
+/// Prepay the agent's commission for the whole term into a reserve (landlord
+/// only, Active agreements with an agent, before any rent is paid). Escrowed
+/// rent is then disbursed to the landlord without a commission cut. Returns
+/// the amount reserved.
+pub fn fund_commission_reserve(env: &Env, agreement_id: String) -> Result<i128, RentalError> {
+    let mut agreement = load_agreement(env, &agreement_id)?;
+    agreement.landlord.require_auth();
+
+    if agreement.status != AgreementStatus::Active {
+        return Err(RentalError::AgreementNotActive);
+    }
+    // Once rent is paid its commission is taken at disbursement, so a
+    // full-term reserve would pay those periods twice
+    if agreement.agent.is_none() || agreement.commission_reserve > 0 || agreement.payment_count > 0
+    {
+        return Err(RentalError::InvalidState);
+    }
+
+    let amount = (agreement.monthly_rent * agreement.agent_commission_rate as i128 / 100)
+        .checked_mul(total_periods(&agreement) as i128)
+        .ok_or(RentalError::InvalidAmount)?;
+    if amount <= 0 {
+        return Err(RentalError::InvalidAmount);
+    }
+
+    token::Client::new(env, &agreement.payment_token).transfer(
+        &agreement.landlord,
+        env.current_contract_address(),
+        &amount,
+    );
+    agreement.commission_reserve = amount;
+    save_agreement(env, &agreement);
+
+    audit::record(env, &agreement_id, symbol_short!("comm_res"));
+    events::commission_reserved(env, agreement_id, agreement.landlord, amount);
+    Ok(amount)
+}
+
+/// Release a funded commission reserve when the lease ends: the agent keeps
+/// the share earned for the time elapsed in the term and the unearned rest
+/// returns to the landlord. Returns the amount refunded.
+pub(crate) fn settle_commission_reserve(
+    env: &Env,
+    agreement_id: &String,
+) -> Result<i128, RentalError> {
+    let mut agreement = load_agreement(env, agreement_id)?;
+    let reserve = agreement.commission_reserve;
+    if reserve == 0 {
+        return Ok(0);
+    }
+
+    let term = agreement.end_date.saturating_sub(agreement.start_date);
+    let elapsed = env
+        .ledger()
+        .timestamp()
+        .clamp(agreement.start_date, agreement.end_date)
+        - agreement.start_date;
+    let earned = if term == 0 {
+        reserve
+    } else {
+        reserve * elapsed as i128 / term as i128
+    };
+    let refund = reserve - earned;
+
+    agreement.commission_reserve = 0;
+    save_agreement(env, &agreement);
+
+    let client = token::Client::new(env, &agreement.payment_token);
+    let contract = env.current_contract_address();
+    if let Some(agent) = agreement.agent.as_ref().filter(|_| earned > 0) {
+        client.transfer(&contract, agent, &earned);
+    }
+    if refund > 0 {
+        client.transfer(&contract, &agreement.landlord, &refund);
+    }
+
+    audit::record(env, agreement_id, symbol_short!("comm_back"));
+    events::commission_refunded(env, agreement_id.clone(), agreement.landlord, refund);
+    Ok(refund)
+}
@@ -1,5 +1,23 @@
+use crate::storage::DataKey;
+use crate::types::{AgreementStatus, RentAgreement};
 use crate::Config;
-use soroban_sdk::{contractevent, Address, Env, String};
+use soroban_sdk::{contractevent, Address, Env, String, Symbol};
+
+/// Count an event emitted for an agreement, so indexers can detect gaps.
+fn bump_event_seq(env: &Env, agreement_id: &String) {
+    let key = DataKey::EventSeq(agreement_id.clone());
+    let seq: u32 = env.storage().persistent().get(&key).unwrap_or(0);
+    env.storage().persistent().set(&key, &(seq + 1));
+    env.storage().persistent().extend_ttl(&key, 500000, 500000);
+}
+
+/// Number of events emitted so far for an agreement.
+pub fn get_event_seq(env: &Env, agreement_id: String) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::EventSeq(agreement_id))
+        .unwrap_or(0)
+}
 
 /// Event emitted when the contract is initialized
 /// Topics: ["initialized", admin: Address]
@@ -62,6 +80,92 @@ pub struct AgreementCancelled {
     pub agreement_id: String,
 }
 
+/// Event emitted when a landlord terminates an agreement for arrears
+/// Topics: ["agr_term", landlord: Address, tenant: Address]
+#[contractevent(topics = ["agr_term"])]
+pub struct AgreementTerminated {
+    #[topic]
+    pub landlord: Address,
+    #[topic]
+    pub tenant: Address,
+    pub agreement_id: String,
+    pub arrears_periods: u32,
+}
+
+/// Event emitted when a tenant asks to break a lease early
+/// Topics: ["brk_request", tenant: Address]
+#[contractevent(topics = ["brk_request"])]
+pub struct BreakRequested {
+    #[topic]
+    pub tenant: Address,
+    pub agreement_id: String,
+}
+
+/// Event emitted when a landlord approves a lease break
+/// Topics: ["lease_broken", landlord: Address, tenant: Address]
+#[contractevent(topics = ["lease_broken"])]
+pub struct LeaseBroken {
+    #[topic]
+    pub landlord: Address,
+    #[topic]
+    pub tenant: Address,
+    pub agreement_id: String,
+    pub penalty: i128,
+}
+
+/// Event emitted when a settled agreement's storage is reclaimed
+/// Topics: ["agr_closed", closed_by: Address]
+#[contractevent(topics = ["agr_closed"])]
+pub struct AgreementClosed {
+    #[topic]
+    pub closed_by: Address,
+    pub agreement_id: String,
+}
+
+/// Event emitted when a party puts an agreement into dispute
+/// Topics: ["agr_dispute", raised_by: Address]
+#[contractevent(topics = ["agr_dispute"])]
+pub struct DisputeRaised {
+    #[topic]
+    pub raised_by: Address,
+    pub agreement_id: String,
+}
+
+/// Event emitted when a dispute is decided, pending acknowledgment
+/// Topics: ["dsp_resolved"]
+#[contractevent(topics = ["dsp_resolved"])]
+pub struct DisputeResolved {
+    pub agreement_id: String,
+    pub outcome: AgreementStatus,
+}
+
+/// Event emitted when a party escalates an unresolved dispute to the admin
+/// Topics: ["dsp_escal", escalated_by: Address]
+#[contractevent(topics = ["dsp_escal"])]
+pub struct DisputeEscalated {
+    #[topic]
+    pub escalated_by: Address,
+    pub agreement_id: String,
+}
+
+/// Event emitted when a party acknowledges a dispute's resolution
+/// Topics: ["res_ack", party: Address]
+#[contractevent(topics = ["res_ack"])]
+pub struct ResolutionAcknowledged {
+    #[topic]
+    pub party: Address,
+    pub agreement_id: String,
+}
+
+/// Event emitted when an agreement's monthly rent is amended
+/// Topics: ["rent_amend"]
+#[contractevent(topics = ["rent_amend"])]
+pub struct RentAmended {
+    pub agreement_id: String,
+    pub old_rent: i128,
+    pub new_rent: i128,
+}
+
 /// Event emitted when the contract configuration is updated
 /// Topics: ["cfg_updated", admin: Address]
 #[contractevent(topics = ["cfg_updated"])]
@@ -76,6 +180,15 @@ pub struct ConfigUpdated {
     pub new_paused: bool,
 }
 
+/// Event emitted whenever an admin setter changes deployment configuration
+/// Topics: ["cfg_changed", key: Symbol]
+#[contractevent(topics = ["cfg_changed"])]
+pub struct ConfigChangedEvent {
+    #[topic]
+    pub key: Symbol,
+    pub timestamp: u64,
+}
+
 #[contractevent(topics = ["paused"])]
 pub struct Paused {
     #[topic]
@@ -113,6 +226,7 @@ pub(crate) fn agreement_created(
     end_date: u64,
     agent: Option<Address>,
 ) {
+    bump_event_seq(env, &agreement_id);
     AgreementCreated {
         tenant,
         landlord,
@@ -134,6 +248,7 @@ pub(crate) fn agreement_signed(
     landlord: Address,
     signed_at: u64,
 ) {
+    bump_event_seq(env, &agreement_id);
     AgreementSigned {
         tenant,
         landlord,
@@ -150,6 +265,7 @@ pub(crate) fn agreement_submitted(
     landlord: Address,
     tenant: Address,
 ) {
+    bump_event_seq(env, &agreement_id);
     AgreementSubmitted {
         landlord,
         tenant,
@@ -158,6 +274,17 @@ pub(crate) fn agreement_submitted(
     .publish(env);
 }
 
+/// Helper function to emit rent amended event
+pub(crate) fn rent_amended(env: &Env, agreement_id: String, old_rent: i128, new_rent: i128) {
+    bump_event_seq(env, &agreement_id);
+    RentAmended {
+        agreement_id,
+        old_rent,
+        new_rent,
+    }
+    .publish(env);
+}
+
 /// Helper function to emit agreement cancelled event
 pub(crate) fn agreement_cancelled(
     env: &Env,
@@ -165,6 +292,7 @@ pub(crate) fn agreement_cancelled(
     landlord: Address,
     tenant: Address,
 ) {
+    bump_event_seq(env, &agreement_id);
     AgreementCancelled {
         landlord,
         tenant,
@@ -173,6 +301,99 @@ pub(crate) fn agreement_cancelled(
     .publish(env);
 }
 
+/// Helper function to emit agreement terminated event
+pub(crate) fn agreement_terminated(env: &Env, agreement: &RentAgreement, arrears_periods: u32) {
+    bump_event_seq(env, &agreement.agreement_id);
+    AgreementTerminated {
+        landlord: agreement.landlord.clone(),
+        tenant: agreement.tenant.clone(),
+        agreement_id: agreement.agreement_id.clone(),
+        arrears_periods,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit break requested event
+pub(crate) fn break_requested(env: &Env, agreement_id: String, tenant: Address) {
+    bump_event_seq(env, &agreement_id);
+    BreakRequested {
+        tenant,
+        agreement_id,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit lease broken event
+pub(crate) fn lease_broken(env: &Env, agreement: &RentAgreement, penalty: i128) {
+    bump_event_seq(env, &agreement.agreement_id);
+    LeaseBroken {
+        landlord: agreement.landlord.clone(),
+        tenant: agreement.tenant.clone(),
+        agreement_id: agreement.agreement_id.clone(),
+        penalty,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit agreement closed event
+pub(crate) fn agreement_closed(env: &Env, agreement_id: String, closed_by: Address) {
+    bump_event_seq(env, &agreement_id);
+    AgreementClosed {
+        closed_by,
+        agreement_id,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit dispute raised event
+pub(crate) fn dispute_raised(env: &Env, agreement_id: String, raised_by: Address) {
+    bump_event_seq(env, &agreement_id);
+    DisputeRaised {
+        raised_by,
+        agreement_id,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit dispute resolved event
+pub(crate) fn dispute_resolved(env: &Env, agreement_id: String, outcome: AgreementStatus) {
+    bump_event_seq(env, &agreement_id);
+    DisputeResolved {
+        agreement_id,
+        outcome,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit dispute escalated event
+pub(crate) fn dispute_escalated(env: &Env, agreement_id: String, escalated_by: Address) {
+    bump_event_seq(env, &agreement_id);
+    DisputeEscalated {
+        escalated_by,
+        agreement_id,
+    }
+    .publish(env);
+}
+
+/// Helper function to emit resolution acknowledged event
+pub(crate) fn resolution_acknowledged(env: &Env, agreement_id: String, party: Address) {
+    bump_event_seq(env, &agreement_id);
+    ResolutionAcknowledged {
+        party,
+        agreement_id,
+    }
+    .publish(env);
+}
+
+/// Helper function to broadcast an admin configuration change under `key`
+pub(crate) fn config_changed(env: &Env, key: Symbol) {
+    ConfigChangedEvent {
+        key,
+        timestamp: env.ledger().timestamp(),
+    }
+    .publish(env);
+}
+
 /// Helper function to emit config updated event
 pub(crate) fn config_updated(env: &Env, admin: Address, old_config: Config, new_config: Config) {
     ConfigUpdated {
@@ -218,6 +439,7 @@ pub struct ExchangeRateUpdated {
 #[contractevent]
 pub struct PaymentMadeWithToken {
     pub agreement_id: String,
+    pub payment_number: u32,
     pub token: Address,
     pub amount: i128,
 }
@@ -229,6 +451,14 @@ pub struct EscrowReleasedWithToken {
     pub amount: i128,
 }
 
+#[contractevent]
+pub struct EscrowRefunded {
+    pub agreement_id: String,
+    pub tenant: Address,
+    pub token: Address,
+    pub amount: i128,
+}
+
 pub(crate) fn token_added(env: &Env, token: Address, symbol: String) {
     TokenAdded { token, symbol }.publish(env);
 }
@@ -249,11 +479,14 @@ pub(crate) fn exchange_rate_updated(env: &Env, from_token: Address, to_token: Ad
 pub(crate) fn payment_made_with_token(
     env: &Env,
     agreement_id: String,
+    payment_number: u32,
     token: Address,
     amount: i128,
 ) {
+    bump_event_seq(env, &agreement_id);
     PaymentMadeWithToken {
         agreement_id,
+        payment_number,
         token,
         amount,
     }
@@ -266,6 +499,7 @@ pub(crate) fn escrow_released_with_token(
     token: Address,
     amount: i128,
 ) {
+    bump_event_seq(env, &escrow_id);
     EscrowReleasedWithToken {
         escrow_id,
         token,
@@ -274,6 +508,297 @@ pub(crate) fn escrow_released_with_token(
     .publish(env);
 }
 
+pub(crate) fn escrow_refunded(
+    env: &Env,
+    agreement_id: String,
+    tenant: Address,
+    token: Address,
+    amount: i128,
+) {
+    bump_event_seq(env, &agreement_id);
+    EscrowRefunded {
+        agreement_id,
+        tenant,
+        token,
+        amount,
+    }
+    .publish(env);
+}
+
+/// Event emitted when a tenant prepays future rent into escrow
+/// Topics: ["rent_prepaid", tenant: Address]
+#[contractevent(topics = ["rent_prepaid"])]
+pub struct RentPrepaid {
+    #[topic]
+    pub tenant: Address,
+    pub agreement_id: String,
+    pub months: u32,
+    pub amount: i128,
+}
+
+/// Event emitted when prepaid rent is drawn into escrow for periods that came due
+/// Topics: ["prepaid_out", landlord: Address]
+#[contractevent(topics = ["prepaid_out"])]
+pub struct PrepaidDisbursed {
+    #[topic]
+    pub landlord: Address,
+    pub agreement_id: String,
+    pub periods: u32,
+    pub amount: i128,
+}
+
+/// Event emitted when unused prepaid rent is returned to the tenant
+/// Topics: ["prepaid_back", tenant: Address]
+#[contractevent(topics = ["prepaid_back"])]
+pub struct PrepaidRefunded {
+    #[topic]
+    pub tenant: Address,
+    pub agreement_id: String,
+    pub amount: i128,
+}
+
+pub(crate) fn rent_prepaid(
+    env: &Env,
+    agreement_id: String,
+    tenant: Address,
+    months: u32,
+    amount: i128,
+) {
+    bump_event_seq(env, &agreement_id);
+    RentPrepaid {
+        tenant,
+        agreement_id,
+        months,
+        amount,
+    }
+    .publish(env);
+}
+
+pub(crate) fn prepaid_disbursed(
+    env: &Env,
+    agreement_id: String,
+    landlord: Address,
+    periods: u32,
+    amount: i128,
+) {
+    bump_event_seq(env, &agreement_id);
+    PrepaidDisbursed {
+        landlord,
+        agreement_id,
+        periods,
+        amount,
+    }
+    .publish(env);
+}
+
+pub(crate) fn prepaid_refunded(env: &Env, agreement_id: String, tenant: Address, amount: i128) {
+    bump_event_seq(env, &agreement_id);
+    PrepaidRefunded {
+        tenant,
+        agreement_id,
+        amount,
+    }
+    .publish(env);
+}
+
+/// Event emitted when a landlord prepays the agent's full-term commission
+/// Topics: ["comm_reserve", landlord: Address]
+#[contractevent(topics = ["comm_reserve"])]
+pub struct CommissionReserved {
+    #[topic]
+    pub landlord: Address,
+    pub agreement_id: String,
+    pub amount: i128,
+}
+
+/// Event emitted when the unearned part of a commission reserve is returned
+/// Topics: ["comm_refund", landlord: Address]
+#[contractevent(topics = ["comm_refund"])]
+pub struct CommissionRefunded {
+    #[topic]
+    pub landlord: Address,
+    pub agreement_id: String,
+    pub amount: i128,
+}
+
+pub(crate) fn commission_reserved(
+    env: &Env,
+    agreement_id: String,
+    landlord: Address,
+    amount: i128,
+) {
+    bump_event_seq(env, &agreement_id);
+    CommissionReserved {
+        landlord,
+        agreement_id,
+        amount,
+    }
+    .publish(env);
+}
+
+pub(crate) fn commission_refunded(
+    env: &Env,
+    agreement_id: String,
+    landlord: Address,
+    amount: i128,
+) {
+    bump_event_seq(env, &agreement_id);
+    CommissionRefunded {
+        landlord,
+        agreement_id,
+        amount,
+    }
+    .publish(env);
+}
+
+// ─── Application Fee Events ───────────────────────────────────────────────────
+
+/// Event emitted when a prospective tenant escrows an application fee
+/// Topics: ["app_fee", applicant: Address]
+#[contractevent(topics = ["app_fee"])]
+pub struct ApplicationFeePaid {
+    #[topic]
+    pub applicant: Address,
+    pub property_id: String,
+    pub amount: i128,
+}
+
+/// Event emitted when a landlord refunds an unused application fee
+/// Topics: ["app_refund", applicant: Address]
+#[contractevent(topics = ["app_refund"])]
+pub struct ApplicationFeeRefunded {
+    #[topic]
+    pub applicant: Address,
+    pub property_id: String,
+    pub amount: i128,
+}
+
+pub(crate) fn application_fee_paid(
+    env: &Env,
+    applicant: Address,
+    property_id: String,
+    amount: i128,
+) {
+    ApplicationFeePaid {
+        applicant,
+        property_id,
+        amount,
+    }
+    .publish(env);
+}
+
+pub(crate) fn application_fee_refunded(
+    env: &Env,
+    applicant: Address,
+    property_id: String,
+    amount: i128,
+) {
+    ApplicationFeeRefunded {
+        applicant,
+        property_id,
+        amount,
+    }
+    .publish(env);
+}
+
+// ─── Deposit Events ───────────────────────────────────────────────────────────
+
+/// Event emitted when a tenant places the security deposit in custody
+/// Topics: ["dep_recv", tenant: Address]
+#[contractevent(topics = ["dep_recv"])]
+pub struct DepositReceived {
+    #[topic]
+    pub tenant: Address,
+    pub agreement_id: String,
+    pub amount: i128,
+}
+
+/// Event emitted when a held deposit is paid out
+/// Topics: ["dep_release", recipient: Address]
+#[contractevent(topics = ["dep_release"])]
+pub struct DepositReleased {
+    #[topic]
+    pub recipient: Address,
+    pub agreement_id: String,
+    pub amount: i128,
+}
+
+/// Event emitted when a landlord collects overdue rent from the guarantor
+/// Topics: ["guar_charge", guarantor: Address]
+#[contractevent(topics = ["guar_charge"])]
+pub struct GuarantorCharged {
+    #[topic]
+    pub guarantor: Address,
+    pub agreement_id: String,
+    pub amount: i128,
+}
+
+/// Single consolidated event emitted when an agreement is settled, in place of
+/// separate status, deposit and escrow events
+/// Topics: ["agr_settled", landlord: Address, tenant: Address]
+#[contractevent(topics = ["agr_settled"])]
+pub struct SettlementEvent {
+    #[topic]
+    pub landlord: Address,
+    #[topic]
+    pub tenant: Address,
+    pub agreement_id: String,
+    pub status: AgreementStatus,
+    pub deposit_recipient: Address,
+    pub deposit_returned: i128,
+    pub escrow_disbursed: i128,
+}
+
+pub(crate) fn deposit_received(env: &Env, agreement_id: String, tenant: Address, amount: i128) {
+    bump_event_seq(env, &agreement_id);
+    DepositReceived {
+        tenant,
+        agreement_id,
+        amount,
+    }
+    .publish(env);
+}
+
+pub(crate) fn deposit_released(env: &Env, agreement_id: String, recipient: Address, amount: i128) {
+    bump_event_seq(env, &agreement_id);
+    DepositReleased {
+        recipient,
+        agreement_id,
+        amount,
+    }
+    .publish(env);
+}
+
+pub(crate) fn guarantor_charged(env: &Env, agreement_id: String, guarantor: Address, amount: i128) {
+    bump_event_seq(env, &agreement_id);
+    GuarantorCharged {
+        guarantor,
+        agreement_id,
+        amount,
+    }
+    .publish(env);
+}
+
+pub(crate) fn agreement_settled(
+    env: &Env,
+    agreement: &RentAgreement,
+    deposit_recipient: Address,
+    deposit_returned: i128,
+    escrow_disbursed: i128,
+) {
+    bump_event_seq(env, &agreement.agreement_id);
+    SettlementEvent {
+        landlord: agreement.landlord.clone(),
+        tenant: agreement.tenant.clone(),
+        agreement_id: agreement.agreement_id.clone(),
+        status: agreement.status.clone(),
+        deposit_recipient,
+        deposit_returned,
+        escrow_disbursed,
+    }
+    .publish(env);
+}
+
 // ─── Deposit Interest Events ──────────────────────────────────────────────────
 
 #[contractevent]
@@ -297,6 +822,7 @@ pub struct InterestDistributed {
 }
 
 pub(crate) fn interest_config_set(env: &Env, agreement_id: String, annual_rate: u32) {
+    bump_event_seq(env, &agreement_id);
     InterestConfigSet {
         agreement_id,
         annual_rate,
@@ -305,6 +831,7 @@ pub(crate) fn interest_config_set(env: &Env, agreement_id: String, annual_rate:
 }
 
 pub(crate) fn interest_accrued(env: &Env, escrow_id: String, amount: i128, total_accrued: i128) {
+    bump_event_seq(env, &escrow_id);
     InterestAccruedEvent {
         escrow_id,
         amount,
@@ -319,6 +846,7 @@ pub(crate) fn interest_distributed(
     tenant_share: i128,
     landlord_share: i128,
 ) {
+    bump_event_seq(env, &escrow_id);
     InterestDistributed {
         escrow_id,
         tenant_share,
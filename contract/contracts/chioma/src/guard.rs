@@ -0,0 +1,25 @@
+//! Reentrancy lock for entry points that pay funds out.
+
+use soroban_sdk::Env;
+
+use crate::errors::RentalError;
+use crate::storage::DataKey;
+
+/// Run `f` while holding the contract-wide reentrancy lock.
+///
+/// A call that arrives while the lock is held fails with `Reentrancy`.
+pub(crate) fn non_reentrant<T>(
+    env: &Env,
+    f: impl FnOnce() -> Result<T, RentalError>,
+) -> Result<T, RentalError> {
+    if env.storage().instance().has(&DataKey::ReentrancyGuard) {
+        return Err(RentalError::Reentrancy);
+    }
+
+    env.storage()
+        .instance()
+        .set(&DataKey::ReentrancyGuard, &true);
+    let result = f();
+    env.storage().instance().remove(&DataKey::ReentrancyGuard);
+    result
+}
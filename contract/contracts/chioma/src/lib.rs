@@ -6,16 +6,26 @@
 //! @title Chioma
 //! @notice On-chain rental agreement lifecycle: create, sign, submit, cancel, and query agreements.
 
-use soroban_sdk::{contract, contractimpl, Address, Bytes, Env, String, Vec};
+use soroban_sdk::{contract, contractimpl, symbol_short, Address, Bytes, Env, String, Symbol, Vec};
 
 mod agreement;
+mod allowlist;
+mod application;
+mod audit;
+mod billing;
+mod deposit;
 mod deposit_interest;
+mod dispute;
 mod errors;
+mod escrow;
 mod events;
+mod guard;
 mod multi_sig;
 mod multi_token;
+mod policy;
 mod rate_limit;
 mod royalties;
+mod stats;
 mod storage;
 mod timelock;
 mod types;
@@ -26,9 +36,21 @@ mod tests;
 #[cfg(test)]
 mod tests_multi_token;
 
+#[cfg(test)]
+mod tests_billing;
+
+#[cfg(test)]
+mod tests_deposit;
+
 #[cfg(test)]
 mod tests_deposit_interest;
 
+#[cfg(test)]
+mod tests_dispute;
+
+#[cfg(test)]
+mod tests_escrow;
+
 #[cfg(test)]
 mod tests_multisig_governance;
 
@@ -51,24 +73,27 @@ mod tests_timelock;
 mod tests_version_pause;
 
 pub use agreement::{
-    cancel_agreement, create_agreement, create_agreement_with_token, get_agreement,
-    get_agreement_count, get_agreement_token, get_payment_history, get_payment_split,
-    has_agreement, make_payment_with_token, release_escrow_with_token, sign_agreement,
-    submit_agreement, update_metadata, validate_agreement_params,
+    cancel_agreement, create_agreement, create_agreement_with_token, expire_stale_drafts,
+    get_agreement, get_agreement_count, get_agreement_token, get_created_at, get_payment_history,
+    get_payment_split, get_property_agreements, get_token_decimals, has_agreement, link_property,
+    make_payment_with_token, release_escrow_with_token, sign_agreement, submit_agreement,
+    update_metadata, validate_agreement_params,
 };
-pub use errors::RentalError;
+pub use errors::{LedgerError, RentalError};
 pub use multi_token::{
-    add_supported_token, convert_amount, get_exchange_rate, get_supported_tokens,
-    is_token_supported, remove_supported_token, set_exchange_rate,
+    add_supported_token, convert_amount, format_amount, get_exchange_rate, get_supported_tokens,
+    get_tvl, is_token_supported, remove_supported_token, set_exchange_rate,
 };
 pub use storage::DataKey;
 pub use types::{
-    ActionType, AdminProposal, AgreementInput, AgreementStatus, AgreementTerms, AgreementWithToken,
-    Attribute, CompoundingFrequency, Config, ContractState, ContractVersion, DepositInterest,
-    DepositInterestConfig, ErrorContext, InterestAccrual, InterestRecipient, MultiSigConfig,
-    PauseState, PaymentSplit, RateLimitConfig, RateLimitReason, RentAgreement, RoyaltyConfig,
-    RoyaltyPayment, SupportedToken, TimelockAction, TimelockActionType, TokenExchangeRate,
-    UserCallCount, VersionStatus,
+    ActionType, AdminProposal, AgreementInput, AgreementPolicy, AgreementStatus, AgreementTerms,
+    AgreementWithToken, AllocationPolicy, ApplicationFee, Attribute, CompoundingFrequency, Config,
+    ContractState, ContractStats, ContractVersion, DepositCustody, DepositInterest,
+    DepositInterestConfig, DisputeResolution, ErrorContext, EscrowBreakdown, InterestAccrual,
+    InterestRecipient, MultiSigConfig, PauseState, PaymentSplit, PortfolioSummary, PrepaidRent,
+    PropertyDetails, ProrationBasis, RateLimitConfig, RateLimitReason, RentAgreement,
+    RoyaltyConfig, RoyaltyPayment, SupportedToken, TimelockAction, TimelockActionType,
+    TokenExchangeRate, UserCallCount, VersionStatus,
 };
 
 /// Chioma rental agreement contract.
@@ -100,6 +125,7 @@ impl Contract {
 
     /// Record a new contract version (admin only).
     pub fn record_version(env: Env, version: ContractVersion) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
         let state = Self::get_state(env.clone()).ok_or(RentalError::InvalidState)?;
         state.admin.require_auth();
 
@@ -132,6 +158,7 @@ impl Contract {
         patch: u32,
         status: VersionStatus,
     ) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
         let state = Self::get_state(env.clone()).ok_or(RentalError::InvalidState)?;
         state.admin.require_auth();
 
@@ -248,6 +275,13 @@ impl Contract {
         pause_state
     }
 
+    fn check_initialized(env: &Env) -> Result<(), RentalError> {
+        if !env.storage().persistent().has(&DataKey::Initialized) {
+            return Err(RentalError::NotInitialized);
+        }
+        Ok(())
+    }
+
     fn check_paused(env: &Env) -> Result<(), RentalError> {
         if Self::is_paused(env.clone()) {
             return Err(RentalError::ContractPaused);
@@ -264,6 +298,7 @@ impl Contract {
     /// @custom:error InvalidState If contract state is missing.
     /// @custom:error InvalidConfig If new_config.fee_bps > 10000.
     pub fn update_config(env: Env, new_config: Config) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
         let mut state = Self::get_state(env.clone()).ok_or(RentalError::InvalidState)?;
 
         state.admin.require_auth();
@@ -284,9 +319,17 @@ impl Contract {
             let reason = String::from_str(&env, "Paused via config update");
             Self::set_pause_state(&env, state.admin.clone(), reason.clone());
             events::paused(&env, reason, state.admin.clone());
+            events::config_changed(&env, symbol_short!("paused"));
         } else if !new_config.paused && was_paused {
             env.storage().instance().remove(&DataKey::PauseState);
             events::unpaused(&env, state.admin.clone());
+            events::config_changed(&env, symbol_short!("paused"));
+        }
+
+        if new_config.fee_bps != old_config.fee_bps
+            || new_config.fee_collector != old_config.fee_collector
+        {
+            events::config_changed(&env, symbol_short!("fees"));
         }
 
         events::config_updated(&env, state.admin, old_config, new_config);
@@ -295,6 +338,7 @@ impl Contract {
     }
 
     pub fn pause(env: Env, reason: String) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
         let mut state = Self::get_state(env.clone()).ok_or(RentalError::InvalidState)?;
 
         state.admin.require_auth();
@@ -312,10 +356,12 @@ impl Contract {
         }
 
         events::paused(&env, reason, state.admin);
+        events::config_changed(&env, symbol_short!("paused"));
         Ok(())
     }
 
     pub fn unpause(env: Env) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
         let mut state = Self::get_state(env.clone()).ok_or(RentalError::InvalidState)?;
 
         state.admin.require_auth();
@@ -333,9 +379,13 @@ impl Contract {
         }
 
         events::unpaused(&env, state.admin);
+        events::config_changed(&env, symbol_short!("paused"));
         Ok(())
     }
 
+    /// Whether mutating calls are currently blocked by a pause.
+    ///
+    /// Defaults to `false`, including before the contract is initialized.
     pub fn is_paused(env: Env) -> bool {
         if let Some(pause_state) = env
             .storage()
@@ -352,6 +402,166 @@ impl Contract {
         false
     }
 
+    // --- Agreement Policy Functions ---
+
+    /// Get the deployment-wide agreement policy.
+    pub fn get_policy(env: Env) -> AgreementPolicy {
+        policy::get_policy(&env)
+    }
+
+    /// Set the rent above which amendments need agent co-authorization (admin only, 0 = off).
+    pub fn set_coauth_threshold(env: Env, threshold: i128) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
+        let state = Self::get_state(env.clone()).ok_or(RentalError::InvalidState)?;
+        state.admin.require_auth();
+
+        policy::set_coauth_threshold(&env, threshold)?;
+        events::config_changed(&env, symbol_short!("coauth"));
+        Ok(())
+    }
+
+    /// Set the agent commission band accepted on new agreements (admin only).
+    ///
+    /// Unset bounds default to 0–10000.
+    pub fn set_commission_bounds(env: Env, min_bps: u32, max_bps: u32) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
+        let state = Self::get_state(env.clone()).ok_or(RentalError::InvalidState)?;
+        state.admin.require_auth();
+
+        policy::set_commission_bounds(&env, min_bps, max_bps)?;
+        events::config_changed(&env, symbol_short!("comm_band"));
+        Ok(())
+    }
+
+    /// Cap rent increases on amendment, in bps of the prior rent (admin only, None = uncapped).
+    pub fn set_max_increase(env: Env, max_bps: Option<u32>) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
+        let state = Self::get_state(env.clone()).ok_or(RentalError::InvalidState)?;
+        state.admin.require_auth();
+
+        policy::set_max_increase(&env, max_bps);
+        events::config_changed(&env, symbol_short!("max_incr"));
+        Ok(())
+    }
+
+    /// Hold refundable deposits for `period` seconds after completion (admin only, 0 = off).
+    pub fn set_inspection_period(env: Env, period: u64) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
+        let state = Self::get_state(env.clone()).ok_or(RentalError::InvalidState)?;
+        state.admin.require_auth();
+
+        policy::set_inspection_period(&env, period);
+        events::config_changed(&env, symbol_short!("inspect"));
+        Ok(())
+    }
+
+    /// Set the shortest lease term, in seconds, accepted on new agreements (admin only, 0 = off).
+    pub fn set_min_duration(env: Env, min_duration: u64) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
+        let state = Self::get_state(env.clone()).ok_or(RentalError::InvalidState)?;
+        state.admin.require_auth();
+
+        policy::set_min_duration(&env, min_duration);
+        events::config_changed(&env, symbol_short!("min_term"));
+        Ok(())
+    }
+
+    /// Let landlords terminate leases once `periods` rent periods are unpaid (admin only, 0 = off).
+    pub fn set_max_arrears_periods(env: Env, periods: u32) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
+        let state = Self::get_state(env.clone()).ok_or(RentalError::InvalidState)?;
+        state.admin.require_auth();
+
+        policy::set_max_arrears_periods(&env, periods);
+        events::config_changed(&env, symbol_short!("arrears"));
+        Ok(())
+    }
+
+    /// Keep escrowed rent out of landlord sweeps for `window` seconds after each
+    /// payment (admin only, 0 = off).
+    pub fn set_escrow_dispute_window(env: Env, window: u64) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
+        let state = Self::get_state(env.clone()).ok_or(RentalError::InvalidState)?;
+        state.admin.require_auth();
+
+        policy::set_escrow_dispute_window(&env, window);
+        events::config_changed(&env, symbol_short!("sweep_win"));
+        Ok(())
+    }
+
+    /// Require rents on new agreements and amendments to be multiples of
+    /// `tick_size` (admin only, 0 or 1 = any amount).
+    pub fn set_tick_size(env: Env, tick_size: i128) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
+        let state = Self::get_state(env.clone()).ok_or(RentalError::InvalidState)?;
+        state.admin.require_auth();
+
+        policy::set_tick_size(&env, tick_size)?;
+        events::config_changed(&env, symbol_short!("tick"));
+        Ok(())
+    }
+
+    /// Let parties escalate disputes to the admin once they have gone
+    /// unresolved for `window` seconds (admin only, 0 = off).
+    pub fn set_dispute_escalation_window(env: Env, window: u64) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
+        let state = Self::get_state(env.clone()).ok_or(RentalError::InvalidState)?;
+        state.admin.require_auth();
+
+        policy::set_dispute_escalation_window(&env, window);
+        events::config_changed(&env, symbol_short!("dsp_win"));
+        Ok(())
+    }
+
+    /// Require new agreements to start at or after their creation time
+    /// (admin only, off by default).
+    pub fn set_require_future_start(env: Env, required: bool) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
+        let state = Self::get_state(env.clone()).ok_or(RentalError::InvalidState)?;
+        state.admin.require_auth();
+
+        policy::set_require_future_start(&env, required);
+        events::config_changed(&env, symbol_short!("fut_start"));
+        Ok(())
+    }
+
+    /// Require the landlord to authorize agreement creation alongside the
+    /// tenant (admin only, off by default).
+    pub fn set_require_landlord_auth(env: Env, required: bool) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
+        let state = Self::get_state(env.clone()).ok_or(RentalError::InvalidState)?;
+        state.admin.require_auth();
+
+        policy::set_require_landlord_auth(&env, required);
+        events::config_changed(&env, symbol_short!("ll_auth"));
+        Ok(())
+    }
+
+    /// Let tenants end a lease without landlord approval once a break request
+    /// has served `notice_period` seconds of notice (admin only, 0 = off).
+    pub fn set_notice_period(env: Env, notice_period: u64) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
+        let state = Self::get_state(env.clone()).ok_or(RentalError::InvalidState)?;
+        state.admin.require_auth();
+
+        policy::set_notice_period(&env, notice_period);
+        events::config_changed(&env, symbol_short!("notice"));
+        Ok(())
+    }
+
+    /// Reject security deposits below `min_deposit` (admin only, 0 = no
+    /// minimum). The minimum is quoted at 7 decimals and scaled to each
+    /// agreement's token decimals.
+    pub fn set_min_deposit(env: Env, min_deposit: i128) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
+        let state = Self::get_state(env.clone()).ok_or(RentalError::InvalidState)?;
+        state.admin.require_auth();
+
+        policy::set_min_deposit(&env, min_deposit)?;
+        events::config_changed(&env, symbol_short!("min_dep"));
+        Ok(())
+    }
+
     // --- Token Management Functions ---
 
     pub fn add_supported_token(
@@ -362,27 +572,33 @@ impl Contract {
         min_amount: i128,
         max_amount: i128,
     ) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
         Self::check_paused(&env)?;
         // Only admin can add tokens
         let state = Self::get_state(env.clone()).ok_or(RentalError::InvalidState)?;
         state.admin.require_auth();
 
         multi_token::add_supported_token(
-            env,
+            env.clone(),
             token_address,
             symbol,
             decimals,
             min_amount,
             max_amount,
-        )
+        )?;
+        events::config_changed(&env, symbol_short!("tokens"));
+        Ok(())
     }
 
     pub fn remove_supported_token(env: Env, token_address: Address) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
         Self::check_paused(&env)?;
         let state = Self::get_state(env.clone()).ok_or(RentalError::InvalidState)?;
         state.admin.require_auth();
 
-        multi_token::remove_supported_token(env, token_address)
+        multi_token::remove_supported_token(env.clone(), token_address)?;
+        events::config_changed(&env, symbol_short!("tokens"));
+        Ok(())
     }
 
     pub fn get_supported_tokens(env: Env) -> Result<Vec<SupportedToken>, RentalError> {
@@ -393,6 +609,13 @@ impl Contract {
         multi_token::is_token_supported(env, token_address)
     }
 
+    /// Get the contract's total value locked for each of the given tokens.
+    ///
+    /// Reports the raw balance the contract holds per token (escrowed rent and deposits).
+    pub fn get_tvl(env: Env, tokens: Vec<Address>) -> Vec<(Address, i128)> {
+        multi_token::get_tvl(env, tokens)
+    }
+
     // --- Exchange Rate Functions ---
 
     pub fn set_exchange_rate(
@@ -401,11 +624,14 @@ impl Contract {
         to_token: Address,
         rate: i128,
     ) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
         Self::check_paused(&env)?;
         let state = Self::get_state(env.clone()).ok_or(RentalError::InvalidState)?;
         state.admin.require_auth();
 
-        multi_token::set_exchange_rate(env, from_token, to_token, rate)
+        multi_token::set_exchange_rate(env.clone(), from_token, to_token, rate)?;
+        events::config_changed(&env, symbol_short!("fx_rates"));
+        Ok(())
     }
 
     pub fn get_exchange_rate(
@@ -420,6 +646,7 @@ impl Contract {
         env: Env,
         rates: Vec<(Address, Address, i128)>,
     ) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
         Self::check_paused(&env)?;
         let state = Self::get_state(env.clone()).ok_or(RentalError::InvalidState)?;
         state.admin.require_auth();
@@ -427,6 +654,7 @@ impl Contract {
         for (from, to, rate) in rates.iter() {
             multi_token::set_exchange_rate(env.clone(), from, to, rate)?;
         }
+        events::config_changed(&env, symbol_short!("fx_rates"));
         Ok(())
     }
 
@@ -436,33 +664,489 @@ impl Contract {
         to_token: Address,
         amount: i128,
     ) -> Result<i128, RentalError> {
-        multi_token::convert_amount(env, from_token, to_token, amount)
+        multi_token::convert_amount(env, from_token, to_token, amount)
+    }
+
+    /// Format a raw token amount for display, e.g. 10000000 at 7 decimals as
+    /// "1.0000000".
+    pub fn format_amount(env: Env, amount: i128, decimals: u32) -> String {
+        multi_token::format_amount(&env, amount, decimals)
+    }
+
+    // --- Agreement Functions with Token ---
+
+    pub fn create_agreement_with_token(
+        env: Env,
+        input: crate::types::AgreementInput,
+    ) -> Result<String, RentalError> {
+        Self::check_initialized(&env)?;
+        Self::check_paused(&env)?;
+        agreement::create_agreement_with_token(&env, input)
+    }
+
+    pub fn get_agreement_token(env: Env, agreement_id: String) -> Result<Address, RentalError> {
+        agreement::get_agreement_token(&env, agreement_id)
+    }
+
+    /// Get lease progress as `(periods_elapsed, total_periods, percent_complete)`.
+    ///
+    /// Periods are 30 days long; a completed or expired agreement reports 100 percent.
+    pub fn get_progress(env: Env, agreement_id: String) -> Result<(u32, u32, u32), RentalError> {
+        agreement::get_progress(&env, agreement_id)
+    }
+
+    /// Set the fee owed for ending the lease early (landlord only, before activation).
+    pub fn set_early_termination_fee(
+        env: Env,
+        agreement_id: String,
+        fee: i128,
+    ) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
+        Self::check_paused(&env)?;
+        agreement::set_early_termination_fee(&env, agreement_id, fee)
+    }
+
+    /// Get the pro-rated rent for a first period only `days` long, using the
+    /// agreement's pro-ration basis.
+    pub fn get_first_period_charge(
+        env: Env,
+        agreement_id: String,
+        days: u32,
+    ) -> Result<i128, RentalError> {
+        billing::get_first_period_charge(&env, agreement_id, days)
+    }
+
+    /// Choose how rent is pro-rated for partial periods (landlord only,
+    /// before activation).
+    pub fn set_proration_basis(
+        env: Env,
+        agreement_id: String,
+        basis: ProrationBasis,
+    ) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
+        Self::check_paused(&env)?;
+        agreement::set_proration_basis(&env, agreement_id, basis)
+    }
+
+    /// Correct a mis-entered agent commission rate (landlord and tenant,
+    /// before activation).
+    pub fn set_commission(
+        env: Env,
+        agreement_id: String,
+        new_rate: u32,
+    ) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
+        Self::check_paused(&env)?;
+        agreement::set_commission(&env, agreement_id, new_rate)
+    }
+
+    /// Get the single amount a tenant must pay to settle the lease early.
+    ///
+    /// Sums outstanding arrears, remaining scheduled rent and the early-termination
+    /// fee, minus any credit from rent paid ahead of schedule.
+    pub fn get_payoff_quote(env: Env, agreement_id: String) -> Result<i128, RentalError> {
+        billing::get_payoff_quote(&env, agreement_id)
+    }
+
+    /// Get the amount the tenant owes right now, net of rent paid and prepaid.
+    pub fn get_outstanding_balance(env: Env, agreement_id: String) -> Result<i128, RentalError> {
+        billing::get_outstanding_balance(&env, agreement_id)
+    }
+
+    /// Get the monthly rent converted into `pay_token` at the stored exchange rate.
+    ///
+    /// @custom:error RateNotFound If no rate is set from the agreement's token.
+    pub fn amount_due_in(
+        env: Env,
+        agreement_id: String,
+        pay_token: Address,
+    ) -> Result<i128, RentalError> {
+        billing::amount_due_in(&env, agreement_id, pay_token)
+    }
+
+    /// List Active agreements whose next rent is due within `within` seconds.
+    ///
+    /// @notice For keeper bots sending tenant reminders; pages through the
+    /// agreement index from `start`, scanning at most `limit` (max 50) entries.
+    /// @param env The Soroban environment.
+    /// @param within Look-ahead window in seconds.
+    /// @param start Index of the first agreement to scan.
+    /// @param limit Maximum number of agreements to scan.
+    /// @return `(agreement_id, due_date)` pairs in index order.
+    pub fn get_due_reminders(env: Env, within: u64, start: u32, limit: u32) -> Vec<(String, u64)> {
+        billing::get_due_reminders(&env, within, start, limit)
+    }
+
+    /// Verify an agreement's `total_rent_paid` against its payment records,
+    /// summing at most `limit` records (capped at 50) from index `start` onto
+    /// `carried`, the sum returned by the previous page. Returns the sum so far.
+    ///
+    /// Fails with `LedgerError::ReconciliationMismatch` on the last page if
+    /// the stored total has drifted from the records.
+    pub fn reconcile(
+        env: Env,
+        agreement_id: String,
+        start: u32,
+        limit: u32,
+        carried: i128,
+    ) -> Result<i128, soroban_sdk::Error> {
+        billing::reconcile(&env, agreement_id, start, limit, carried)
+    }
+
+    /// Get the number of rent periods that have fallen due but remain unpaid.
+    pub fn get_arrears(env: Env, agreement_id: String) -> Result<u32, RentalError> {
+        billing::get_arrears(&env, agreement_id)
+    }
+
+    /// Get the number of unpaid periods older than the current one.
+    pub fn get_overdue_periods(env: Env, agreement_id: String) -> Result<u32, RentalError> {
+        billing::get_overdue_periods(&env, agreement_id)
+    }
+
+    /// Choose whether payments made in arrears clear the oldest overdue period
+    /// or the current one first (landlord only, before activation).
+    pub fn set_allocation_policy(
+        env: Env,
+        agreement_id: String,
+        policy: AllocationPolicy,
+    ) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
+        Self::check_paused(&env)?;
+        agreement::set_allocation_policy(&env, agreement_id, policy)
+    }
+
+    /// Terminate an Active agreement once its arrears reach the configured
+    /// threshold (landlord only).
+    pub fn enforce_arrears(env: Env, agreement_id: String) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
+        Self::check_paused(&env)?;
+        agreement::enforce_arrears(&env, agreement_id)
+    }
+
+    /// Ask to break an Active lease early (tenant only); the landlord must approve.
+    pub fn request_break(
+        env: Env,
+        agreement_id: String,
+        tenant: Address,
+    ) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
+        Self::check_paused(&env)?;
+        agreement::request_break(&env, agreement_id, tenant)
+    }
+
+    /// Approve a requested lease break (landlord only), terminating the
+    /// agreement with `penalty` recorded as the tenant's early-termination fee.
+    pub fn approve_break(
+        env: Env,
+        agreement_id: String,
+        landlord: Address,
+        penalty: i128,
+    ) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
+        Self::check_paused(&env)?;
+        agreement::approve_break(&env, agreement_id, landlord, penalty)
+    }
+
+    /// Terminate a lease once its break request has served the configured
+    /// notice period. Fails with `NoticePeriodActive` while notice is running.
+    pub fn finalize_break(env: Env, agreement_id: String) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
+        Self::check_paused(&env)?;
+        agreement::finalize_break(&env, agreement_id)
+    }
+
+    /// Delete a settled Completed or Cancelled agreement and its records
+    /// (landlord or tenant). Fails with `LedgerError::BalanceRemaining` while
+    /// any escrow, deposit or prepaid rent remains.
+    pub fn close_agreement(
+        env: Env,
+        agreement_id: String,
+        caller: Address,
+    ) -> Result<(), soroban_sdk::Error> {
+        Self::check_initialized(&env)?;
+        Self::check_paused(&env)?;
+        agreement::close_agreement(&env, agreement_id, caller)
+    }
+
+    /// Get the final status of an agreement removed by `close_agreement`.
+    pub fn get_closed_status(env: Env, agreement_id: String) -> Option<AgreementStatus> {
+        agreement::get_closed_status(&env, agreement_id)
+    }
+
+    /// Put an Active agreement into dispute (landlord or tenant).
+    pub fn raise_dispute(
+        env: Env,
+        agreement_id: String,
+        caller: Address,
+    ) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
+        Self::check_paused(&env)?;
+        dispute::raise_dispute(&env, agreement_id, caller)
+    }
+
+    /// Hand a dispute to the admin once it has gone unresolved for the
+    /// configured escalation window (landlord or tenant), taking it away
+    /// from the agent's arbitration.
+    pub fn escalate_dispute(
+        env: Env,
+        agreement_id: String,
+        caller: Address,
+    ) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
+        Self::check_paused(&env)?;
+        dispute::escalate_dispute(&env, agreement_id, caller)
+    }
+
+    /// Decide a dispute as the agreement's agent, resuming the agreement or
+    /// terminating it once both parties acknowledge. Fails once the dispute
+    /// has been escalated to the admin.
+    pub fn arbitrate_dispute(
+        env: Env,
+        agreement_id: String,
+        agent: Address,
+        terminate: bool,
+    ) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
+        Self::check_paused(&env)?;
+        dispute::arbitrate_dispute(&env, agreement_id, agent, terminate)
+    }
+
+    /// Whether an open dispute has been escalated to the admin.
+    pub fn is_dispute_escalated(env: Env, agreement_id: String) -> bool {
+        dispute::is_escalated(&env, agreement_id)
+    }
+
+    /// Decide a dispute (admin only), resuming the agreement or terminating it
+    /// once both parties acknowledge.
+    pub fn resolve_dispute(
+        env: Env,
+        agreement_id: String,
+        terminate: bool,
+    ) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
+        Self::check_paused(&env)?;
+        let state = Self::get_state(env.clone()).ok_or(RentalError::InvalidState)?;
+        state.admin.require_auth();
+
+        dispute::resolve_dispute(&env, agreement_id, terminate)
+    }
+
+    /// Acknowledge a dispute's resolution as the landlord or tenant; the
+    /// outcome applies once both have, and payments stay blocked until then.
+    pub fn acknowledge_resolution(
+        env: Env,
+        agreement_id: String,
+        caller: Address,
+    ) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
+        dispute::acknowledge_resolution(&env, agreement_id, caller)
+    }
+
+    /// Apply a pending dispute resolution without waiting for both parties
+    /// to acknowledge it (admin only).
+    pub fn finalize_resolution(env: Env, agreement_id: String) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
+        let state = Self::get_state(env.clone()).ok_or(RentalError::InvalidState)?;
+        state.admin.require_auth();
+
+        dispute::finalize_resolution(&env, agreement_id)
+    }
+
+    /// Get the pending resolution of a disputed agreement, if any.
+    pub fn get_resolution(env: Env, agreement_id: String) -> Option<DisputeResolution> {
+        dispute::get_resolution(&env, agreement_id)
+    }
+
+    /// Get contract-wide totals: agreements on record, currently active and
+    /// payments recorded.
+    pub fn get_stats(env: Env) -> ContractStats {
+        stats::get_stats(&env)
+    }
+
+    /// Get how many events have been emitted for an agreement, for indexers
+    /// checking they have not missed any.
+    pub fn get_event_seq(env: Env, agreement_id: String) -> u32 {
+        events::get_event_seq(&env, agreement_id)
+    }
+
+    /// Get the cumulative rent paid in `token` across all agreements.
+    pub fn get_rent_volume(env: Env, token: Address) -> i128 {
+        stats::get_rent_volume(&env, token)
+    }
+
+    /// Get a landlord's totals across a page of their agreements paid in
+    /// `token`: how many there are, how many are active, rent received, rent
+    /// currently owed and deposits held. Pages hold at most 50 agreements
+    /// from index `start`.
+    pub fn get_portfolio_summary(
+        env: Env,
+        landlord: Address,
+        token: Address,
+        start: u32,
+        limit: u32,
+    ) -> PortfolioSummary {
+        stats::get_portfolio_summary(&env, landlord, token, start, limit)
+    }
+
+    /// Get how many agreements have been created for a landlord, for paging
+    /// the per-landlord views.
+    pub fn get_landlord_agreement_count(env: Env, landlord: Address) -> u32 {
+        stats::get_landlord_agreement_count(&env, &landlord)
+    }
+
+    /// Ids of the landlord's active agreements ending within `within` seconds
+    /// of now, for starting renewals. Scans at most 50 of the landlord's
+    /// agreements from index `start`.
+    pub fn get_upcoming_renewals(
+        env: Env,
+        landlord: Address,
+        within: u64,
+        start: u32,
+        limit: u32,
+    ) -> Vec<String> {
+        stats::get_upcoming_renewals(&env, landlord, within, start, limit)
+    }
+
+    /// Get the ledger timestamp at which the agreement was created.
+    pub fn get_created_at(env: Env, agreement_id: String) -> Result<u64, RentalError> {
+        agreement::get_created_at(&env, agreement_id)
+    }
+
+    /// Get the decimal places of the agreement's payment token, for formatting amounts.
+    pub fn get_token_decimals(env: Env, agreement_id: String) -> Result<u32, RentalError> {
+        agreement::get_token_decimals(&env, agreement_id)
+    }
+
+    // --- Payment Functions with Token ---
+
+    pub fn make_payment_with_token(
+        env: Env,
+        agreement_id: String,
+        amount: i128,
+        token: Address,
+    ) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
+        Self::check_paused(&env)?;
+        agreement::make_payment_with_token(&env, agreement_id, amount, token)
+    }
+
+    /// Get the rent currently held in escrow for an agreement, in its payment token.
+    pub fn get_escrow_balance(env: Env, agreement_id: String) -> i128 {
+        agreement::get_escrow_balance(&env, agreement_id)
+    }
+
+    /// Get the rent held in escrow for an agreement in `token`, a supported
+    /// token other than its payment token.
+    pub fn get_token_escrow_balance(env: Env, agreement_id: String, token: Address) -> i128 {
+        agreement::get_token_escrow_balance(&env, agreement_id, token)
+    }
+
+    /// Require both landlord and tenant approval to disburse escrowed rent (landlord only).
+    pub fn set_dual_release_approval(
+        env: Env,
+        agreement_id: String,
+        enabled: bool,
+    ) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
+        Self::check_paused(&env)?;
+        escrow::set_dual_release_approval(&env, agreement_id, enabled)
+    }
+
+    /// Record the landlord's or tenant's approval to disburse escrowed rent.
+    pub fn approve_disbursement(
+        env: Env,
+        agreement_id: String,
+        caller: Address,
+    ) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
+        Self::check_paused(&env)?;
+        escrow::approve_disbursement(&env, agreement_id, caller)
+    }
+
+    /// Get the parties that have approved disbursing an agreement's escrowed rent.
+    pub fn get_disbursement_approvals(env: Env, agreement_id: String) -> Vec<Address> {
+        escrow::get_disbursement_approvals(&env, agreement_id)
+    }
+
+    /// Disburse escrowed rent to the landlord, once approved where required.
+    pub fn disburse(env: Env, agreement_id: String, caller: Address) -> Result<i128, RentalError> {
+        Self::check_initialized(&env)?;
+        Self::check_paused(&env)?;
+        guard::non_reentrant(&env, || escrow::disburse(&env, agreement_id, caller))
+    }
+
+    /// Disburse all accumulated escrowed rent in two transfers, the agent's
+    /// commission and the landlord's share (landlord only).
+    pub fn disburse_accumulated(
+        env: Env,
+        agreement_id: String,
+        token: Address,
+    ) -> Result<i128, RentalError> {
+        Self::check_initialized(&env)?;
+        Self::check_paused(&env)?;
+        guard::non_reentrant(&env, || {
+            escrow::disburse_accumulated(&env, agreement_id, token)
+        })
+    }
+
+    /// Disburse a landlord's escrowed rent in `token` that is past its
+    /// dispute window, across at most 50 of their agreements from index
+    /// `start`, returning the total swept (landlord only).
+    pub fn sweep_disbursable(
+        env: Env,
+        landlord: Address,
+        token: Address,
+        start: u32,
+        limit: u32,
+    ) -> Result<i128, RentalError> {
+        Self::check_initialized(&env)?;
+        Self::check_paused(&env)?;
+        guard::non_reentrant(&env, || {
+            Ok(escrow::sweep_disbursable(
+                &env, landlord, token, start, limit,
+            ))
+        })
+    }
+
+    /// Prepay the agent's full-term commission into a reserve (landlord only,
+    /// Active agreements). If the lease ends early, the unearned part is
+    /// refunded to the landlord.
+    pub fn fund_commission_reserve(env: Env, agreement_id: String) -> Result<i128, RentalError> {
+        Self::check_initialized(&env)?;
+        Self::check_paused(&env)?;
+        escrow::fund_commission_reserve(&env, agreement_id)
     }
 
-    // --- Agreement Functions with Token ---
-
-    pub fn create_agreement_with_token(
+    /// Prepay `months` of future rent into escrow (tenant only, Active agreements).
+    pub fn prepay_rent(
         env: Env,
-        input: crate::types::AgreementInput,
-    ) -> Result<String, RentalError> {
+        agreement_id: String,
+        token: Address,
+        months: u32,
+    ) -> Result<i128, RentalError> {
+        Self::check_initialized(&env)?;
         Self::check_paused(&env)?;
-        agreement::create_agreement_with_token(&env, input)
+        escrow::prepay_rent(&env, agreement_id, token, months)
     }
 
-    pub fn get_agreement_token(env: Env, agreement_id: String) -> Result<Address, RentalError> {
-        agreement::get_agreement_token(&env, agreement_id)
+    /// Draw prepaid rent for every earmarked period now due into the rent escrow.
+    pub fn disburse_prepaid_rent(env: Env, agreement_id: String) -> Result<u32, RentalError> {
+        Self::check_initialized(&env)?;
+        Self::check_paused(&env)?;
+        escrow::disburse_prepaid_rent(&env, agreement_id)
     }
 
-    // --- Payment Functions with Token ---
+    /// Get the prepaid rent escrowed for an agreement but not yet disbursed.
+    pub fn get_remaining_prepaid(env: Env, agreement_id: String) -> i128 {
+        escrow::get_remaining_prepaid(&env, agreement_id)
+    }
 
-    pub fn make_payment_with_token(
+    /// Get the funds held for an agreement, split by what they are earmarked for.
+    pub fn get_escrow_breakdown(
         env: Env,
         agreement_id: String,
-        amount: i128,
-        token: Address,
-    ) -> Result<(), RentalError> {
-        Self::check_paused(&env)?;
-        agreement::make_payment_with_token(&env, agreement_id, amount, token)
+    ) -> Result<EscrowBreakdown, RentalError> {
+        escrow::get_escrow_breakdown(&env, agreement_id)
     }
 
     pub fn release_escrow_with_token(
@@ -470,8 +1154,11 @@ impl Contract {
         escrow_id: String,
         token: Address,
     ) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
         Self::check_paused(&env)?;
-        agreement::release_escrow_with_token(&env, escrow_id, token)
+        guard::non_reentrant(&env, || {
+            agreement::release_escrow_with_token(&env, escrow_id, token)
+        })
     }
 
     /// Create a new rental agreement.
@@ -489,15 +1176,59 @@ impl Contract {
     /// @param agent_commission_rate Agent commission in basis points (0–100).
     /// @param payment_token Token address used for payments.
     /// @return Ok(()) on success.
+    /// @custom:error NotInitialized If the contract has not been initialized.
     #[allow(clippy::too_many_arguments)]
     pub fn create_agreement(
         env: Env,
         input: crate::types::AgreementInput,
     ) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
         Self::check_paused(&env)?;
         agreement::create_agreement(&env, input)
     }
 
+    /// Create a new Draft agreement using an existing agreement as a template.
+    ///
+    /// @notice Copies the source's rent, deposit, dates and commission into `new_id`
+    /// for `new_tenant`. The source landlord must authorize.
+    pub fn clone_agreement(
+        env: Env,
+        source_id: String,
+        new_id: String,
+        new_tenant: Address,
+    ) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
+        Self::check_paused(&env)?;
+        agreement::clone_agreement(&env, source_id, new_id, new_tenant)
+    }
+
+    /// Dry-run validation of agreement parameters.
+    ///
+    /// @notice Runs the same checks as `create_agreement`, including any configured
+    /// commission bounds, without storing anything or requiring auth.
+    /// @return Ok(()) if the parameters would be accepted.
+    /// @custom:error InvalidAmount If rent is not positive or the deposit is negative.
+    /// @custom:error InvalidDate If the dates are inverted or start too far in the past.
+    /// @custom:error InvalidCommissionRate If the rate is out of range.
+    pub fn validate_params(
+        env: Env,
+        monthly_rent: i128,
+        security_deposit: i128,
+        start_date: u64,
+        end_date: u64,
+        commission_rate: u32,
+    ) -> Result<(), RentalError> {
+        agreement::validate_agreement_params(
+            &env,
+            &monthly_rent,
+            &security_deposit,
+            &start_date,
+            &end_date,
+            &commission_rate,
+        )?;
+        policy::check_commission_rate(&env, commission_rate)
+    }
+
     /// Sign an existing rental agreement.
     ///
     /// @notice Tenant signs a pending agreement, moving it to Active. Tenant must authorize.
@@ -505,11 +1236,13 @@ impl Contract {
     /// @param tenant Address of the tenant signing (must authorize).
     /// @param agreement_id Identifier of the agreement to sign.
     /// @return Ok(()) on success.
+    /// @custom:error PropertyAlreadyLeased If an overlapping lease on the property is active.
     pub fn sign_agreement(
         env: Env,
         tenant: Address,
         agreement_id: String,
     ) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
         Self::check_paused(&env)?;
         agreement::sign_agreement(&env, tenant, agreement_id)
     }
@@ -526,13 +1259,138 @@ impl Contract {
         landlord: Address,
         agreement_id: String,
     ) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
         Self::check_paused(&env)?;
         agreement::submit_agreement(&env, landlord, agreement_id)
     }
 
+    /// Link an agreement to a property registry id.
+    ///
+    /// @notice Landlord attaches a property to a Draft or Pending agreement. Signing is
+    /// rejected while another active lease on the same property overlaps its dates.
+    /// @param env The Soroban environment.
+    /// @param landlord Address of the landlord (must authorize).
+    /// @param agreement_id Identifier of the agreement to link.
+    /// @param property_id Property registry identifier.
+    /// @return Ok(()) on success.
+    /// @custom:error InvalidState If the agreement is past Pending or already linked.
+    pub fn link_property(
+        env: Env,
+        landlord: Address,
+        agreement_id: String,
+        property_id: String,
+    ) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
+        Self::check_paused(&env)?;
+        agreement::link_property(&env, landlord, agreement_id, property_id)
+    }
+
+    /// Set the contract notified of each new agreement, or clear it with `None`
+    /// (admin only).
+    ///
+    /// The hook must expose `on_agreement_created(agreement_id: String)`.
+    pub fn set_create_hook(env: Env, hook: Option<Address>) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
+        let state = Self::get_state(env.clone()).ok_or(RentalError::InvalidState)?;
+        state.admin.require_auth();
+
+        match hook {
+            Some(hook) => env.storage().instance().set(&DataKey::CreateHook, &hook),
+            None => env.storage().instance().remove(&DataKey::CreateHook),
+        }
+        env.storage().instance().extend_ttl(500000, 500000);
+        events::config_changed(&env, symbol_short!("hook"));
+        Ok(())
+    }
+
+    /// Get the contract notified of each new agreement, if any.
+    pub fn get_create_hook(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::CreateHook)
+    }
+
+    /// Set the property registry contract used for property lookups (admin only).
+    pub fn set_property_registry(env: Env, registry: Address) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
+        let state = Self::get_state(env.clone()).ok_or(RentalError::InvalidState)?;
+        state.admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&DataKey::PropertyRegistry, &registry);
+        env.storage().instance().extend_ttl(500000, 500000);
+        events::config_changed(&env, symbol_short!("registry"));
+        Ok(())
+    }
+
+    /// Get the registry details of the property an agreement is linked to.
+    ///
+    /// @notice Cross-calls the configured property registry's `get_property`.
+    /// @return The property details, or None if the link, registry or record is missing.
+    pub fn get_agreement_property(env: Env, agreement_id: String) -> Option<PropertyDetails> {
+        agreement::get_agreement_property(&env, agreement_id)
+    }
+
+    /// Get the ids of all agreements linked to a property.
+    pub fn get_property_agreements(env: Env, property_id: String) -> Vec<String> {
+        agreement::get_property_agreements(&env, property_id)
+    }
+
+    /// Get the id of the lease currently Active on a property, if any.
+    pub fn get_property_active_agreement(env: Env, property_id: String) -> Option<String> {
+        agreement::get_property_active_agreement(&env, property_id)
+    }
+
+    /// Amend the monthly rent of an Active agreement.
+    ///
+    /// @notice Landlord and tenant must authorize; above the co-authorization
+    /// threshold the agent must authorize too.
+    /// @param env The Soroban environment.
+    /// @param agreement_id Identifier of the agreement to amend.
+    /// @param new_rent New monthly rent amount.
+    /// @return Ok(()) on success.
+    /// @custom:error InvalidParties If co-authorization is required but no agent is set.
+    pub fn amend_rent(env: Env, agreement_id: String, new_rent: i128) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
+        Self::check_paused(&env)?;
+        agreement::amend_rent(&env, agreement_id, new_rent)
+    }
+
+    /// Add a tenant to the landlord's allowlist (landlord only).
+    ///
+    /// @notice Once a landlord's allowlist is non-empty, agreements can only be
+    /// created for tenants on it.
+    pub fn add_allowed_tenant(
+        env: Env,
+        landlord: Address,
+        tenant: Address,
+    ) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
+        Self::check_paused(&env)?;
+        allowlist::add_allowed_tenant(&env, landlord, tenant);
+        Ok(())
+    }
+
+    /// Remove a tenant from the landlord's allowlist (landlord only).
+    pub fn remove_allowed_tenant(
+        env: Env,
+        landlord: Address,
+        tenant: Address,
+    ) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
+        Self::check_paused(&env)?;
+        allowlist::remove_allowed_tenant(&env, landlord, tenant);
+        Ok(())
+    }
+
+    /// Get the landlord's tenant allowlist (empty means open to any tenant).
+    pub fn get_tenant_allowlist(env: Env, landlord: Address) -> Vec<Address> {
+        allowlist::get_tenant_allowlist(&env, landlord)
+    }
+
     /// Cancel an agreement while in Draft or Pending state.
     ///
     /// @notice Landlord cancels a draft or pending agreement. Caller must be landlord.
+    /// Any rent already held in escrow is refunded to the tenant.
     /// @param env The Soroban environment.
     /// @param caller Address of the caller (must be the agreement landlord).
     /// @param agreement_id Identifier of the agreement to cancel.
@@ -542,10 +1400,37 @@ impl Contract {
         caller: Address,
         agreement_id: String,
     ) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
         Self::check_paused(&env)?;
         agreement::cancel_agreement(&env, caller, agreement_id)
     }
 
+    /// Expire abandoned draft agreements.
+    ///
+    /// @notice Admin-only sweep cancelling Draft agreements created before `older_than`.
+    /// @param env The Soroban environment.
+    /// @param admin Contract admin (must authorize).
+    /// @param older_than Creation timestamp cutoff (exclusive).
+    /// @param limit Maximum number of drafts to expire in this call.
+    /// @return The number of drafts expired.
+    /// @custom:error Unauthorized If `admin` is not the contract admin.
+    pub fn expire_stale_drafts(
+        env: Env,
+        admin: Address,
+        older_than: u64,
+        limit: u32,
+    ) -> Result<u32, RentalError> {
+        Self::check_initialized(&env)?;
+        Self::check_paused(&env)?;
+        let state = Self::get_state(env.clone()).ok_or(RentalError::InvalidState)?;
+        if state.admin != admin {
+            return Err(RentalError::Unauthorized);
+        }
+        admin.require_auth();
+
+        Ok(agreement::expire_stale_drafts(&env, older_than, limit))
+    }
+
     /// Retrieve details of a rental agreement.
     ///
     /// @notice Returns full agreement data (parties, amounts, dates, status) by ID.
@@ -556,6 +1441,101 @@ impl Contract {
         agreement::get_agreement(&env, agreement_id)
     }
 
+    /// Retrieve an agreement's scalar fields as a flat tuple.
+    ///
+    /// @notice For clients that cannot decode `RentAgreement`; the agent is omitted
+    /// and available through `get_agreement`.
+    /// @param env The Soroban environment.
+    /// @param agreement_id Identifier of the agreement.
+    /// @return (id, landlord, tenant, monthly_rent, security_deposit, start_date,
+    /// end_date, agent_commission_rate, payment_count).
+    /// @custom:error AgreementNotFound If the agreement does not exist.
+    #[allow(clippy::type_complexity)]
+    pub fn get_agreement_flat(
+        env: Env,
+        agreement_id: String,
+    ) -> Result<(String, Address, Address, i128, i128, u64, u64, u32, u32), RentalError> {
+        let agreement = agreement::load_agreement(&env, &agreement_id)?;
+        Ok((
+            agreement.agreement_id,
+            agreement.landlord,
+            agreement.tenant,
+            agreement.monthly_rent,
+            agreement.security_deposit,
+            agreement.start_date,
+            agreement.end_date,
+            agreement.agent_commission_rate,
+            agreement.payment_count,
+        ))
+    }
+
+    /// Retrieve several rental agreements in one call.
+    ///
+    /// @notice Results are aligned with `ids`, with None for ids that do not exist.
+    /// @param env The Soroban environment.
+    /// @param ids Up to 50 agreement identifiers.
+    /// @return One entry per requested id.
+    /// @custom:error InvalidInput If more than 50 ids are requested.
+    pub fn get_agreements_batch(
+        env: Env,
+        ids: Vec<String>,
+    ) -> Result<Vec<Option<RentAgreement>>, RentalError> {
+        agreement::get_agreements_batch(&env, ids)
+    }
+
+    /// List agreements in creation order, optionally filtered by status.
+    ///
+    /// @notice Private agreements are left out unless `viewer` is a party. This
+    /// only shapes the listing; every agreement stays readable via `get_agreement`.
+    /// @param env The Soroban environment.
+    /// @param viewer Address whose own private agreements should be included.
+    /// @param status Only return agreements in this status, if given.
+    /// @param start Index of the first agreement to scan.
+    /// @param limit Maximum number of agreements to scan (capped at 50).
+    /// @return The visible agreements among those scanned.
+    pub fn list_agreements(
+        env: Env,
+        viewer: Option<Address>,
+        status: Option<AgreementStatus>,
+        start: u32,
+        limit: u32,
+    ) -> Vec<RentAgreement> {
+        agreement::list_agreements(&env, viewer, status, start, limit)
+    }
+
+    /// Get the ids of public agreements filed under `category`.
+    ///
+    /// The category is set at creation from a `category` attribute
+    /// (residential, commercial or short_term; residential when absent).
+    /// Pages over creation order like `list_agreements`.
+    pub fn get_agreements_by_category(
+        env: Env,
+        category: String,
+        start: u32,
+        limit: u32,
+    ) -> Vec<String> {
+        agreement::get_agreements_by_category(&env, category, start, limit)
+    }
+
+    /// Hide the agreement from listings for non-parties, or show it again
+    /// (landlord only).
+    pub fn set_public(env: Env, agreement_id: String, public: bool) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
+        Self::check_paused(&env)?;
+        agreement::set_public(&env, agreement_id, public)
+    }
+
+    /// Get the on-chain audit trail of lifecycle changes for an agreement.
+    ///
+    /// @notice Returns `(timestamp, action)` entries oldest first, keeping only the
+    /// most recent `AUDIT_LOG_WINDOW` entries.
+    /// @param env The Soroban environment.
+    /// @param agreement_id Identifier of the agreement.
+    /// @return The recorded entries, empty if none.
+    pub fn get_audit_log(env: Env, agreement_id: String) -> Vec<(u64, Symbol)> {
+        audit::get_audit_log(&env, agreement_id)
+    }
+
     /// Check if an agreement exists for a given ID.
     ///
     /// @notice Returns whether an agreement with the given ID is stored.
@@ -575,6 +1555,15 @@ impl Contract {
         agreement::get_agreement_count(&env)
     }
 
+    /// Get the distinct agents seen on created agreements.
+    ///
+    /// @notice Used for commission reconciliation; each agent appears once.
+    /// @param env The Soroban environment.
+    /// @return Agent addresses in the order they were first seen.
+    pub fn get_agents(env: Env) -> Vec<Address> {
+        agreement::get_agents(&env)
+    }
+
     /// Get the payment split details for a specific month of an agreement.
     ///
     /// @notice Returns landlord, tenant, and agent amounts for a given month from payment history.
@@ -602,6 +1591,7 @@ impl Contract {
         metadata_uri: String,
         attributes: Vec<Attribute>,
     ) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
         Self::check_paused(&env)?;
         agreement::update_metadata(&env, agreement_id, metadata_uri, attributes)
     }
@@ -618,6 +1608,7 @@ impl Contract {
         compounding_frequency: CompoundingFrequency,
         interest_recipient: InterestRecipient,
     ) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
         Self::check_paused(&env)?;
         let state = Self::get_state(env.clone()).ok_or(RentalError::InvalidState)?;
         state.admin.require_auth();
@@ -630,6 +1621,21 @@ impl Contract {
         )
     }
 
+    /// Set how long a deposit must be held before interest starts accruing.
+    ///
+    /// Admin-only. Requires an existing interest configuration.
+    pub fn set_interest_grace(
+        env: Env,
+        agreement_id: String,
+        interest_grace: u64,
+    ) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
+        Self::check_paused(&env)?;
+        let state = Self::get_state(env.clone()).ok_or(RentalError::InvalidState)?;
+        state.admin.require_auth();
+        deposit_interest::set_interest_grace(env, agreement_id, interest_grace)
+    }
+
     /// Get the interest configuration for a security deposit.
     pub fn get_deposit_interest_config(
         env: Env,
@@ -645,6 +1651,7 @@ impl Contract {
 
     /// Accrue interest up to the current ledger time and persist the update.
     pub fn accrue_interest(env: Env, escrow_id: String) -> Result<InterestAccrual, RentalError> {
+        Self::check_initialized(&env)?;
         Self::check_paused(&env)?;
         deposit_interest::accrue_interest(env, escrow_id)
     }
@@ -667,12 +1674,14 @@ impl Contract {
 
     /// Distribute all accrued interest to tenant / landlord per configuration.
     pub fn distribute_interest(env: Env, escrow_id: String) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
         Self::check_paused(&env)?;
         deposit_interest::distribute_interest(env, escrow_id)
     }
 
     /// (Keeper / oracle entry-point) Accrue interest for all deposits.
     pub fn process_interest_accruals(env: Env) -> Result<Vec<String>, RentalError> {
+        Self::check_initialized(&env)?;
         Self::check_paused(&env)?;
         deposit_interest::process_interest_accruals(env)
     }
@@ -692,6 +1701,204 @@ impl Contract {
         errors::get_error_logs(&env, limit)
     }
 
+    // ─── Application Fee Functions ───────────────────────────────────────────
+
+    /// Escrow an application fee for a landlord's property (applicant only).
+    ///
+    /// The fee counts toward the security deposit of an agreement later made
+    /// between the same parties for that property, and is otherwise refundable
+    /// by the landlord, or reclaimable by the applicant after a timeout.
+    pub fn pay_application_fee(
+        env: Env,
+        applicant: Address,
+        landlord: Address,
+        property_id: String,
+        token: Address,
+        amount: i128,
+    ) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
+        Self::check_paused(&env)?;
+        application::pay_application_fee(&env, applicant, landlord, property_id, token, amount)
+    }
+
+    /// Return an unused application fee to the applicant (landlord only).
+    pub fn refund_application_fee(
+        env: Env,
+        landlord: Address,
+        applicant: Address,
+        property_id: String,
+    ) -> Result<i128, RentalError> {
+        Self::check_initialized(&env)?;
+        Self::check_paused(&env)?;
+        application::refund_application_fee(&env, landlord, applicant, property_id)
+    }
+
+    /// Reclaim an unused application fee once the refund timeout has passed
+    /// since it was last paid into (applicant only).
+    pub fn reclaim_application_fee(
+        env: Env,
+        applicant: Address,
+        property_id: String,
+    ) -> Result<i128, RentalError> {
+        Self::check_initialized(&env)?;
+        Self::check_paused(&env)?;
+        application::reclaim_application_fee(&env, applicant, property_id)
+    }
+
+    /// Get the application fee held for an applicant and property, if any.
+    pub fn get_application_fee(
+        env: Env,
+        applicant: Address,
+        property_id: String,
+    ) -> Option<ApplicationFee> {
+        application::get_application_fee(&env, applicant, property_id)
+    }
+
+    // ─── Security Deposit Functions ──────────────────────────────────────────
+
+    /// Mark whether the agreement's deposit is refundable (landlord only, before activation).
+    pub fn set_deposit_refundable(
+        env: Env,
+        agreement_id: String,
+        refundable: bool,
+    ) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
+        Self::check_paused(&env)?;
+        deposit::set_deposit_refundable(&env, agreement_id, refundable)
+    }
+
+    /// Apply the deposit to the final period's unpaid rent at settlement instead
+    /// of refunding it (landlord only, before activation).
+    pub fn set_apply_deposit_to_last(
+        env: Env,
+        agreement_id: String,
+        enabled: bool,
+    ) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
+        Self::check_paused(&env)?;
+        deposit::set_apply_deposit_to_last(&env, agreement_id, enabled)
+    }
+
+    /// Choose whether rent payment events publish the paid amount (landlord only).
+    ///
+    /// With amounts off, payment events still carry the agreement id and payment
+    /// number but report an amount of 0.
+    pub fn set_emit_amounts(env: Env, agreement_id: String, emit: bool) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
+        Self::check_paused(&env)?;
+        agreement::set_emit_amounts(&env, agreement_id, emit)
+    }
+
+    /// Activate the agreement as soon as the tenant funds the deposit
+    /// (landlord only, before activation).
+    pub fn set_auto_activate_on_funding(
+        env: Env,
+        agreement_id: String,
+        enabled: bool,
+    ) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
+        Self::check_paused(&env)?;
+        deposit::set_auto_activate_on_funding(&env, agreement_id, enabled)
+    }
+
+    /// Back a deposit-free agreement with a guarantor instead (landlord and
+    /// guarantor, before activation).
+    pub fn set_guarantor(
+        env: Env,
+        agreement_id: String,
+        guarantor: Address,
+    ) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
+        Self::check_paused(&env)?;
+        deposit::set_guarantor(&env, agreement_id, guarantor)
+    }
+
+    /// Collect overdue rent, up to the outstanding balance, from the guarantor
+    /// of a deposit-free agreement (landlord only).
+    ///
+    /// The guarantor must first approve this contract as a spender on the token.
+    pub fn charge_guarantor(
+        env: Env,
+        agreement_id: String,
+        token: Address,
+        amount: i128,
+    ) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
+        Self::check_paused(&env)?;
+        deposit::charge_guarantor(&env, agreement_id, token, amount)
+    }
+
+    /// Transfer the security deposit from the tenant into contract custody.
+    pub fn deposit_security(env: Env, agreement_id: String) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
+        Self::check_paused(&env)?;
+        deposit::deposit_security(&env, agreement_id)
+    }
+
+    /// Return a refundable deposit to the tenant (landlord only).
+    ///
+    /// Fails with `InvalidState` if the deposit is non-refundable or the
+    /// agreement has not ended.
+    pub fn release_deposit(env: Env, agreement_id: String) -> Result<i128, RentalError> {
+        Self::check_initialized(&env)?;
+        Self::check_paused(&env)?;
+        guard::non_reentrant(&env, || deposit::release_deposit(&env, agreement_id))
+    }
+
+    /// Claim back a refundable deposit once the agreement has ended and the
+    /// inspection period has passed (tenant only).
+    pub fn claim_deposit_refund(env: Env, agreement_id: String) -> Result<i128, RentalError> {
+        Self::check_initialized(&env)?;
+        Self::check_paused(&env)?;
+        guard::non_reentrant(&env, || deposit::claim_deposit_refund(&env, agreement_id))
+    }
+
+    /// Configure a vault to hold new security deposits (admin only).
+    ///
+    /// The vault must approve this contract as a spender of the deposit token so
+    /// deposits can be released. Passing `None` returns to self-custody on the
+    /// contract. Deposits already held stay with the custodian recorded when they
+    /// were made.
+    pub fn set_vault(env: Env, vault: Option<Address>) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
+        let state = Self::get_state(env.clone()).ok_or(RentalError::InvalidState)?;
+        state.admin.require_auth();
+
+        deposit::set_vault(&env, vault);
+        events::config_changed(&env, symbol_short!("vault"));
+        Ok(())
+    }
+
+    /// Get the configured deposit vault, if any.
+    pub fn get_vault(env: Env) -> Option<Address> {
+        deposit::get_vault(&env)
+    }
+
+    /// Get where an agreement's security deposit is held and how much.
+    pub fn get_deposit_custody(env: Env, agreement_id: String) -> Option<DepositCustody> {
+        deposit::get_deposit_custody(&env, agreement_id)
+    }
+
+    /// Get the security deposit currently held for an agreement.
+    pub fn get_deposit_held(env: Env, agreement_id: String) -> i128 {
+        deposit::get_deposit_held(&env, agreement_id)
+    }
+
+    /// Complete an agreement after its end date and settle the held deposit.
+    ///
+    /// Refundable deposits return to the tenant; non-refundable ones go to the landlord.
+    pub fn settle_agreement(
+        env: Env,
+        caller: Address,
+        agreement_id: String,
+    ) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
+        Self::check_paused(&env)?;
+        guard::non_reentrant(&env, || {
+            deposit::settle_agreement(&env, caller, agreement_id)
+        })
+    }
+
     // ─── Royalty Functions ───────────────────────────────────────────────────
 
     /// Set the royalty configuration for a specific token (agreement).
@@ -701,6 +1908,7 @@ impl Contract {
         royalty_percentage: u32,
         royalty_recipient: Address,
     ) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
         Self::check_paused(&env)?;
         royalties::set_royalty(env, token_id, royalty_percentage, royalty_recipient)
     }
@@ -726,6 +1934,7 @@ impl Contract {
         to: Address,
         sale_price: i128,
     ) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
         Self::check_paused(&env)?;
         royalties::transfer_with_royalty(env, token_id, to, sale_price)
     }
@@ -742,6 +1951,7 @@ impl Contract {
 
     /// Set rate limit configuration (admin only).
     pub fn set_rate_limit_config(env: Env, config: RateLimitConfig) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
         let state = Self::get_state(env.clone()).ok_or(RentalError::InvalidState)?;
         state.admin.require_auth();
 
@@ -753,6 +1963,7 @@ impl Contract {
             config.max_calls_per_user_per_day,
             config.cooldown_blocks,
         );
+        events::config_changed(&env, symbol_short!("rate_lim"));
 
         Ok(())
     }
@@ -782,6 +1993,7 @@ impl Contract {
         user: Address,
         function_name: String,
     ) -> Result<(), RentalError> {
+        Self::check_initialized(&env)?;
         let state = Self::get_state(env.clone()).ok_or(RentalError::InvalidState)?;
         state.admin.require_auth();
 
@@ -797,6 +2009,7 @@ impl Contract {
         required_signatures: u32,
     ) -> Result<(), RentalError> {
         // Only contract admin can initialize multi-sig
+        Self::check_initialized(&env)?;
         let state = Self::get_state(env.clone()).ok_or(RentalError::InvalidState)?;
         state.admin.require_auth();
 
@@ -938,3 +2151,35 @@ impl Contract {
         timelock::get_action_count(&env)
     }
 }
+
+/// Test-only entry points, compiled in with the `testutils` feature.
+#[cfg(feature = "testutils")]
+#[contractimpl]
+impl Contract {
+    /// Create and activate an agreement in one call for integration tests.
+    ///
+    /// @notice No signatures or deposit are required; never present in production WASM.
+    /// @param env The Soroban environment.
+    /// @param agreement_id Unique identifier for the agreement.
+    /// @param landlord Landlord address.
+    /// @param tenant Tenant address.
+    /// @param monthly_rent Monthly rent amount.
+    /// @param payment_token Token rent is paid in.
+    pub fn seed_active_agreement(
+        env: Env,
+        agreement_id: String,
+        landlord: Address,
+        tenant: Address,
+        monthly_rent: i128,
+        payment_token: Address,
+    ) -> Result<(), RentalError> {
+        agreement::seed_active_agreement(
+            &env,
+            agreement_id,
+            landlord,
+            tenant,
+            monthly_rent,
+            payment_token,
+        )
+    }
+}
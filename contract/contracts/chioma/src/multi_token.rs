@@ -149,3 +149,44 @@ pub fn convert_amount(
         / 1_000_000_000_000_000_000;
     Ok(converted)
 }
+
+/// Render `amount` as a decimal string with `decimals` fractional digits,
+/// e.g. 10000000 at 7 decimals is "1.0000000". Decimals above 38 (the most an
+/// i128 can carry) are treated as 38.
+pub fn format_amount(env: &Env, amount: i128, decimals: u32) -> String {
+    let decimals = decimals.min(38) as usize;
+    // Up to 39 digits, plus the sign and decimal point.
+    let mut buf = [0u8; 41];
+    let mut pos = buf.len();
+    let mut value = amount.unsigned_abs();
+    let mut written = 0;
+
+    while value > 0 || written <= decimals {
+        if decimals > 0 && written == decimals {
+            pos -= 1;
+            buf[pos] = b'.';
+        }
+        pos -= 1;
+        buf[pos] = b'0' + (value % 10) as u8;
+        value /= 10;
+        written += 1;
+    }
+    if amount < 0 {
+        pos -= 1;
+        buf[pos] = b'-';
+    }
+    String::from_bytes(env, &buf[pos..])
+}
+
+/// Total value locked: the contract's balance held in each of the given tokens.
+pub fn get_tvl(env: Env, tokens: Vec<Address>) -> Vec<(Address, i128)> {
+    let contract_addr = env.current_contract_address();
+    let mut tvl = Vec::new(&env);
+
+    for token in tokens.iter() {
+        let client = soroban_sdk::token::Client::new(&env, &token);
+        let balance = client.balance(&contract_addr);
+        tvl.push_back((token, balance));
+    }
+    tvl
+}
@@ -0,0 +1,226 @@
+//! Deployment-wide agreement policy set by the contract admin.
+
+use soroban_sdk::Env;
+
+use crate::errors::RentalError;
+use crate::storage::DataKey;
+use crate::types::AgreementPolicy;
+
+const MAX_BPS: u32 = 10_000;
+/// Decimals the minimum deposit is quoted at, as for Stellar assets.
+const MIN_DEPOSIT_DECIMALS: u32 = 7;
+
+/// Get the current agreement policy, falling back to permissive defaults.
+pub fn get_policy(env: &Env) -> AgreementPolicy {
+    env.storage()
+        .instance()
+        .get(&DataKey::Policy)
+        .unwrap_or(AgreementPolicy {
+            coauth_threshold: 0,
+            min_commission_bps: 0,
+            max_commission_bps: MAX_BPS,
+            max_increase_bps: None,
+            inspection_period: 0,
+            min_duration: 0,
+            max_arrears_periods: 0,
+            escrow_dispute_window: 0,
+            tick_size: 0,
+            dispute_escalation_window: 0,
+            min_deposit: 0,
+            require_future_start: false,
+            require_landlord_auth: false,
+            notice_period: 0,
+        })
+}
+
+fn save_policy(env: &Env, policy: &AgreementPolicy) {
+    env.storage().instance().set(&DataKey::Policy, policy);
+    env.storage().instance().extend_ttl(500000, 500000);
+}
+
+/// Set the rent above which amendments also need the agent's authorization.
+///
+/// A threshold of 0 disables co-authorization.
+pub fn set_coauth_threshold(env: &Env, threshold: i128) -> Result<(), RentalError> {
+    if threshold < 0 {
+        return Err(RentalError::InvalidAmount);
+    }
+
+    let mut policy = get_policy(env);
+    policy.coauth_threshold = threshold;
+    save_policy(env, &policy);
+    Ok(())
+}
+
+/// Set the band of agent commission rates accepted on new agreements.
+pub fn set_commission_bounds(env: &Env, min_bps: u32, max_bps: u32) -> Result<(), RentalError> {
+    if min_bps > max_bps || max_bps > MAX_BPS {
+        return Err(RentalError::InvalidConfig);
+    }
+
+    let mut policy = get_policy(env);
+    policy.min_commission_bps = min_bps;
+    policy.max_commission_bps = max_bps;
+    save_policy(env, &policy);
+    Ok(())
+}
+
+/// Cap rent increases on amendment at `max_bps` of the prior rent, or lift the cap with `None`.
+pub fn set_max_increase(env: &Env, max_bps: Option<u32>) {
+    let mut policy = get_policy(env);
+    policy.max_increase_bps = max_bps;
+    save_policy(env, &policy);
+}
+
+/// Hold deposits for `period` seconds after an agreement completes, for inspection.
+pub fn set_inspection_period(env: &Env, period: u64) {
+    let mut policy = get_policy(env);
+    policy.inspection_period = period;
+    save_policy(env, &policy);
+}
+
+/// Set the shortest lease term, in seconds, accepted on new agreements.
+pub fn set_min_duration(env: &Env, min_duration: u64) {
+    let mut policy = get_policy(env);
+    policy.min_duration = min_duration;
+    save_policy(env, &policy);
+}
+
+/// Let landlords terminate leases once `periods` rent periods are unpaid (0 = off).
+pub fn set_max_arrears_periods(env: &Env, periods: u32) {
+    let mut policy = get_policy(env);
+    policy.max_arrears_periods = periods;
+    save_policy(env, &policy);
+}
+
+/// Keep escrowed rent out of landlord sweeps for `window` seconds after each payment.
+pub fn set_escrow_dispute_window(env: &Env, window: u64) {
+    let mut policy = get_policy(env);
+    policy.escrow_dispute_window = window;
+    save_policy(env, &policy);
+}
+
+/// Require rents to be whole multiples of `tick_size` (0 or 1 = any amount).
+pub fn set_tick_size(env: &Env, tick_size: i128) -> Result<(), RentalError> {
+    if tick_size < 0 {
+        return Err(RentalError::InvalidAmount);
+    }
+
+    let mut policy = get_policy(env);
+    policy.tick_size = tick_size;
+    save_policy(env, &policy);
+    Ok(())
+}
+
+/// Reject a rent that is not a multiple of the configured tick size with
+/// `InvalidTick`.
+pub(crate) fn check_tick(env: &Env, rent: i128) -> Result<(), RentalError> {
+    let tick = get_policy(env).tick_size;
+    if tick > 1 && rent % tick != 0 {
+        return Err(RentalError::InvalidTick);
+    }
+    Ok(())
+}
+
+/// Let parties escalate disputes left unresolved for `window` seconds (0 = off).
+pub fn set_dispute_escalation_window(env: &Env, window: u64) {
+    let mut policy = get_policy(env);
+    policy.dispute_escalation_window = window;
+    save_policy(env, &policy);
+}
+
+/// Set the smallest security deposit accepted, quoted at 7 decimals (0 = no minimum).
+pub fn set_min_deposit(env: &Env, min_deposit: i128) -> Result<(), RentalError> {
+    if min_deposit < 0 {
+        return Err(RentalError::InvalidAmount);
+    }
+
+    let mut policy = get_policy(env);
+    policy.min_deposit = min_deposit;
+    save_policy(env, &policy);
+    Ok(())
+}
+
+/// Require new agreements to start at or after their creation time.
+pub fn set_require_future_start(env: &Env, required: bool) {
+    let mut policy = get_policy(env);
+    policy.require_future_start = required;
+    save_policy(env, &policy);
+}
+
+/// Require the landlord, as well as the tenant, to authorize agreement creation.
+pub fn set_require_landlord_auth(env: &Env, required: bool) {
+    let mut policy = get_policy(env);
+    policy.require_landlord_auth = required;
+    save_policy(env, &policy);
+}
+
+/// Let requested breaks finalize once `notice_period` seconds have passed (0 = off).
+pub fn set_notice_period(env: &Env, notice_period: u64) {
+    let mut policy = get_policy(env);
+    policy.notice_period = notice_period;
+    save_policy(env, &policy);
+}
+
+/// Reject a deposit below the configured minimum, scaled to a token with
+/// `token_decimals` decimals.
+pub(crate) fn check_min_deposit(
+    env: &Env,
+    deposit: i128,
+    token_decimals: u32,
+) -> Result<(), RentalError> {
+    let min_deposit = get_policy(env).min_deposit;
+    if min_deposit == 0 {
+        return Ok(());
+    }
+
+    let scaled = if token_decimals >= MIN_DEPOSIT_DECIMALS {
+        min_deposit.saturating_mul(10i128.pow(token_decimals - MIN_DEPOSIT_DECIMALS))
+    } else {
+        min_deposit / 10i128.pow(MIN_DEPOSIT_DECIMALS - token_decimals)
+    };
+    if deposit < scaled {
+        return Err(RentalError::InvalidAmount);
+    }
+    Ok(())
+}
+
+/// Reject lease terms shorter than the configured minimum.
+pub(crate) fn check_duration(env: &Env, start_date: u64, end_date: u64) -> Result<(), RentalError> {
+    if end_date.saturating_sub(start_date) < get_policy(env).min_duration {
+        return Err(RentalError::DurationTooShort);
+    }
+    Ok(())
+}
+
+/// Reject an amended rent that raises `old_rent` by more than the configured cap.
+pub(crate) fn check_rent_increase(
+    env: &Env,
+    old_rent: i128,
+    new_rent: i128,
+) -> Result<(), RentalError> {
+    let max_bps = match get_policy(env).max_increase_bps {
+        Some(max_bps) => max_bps,
+        None => return Ok(()),
+    };
+
+    let ceiling = old_rent
+        .checked_mul(MAX_BPS as i128 + max_bps as i128)
+        .ok_or(RentalError::InvalidAmount)?
+        / MAX_BPS as i128;
+    if new_rent > ceiling {
+        return Err(RentalError::IncreaseTooLarge);
+    }
+    Ok(())
+}
+
+/// Reject agent commission rates outside the configured band. Rates are whole
+/// percent and the band is in basis points.
+pub(crate) fn check_commission_rate(env: &Env, rate: u32) -> Result<(), RentalError> {
+    let policy = get_policy(env);
+    let rate_bps = rate.saturating_mul(100);
+    if rate_bps < policy.min_commission_bps || rate_bps > policy.max_commission_bps {
+        return Err(RentalError::InvalidCommissionRate);
+    }
+    Ok(())
+}
@@ -22,11 +22,7 @@ pub fn set_royalty(
     }
 
     // Read agreement to verify landlord (creator)
-    let agreement = env
-        .storage()
-        .persistent()
-        .get::<DataKey, crate::types::RentAgreement>(&DataKey::Agreement(token_id.clone()))
-        .ok_or(RentalError::AgreementNotFound)?;
+    let agreement = crate::agreement::load_agreement(&env, &token_id)?;
 
     agreement.landlord.require_auth();
 
@@ -78,11 +74,7 @@ pub fn transfer_with_royalty(
         return Err(RentalError::InvalidAmount);
     }
 
-    let mut agreement = env
-        .storage()
-        .persistent()
-        .get::<DataKey, crate::types::RentAgreement>(&DataKey::Agreement(token_id.clone()))
-        .ok_or(RentalError::AgreementNotFound)?;
+    let mut agreement = crate::agreement::load_agreement(&env, &token_id)?;
 
     let current_landlord = agreement.landlord.clone();
     current_landlord.require_auth();
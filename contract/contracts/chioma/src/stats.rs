@@ -0,0 +1,179 @@
+//! Running totals for operator dashboards, maintained as agreements change.
+
+use soroban_sdk::{Address, Env, String, Vec};
+
+use crate::agreement::MAX_BATCH_SIZE;
+use crate::storage::{DataKey, IndexKey};
+use crate::types::{AgreementStatus, ContractStats, PortfolioSummary};
+
+/// Current contract-wide totals.
+pub fn get_stats(env: &Env) -> ContractStats {
+    env.storage()
+        .instance()
+        .get(&DataKey::Stats)
+        .unwrap_or(ContractStats {
+            total_agreements: 0,
+            active_agreements: 0,
+            total_payments: 0,
+        })
+}
+
+fn save_stats(env: &Env, stats: &ContractStats) {
+    env.storage().instance().set(&DataKey::Stats, stats);
+}
+
+pub(crate) fn record_agreement_created(env: &Env, landlord: &Address, agreement_id: &String) {
+    let mut stats = get_stats(env);
+    stats.total_agreements += 1;
+    save_stats(env, &stats);
+
+    let count = get_landlord_agreement_count(env, landlord);
+    let key = IndexKey::LandlordAgreement(landlord.clone(), count);
+    env.storage().persistent().set(&key, agreement_id);
+    env.storage().persistent().extend_ttl(&key, 500000, 500000);
+
+    let count_key = IndexKey::LandlordAgreementCount(landlord.clone());
+    env.storage().persistent().set(&count_key, &(count + 1));
+    env.storage()
+        .persistent()
+        .extend_ttl(&count_key, 500000, 500000);
+}
+
+/// Number of agreements created for a landlord, closed ones included.
+pub fn get_landlord_agreement_count(env: &Env, landlord: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&IndexKey::LandlordAgreementCount(landlord.clone()))
+        .unwrap_or(0)
+}
+
+/// A landlord's agreement ids in creation order: at most `limit` (capped at
+/// 50) from index `start`.
+pub(crate) fn get_landlord_agreements(
+    env: &Env,
+    landlord: &Address,
+    start: u32,
+    limit: u32,
+) -> Vec<String> {
+    let end = start
+        .saturating_add(limit.min(MAX_BATCH_SIZE))
+        .min(get_landlord_agreement_count(env, landlord));
+    let mut ids = Vec::new(env);
+    for i in start..end {
+        if let Some(id) = env
+            .storage()
+            .persistent()
+            .get(&IndexKey::LandlordAgreement(landlord.clone(), i))
+        {
+            ids.push_back(id);
+        }
+    }
+    ids
+}
+
+/// Totals across a page of a landlord's agreements paid in `token`, read
+/// from the per-agreement running figures. Closed agreements are no longer
+/// counted.
+///
+/// Covers at most `limit` agreements (capped at 50) from index `start`;
+/// dashboards add up the pages up to `get_landlord_agreement_count`.
+pub fn get_portfolio_summary(
+    env: &Env,
+    landlord: Address,
+    token: Address,
+    start: u32,
+    limit: u32,
+) -> PortfolioSummary {
+    let mut summary = PortfolioSummary {
+        agreement_count: 0,
+        active_count: 0,
+        total_income: 0,
+        total_arrears: 0,
+        deposits_held: 0,
+    };
+
+    for agreement_id in get_landlord_agreements(env, &landlord, start, limit).iter() {
+        let agreement = match crate::agreement::get_agreement(env, agreement_id.clone()) {
+            Some(agreement) if agreement.payment_token == token => agreement,
+            _ => continue,
+        };
+
+        summary.agreement_count += 1;
+        summary.total_income += agreement.total_rent_paid;
+        summary.deposits_held += crate::deposit::get_deposit_held(env, agreement_id.clone());
+        if agreement.status == AgreementStatus::Active {
+            summary.active_count += 1;
+            summary.total_arrears +=
+                crate::billing::get_outstanding_balance(env, agreement_id).unwrap_or(0);
+        }
+    }
+    summary
+}
+
+pub(crate) fn record_agreement_closed(env: &Env) {
+    let mut stats = get_stats(env);
+    stats.total_agreements = stats.total_agreements.saturating_sub(1);
+    save_stats(env, &stats);
+}
+
+pub(crate) fn record_status_change(env: &Env, from: &AgreementStatus, to: &AgreementStatus) {
+    let was_active = *from == AgreementStatus::Active;
+    let is_active = *to == AgreementStatus::Active;
+    if was_active == is_active {
+        return;
+    }
+
+    let mut stats = get_stats(env);
+    if is_active {
+        stats.active_agreements += 1;
+    } else {
+        stats.active_agreements = stats.active_agreements.saturating_sub(1);
+    }
+    save_stats(env, &stats);
+}
+
+/// Count a rent payment of `amount` in `token`.
+pub(crate) fn record_payment(env: &Env, token: &Address, amount: i128) {
+    let mut stats = get_stats(env);
+    stats.total_payments += 1;
+    save_stats(env, &stats);
+
+    let key = DataKey::RentVolume(token.clone());
+    let volume: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+    env.storage().persistent().set(&key, &(volume + amount));
+    env.storage().persistent().extend_ttl(&key, 500000, 500000);
+}
+
+/// Cumulative rent paid in `token` across all agreements.
+pub fn get_rent_volume(env: &Env, token: Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RentVolume(token))
+        .unwrap_or(0)
+}
+
+/// The landlord's active agreements whose `end_date` falls within `within`
+/// seconds of now, including any already past it.
+///
+/// Scans at most `limit` of the landlord's agreements (capped at 50) from
+/// index `start`.
+pub fn get_upcoming_renewals(
+    env: &Env,
+    landlord: Address,
+    within: u64,
+    start: u32,
+    limit: u32,
+) -> Vec<String> {
+    let cutoff = env.ledger().timestamp().saturating_add(within);
+    let mut ids = Vec::new(env);
+    for agreement_id in get_landlord_agreements(env, &landlord, start, limit).iter() {
+        let due =
+            crate::agreement::get_agreement(env, agreement_id.clone()).is_some_and(|agreement| {
+                agreement.status == AgreementStatus::Active && agreement.end_date <= cutoff
+            });
+        if due {
+            ids.push_back(agreement_id);
+        }
+    }
+    ids
+}
@@ -1,10 +1,11 @@
-use soroban_sdk::{contracttype, String};
+use soroban_sdk::{contracttype, Address, String};
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum DataKey {
     Agreement(String),
     AgreementCount,
+    AgreementIndex(u32), // creation order -> agreement_id
     State,
     PauseState,
     Initialized,
@@ -22,6 +23,27 @@ pub enum DataKey {
     UserCallCount(soroban_sdk::Address, String), // (user, function_name)
     BlockCallCount(u64, String),                 // (block_number, function_name)
     PaymentRecord(String, u32),
+    PropertyAgreements(String),      // property_id -> agreement ids
+    PropertyActiveAgreement(String), // property_id -> its Active agreement
+    DepositHeld(String),
+    VaultAddr,
+    AuditLog(String),
+    Policy,
+    PropertyRegistry,
+    TenantAllowlist(soroban_sdk::Address), // landlord -> allowed tenants
+    EscrowBalance(String),                 // rent held for an agreement, in its payment token
+    Prepaid(String),                       // rent prepaid for future periods
+    AgentsSet,                             // distinct agents seen at creation
+    DisbursementApprovals(String),         // parties approving escrow disbursement
+    ApplicationFee(soroban_sdk::Address, String), // (applicant, property_id) -> fee held
+    CreateHook,                            // contract notified of new agreements
+    Resolution(String),                    // decided dispute awaiting acknowledgments
+    DisputeRaisedAt(String),               // when the open dispute was raised
+    DisputeEscalated(String),              // open dispute handed to the admin
+    Stats,                                 // running contract-wide totals
+    EventSeq(String),                      // events emitted for an agreement
+    RentVolume(soroban_sdk::Address),      // rent paid in a token, across agreements
+    ReentrancyGuard,                       // held while funds are paid out
     // Multi-sig keys
     MultiSigConfig,
     AdminProposal(String),
@@ -35,3 +57,15 @@ pub enum DataKey {
     CurrentVersion,
     VersionHistory,
 }
+
+/// Keys kept apart from `DataKey`, which is at the 50-variant limit of a
+/// contract type enum.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum IndexKey {
+    LandlordAgreement(Address, u32), // (landlord, creation order) -> agreement_id
+    LandlordAgreementCount(Address), // agreements created for a landlord
+    ClosedAgreement(String),         // closed agreement_id -> its final status
+    TokenEscrow(String, Address),    // (agreement_id, token) -> rent held in another token
+    EscrowTokens(String),            // tokens besides its own an agreement holds rent in
+}
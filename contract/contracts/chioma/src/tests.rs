@@ -1,7 +1,8 @@
 use super::*;
 use soroban_sdk::{
+    symbol_short,
     testutils::{Address as _, Events, Ledger, MockAuth, MockAuthInvoke},
-    Address, Env, IntoVal, String,
+    vec, Address, Env, IntoVal, String,
 };
 
 #[test]
@@ -131,6 +132,12 @@ fn create_contract(env: &Env) -> ContractClient<'_> {
     ContractClient::new(env, &contract_id)
 }
 
+fn setup_contract(env: &Env) -> ContractClient<'_> {
+    let client = create_contract(env);
+    initialize_contract_state(env, &client, &Address::generate(env));
+    client
+}
+
 fn initialize_contract_state(env: &Env, client: &ContractClient<'_>, admin: &Address) {
     let config = Config {
         fee_bps: 100,
@@ -238,7 +245,7 @@ fn test_create_agreement_success() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let client = create_contract(&env);
+    let client = setup_contract(&env);
 
     let tenant = Address::generate(&env);
     let landlord = Address::generate(&env);
@@ -259,6 +266,7 @@ fn test_create_agreement_success() {
             agent_commission_rate: 10,
         },
         payment_token: Address::generate(&env).clone(),
+        token_decimals: 7,
         metadata_uri: String::from_str(&env, "").clone(),
         attributes: Vec::new(&env).clone(),
     });
@@ -276,7 +284,7 @@ fn test_create_agreement_with_agent() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let client = create_contract(&env);
+    let client = setup_contract(&env);
 
     let tenant = Address::generate(&env);
     let landlord = Address::generate(&env);
@@ -297,6 +305,7 @@ fn test_create_agreement_with_agent() {
             agent_commission_rate: 5,
         },
         payment_token: Address::generate(&env).clone(),
+        token_decimals: 7,
         metadata_uri: String::from_str(&env, "").clone(),
         attributes: Vec::new(&env).clone(),
     });
@@ -307,7 +316,7 @@ fn test_create_agreement_without_agent() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let client = create_contract(&env);
+    let client = setup_contract(&env);
 
     let tenant = Address::generate(&env);
     let landlord = Address::generate(&env);
@@ -327,6 +336,7 @@ fn test_create_agreement_without_agent() {
             agent_commission_rate: 0,
         },
         payment_token: Address::generate(&env).clone(),
+        token_decimals: 7,
         metadata_uri: String::from_str(&env, "").clone(),
         attributes: Vec::new(&env).clone(),
     });
@@ -338,7 +348,7 @@ fn test_negative_rent_rejected() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let client = create_contract(&env);
+    let client = setup_contract(&env);
 
     let tenant = Address::generate(&env);
     let landlord = Address::generate(&env);
@@ -358,6 +368,7 @@ fn test_negative_rent_rejected() {
             agent_commission_rate: 0,
         },
         payment_token: Address::generate(&env).clone(),
+        token_decimals: 7,
         metadata_uri: String::from_str(&env, "").clone(),
         attributes: Vec::new(&env).clone(),
     });
@@ -369,7 +380,7 @@ fn test_zero_monthly_rent_rejected() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let client = create_contract(&env);
+    let client = setup_contract(&env);
 
     let tenant = Address::generate(&env);
     let landlord = Address::generate(&env);
@@ -389,6 +400,7 @@ fn test_zero_monthly_rent_rejected() {
             agent_commission_rate: 0,
         },
         payment_token: Address::generate(&env).clone(),
+        token_decimals: 7,
         metadata_uri: String::from_str(&env, "").clone(),
         attributes: Vec::new(&env).clone(),
     });
@@ -400,7 +412,7 @@ fn test_invalid_dates_rejected() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let client = create_contract(&env);
+    let client = setup_contract(&env);
 
     let tenant = Address::generate(&env);
     let landlord = Address::generate(&env);
@@ -420,6 +432,7 @@ fn test_invalid_dates_rejected() {
             agent_commission_rate: 0,
         },
         payment_token: Address::generate(&env).clone(),
+        token_decimals: 7,
         metadata_uri: String::from_str(&env, "").clone(),
         attributes: Vec::new(&env).clone(),
     });
@@ -431,7 +444,7 @@ fn test_backdated_agreement_rejected() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let client = create_contract(&env);
+    let client = setup_contract(&env);
 
     let tenant = Address::generate(&env);
     let landlord = Address::generate(&env);
@@ -457,6 +470,7 @@ fn test_backdated_agreement_rejected() {
             agent_commission_rate: 0,
         },
         payment_token: Address::generate(&env).clone(),
+        token_decimals: 7,
         metadata_uri: String::from_str(&env, "").clone(),
         attributes: Vec::new(&env).clone(),
     });
@@ -467,7 +481,7 @@ fn test_agreement_within_grace_period_accepted() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let client = create_contract(&env);
+    let client = setup_contract(&env);
 
     let tenant = Address::generate(&env);
     let landlord = Address::generate(&env);
@@ -493,6 +507,7 @@ fn test_agreement_within_grace_period_accepted() {
             agent_commission_rate: 0,
         },
         payment_token: Address::generate(&env).clone(),
+        token_decimals: 7,
         metadata_uri: String::from_str(&env, "").clone(),
         attributes: Vec::new(&env).clone(),
     });
@@ -506,7 +521,7 @@ fn test_duplicate_agreement_id() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let client = create_contract(&env);
+    let client = setup_contract(&env);
 
     let tenant = Address::generate(&env);
     let landlord = Address::generate(&env);
@@ -526,57 +541,955 @@ fn test_duplicate_agreement_id() {
             agent_commission_rate: 0,
         },
         payment_token: Address::generate(&env).clone(),
+        token_decimals: 7,
+        metadata_uri: String::from_str(&env, "").clone(),
+        attributes: Vec::new(&env).clone(),
+    });
+
+    client.create_agreement(&AgreementInput {
+        agreement_id: agreement_id.clone(),
+        landlord: landlord.clone(),
+        tenant: tenant.clone(),
+        agent: None,
+        terms: AgreementTerms {
+            monthly_rent: 1000,
+            security_deposit: 2000,
+            start_date: 100,
+            end_date: 200,
+            agent_commission_rate: 0,
+        },
+        payment_token: Address::generate(&env).clone(),
+        token_decimals: 7,
+        metadata_uri: String::from_str(&env, "").clone(),
+        attributes: Vec::new(&env).clone(),
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #7)")]
+fn test_invalid_commission_rate() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = setup_contract(&env);
+
+    let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
+
+    let agreement_id = String::from_str(&env, "BAD_COMMISSION");
+
+    client.create_agreement(&AgreementInput {
+        agreement_id: agreement_id.clone(),
+        landlord: landlord.clone(),
+        tenant: tenant.clone(),
+        agent: None,
+        terms: AgreementTerms {
+            monthly_rent: 1000,
+            security_deposit: 2000,
+            start_date: 100,
+            end_date: 200,
+            agent_commission_rate: 101,
+        },
+        payment_token: Address::generate(&env).clone(),
+        token_decimals: 7,
         metadata_uri: String::from_str(&env, "").clone(),
         attributes: Vec::new(&env).clone(),
     });
+}
+
+#[test]
+fn test_create_agreement_records_token_decimals() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = setup_contract(&env);
+    let agreement_id = String::from_str(&env, "DECIMALS_7");
+
+    client.create_agreement(&AgreementInput {
+        agreement_id: agreement_id.clone(),
+        landlord: Address::generate(&env),
+        tenant: Address::generate(&env),
+        agent: None,
+        terms: AgreementTerms {
+            monthly_rent: 10_000_000,
+            security_deposit: 0,
+            start_date: 100,
+            end_date: 200,
+            agent_commission_rate: 0,
+        },
+        payment_token: Address::generate(&env),
+        token_decimals: 7,
+        metadata_uri: String::from_str(&env, ""),
+        attributes: Vec::new(&env),
+    });
+
+    assert_eq!(client.get_token_decimals(&agreement_id), 7);
+    assert_eq!(
+        client.get_agreement(&agreement_id).unwrap().token_decimals,
+        7
+    );
+}
+
+#[test]
+fn test_create_agreement_rejects_excessive_token_decimals() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = setup_contract(&env);
+    let agreement_id = String::from_str(&env, "DECIMALS_19");
+
+    let result = client.try_create_agreement(&AgreementInput {
+        agreement_id: agreement_id.clone(),
+        landlord: Address::generate(&env),
+        tenant: Address::generate(&env),
+        agent: None,
+        terms: AgreementTerms {
+            monthly_rent: 1000,
+            security_deposit: 0,
+            start_date: 100,
+            end_date: 200,
+            agent_commission_rate: 0,
+        },
+        payment_token: Address::generate(&env),
+        token_decimals: 19,
+        metadata_uri: String::from_str(&env, ""),
+        attributes: Vec::new(&env),
+    });
+
+    assert_eq!(result, Err(Ok(RentalError::InvalidInput)));
+    assert!(!client.has_agreement(&agreement_id));
+}
+
+fn create_property_lease(
+    env: &Env,
+    client: &ContractClient,
+    agreement_id: &str,
+    property_id: &str,
+    start_date: u64,
+    end_date: u64,
+) -> (Address, String) {
+    let tenant = Address::generate(env);
+    let landlord = Address::generate(env);
+    let id = String::from_str(env, agreement_id);
+
+    client.create_agreement(&AgreementInput {
+        agreement_id: id.clone(),
+        landlord: landlord.clone(),
+        tenant: tenant.clone(),
+        agent: None,
+        terms: AgreementTerms {
+            monthly_rent: 1000,
+            security_deposit: 2000,
+            start_date,
+            end_date,
+            agent_commission_rate: 0,
+        },
+        payment_token: Address::generate(env),
+        token_decimals: 7,
+        metadata_uri: String::from_str(env, ""),
+        attributes: Vec::new(env),
+    });
+    client.link_property(&landlord, &id, &String::from_str(env, property_id));
+    client.submit_agreement(&landlord, &id);
+
+    (tenant, id)
+}
+
+#[test]
+fn test_overlapping_lease_on_same_property_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = setup_contract(&env);
+
+    let (tenant_a, lease_a) =
+        create_property_lease(&env, &client, "LEASE_A", "PROP_1", 100, 10_000);
+    let (tenant_b, lease_b) =
+        create_property_lease(&env, &client, "LEASE_B", "PROP_1", 5_000, 20_000);
+
+    client.sign_agreement(&tenant_a, &lease_a);
+
+    let result = client.try_sign_agreement(&tenant_b, &lease_b);
+    assert_eq!(result, Err(Ok(RentalError::PropertyAlreadyLeased)));
+    assert_eq!(
+        client.get_agreement(&lease_b).unwrap().status,
+        AgreementStatus::Pending
+    );
+}
+
+#[test]
+fn test_non_overlapping_lease_on_same_property_allowed() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = setup_contract(&env);
+
+    let (tenant_a, lease_a) =
+        create_property_lease(&env, &client, "LEASE_A", "PROP_1", 100, 10_000);
+    let (tenant_b, lease_b) =
+        create_property_lease(&env, &client, "LEASE_B", "PROP_1", 10_000, 20_000);
+
+    client.sign_agreement(&tenant_a, &lease_a);
+    client.sign_agreement(&tenant_b, &lease_b);
+
+    assert_eq!(
+        client.get_agreement(&lease_b).unwrap().status,
+        AgreementStatus::Active
+    );
+    assert_eq!(
+        client.get_property_agreements(&String::from_str(&env, "PROP_1")),
+        vec![&env, lease_a, lease_b]
+    );
+}
+
+fn create_draft_agreement(env: &Env, client: &ContractClient, agreement_id: &str, start: u64) {
+    client.create_agreement(&AgreementInput {
+        agreement_id: String::from_str(env, agreement_id),
+        landlord: Address::generate(env),
+        tenant: Address::generate(env),
+        agent: None,
+        terms: AgreementTerms {
+            monthly_rent: 1000,
+            security_deposit: 2000,
+            start_date: start,
+            end_date: start + 1_000_000,
+            agent_commission_rate: 0,
+        },
+        payment_token: Address::generate(env),
+        token_decimals: 7,
+        metadata_uri: String::from_str(env, ""),
+        attributes: Vec::new(env),
+    });
+}
+
+#[test]
+fn test_expire_stale_drafts_only_expires_old_drafts() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let admin = Address::generate(&env);
+    initialize_contract_state(&env, &client, &admin);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    create_draft_agreement(&env, &client, "OLD_1", 1_000);
+    create_draft_agreement(&env, &client, "OLD_2", 1_000);
+
+    env.ledger().with_mut(|li| li.timestamp = 200_000);
+    create_draft_agreement(&env, &client, "NEW_1", 200_000);
+
+    let expired = client.expire_stale_drafts(&admin, &100_000, &10);
+    assert_eq!(expired, 2);
+
+    let status = |id: &str| {
+        client
+            .get_agreement(&String::from_str(&env, id))
+            .unwrap()
+            .status
+    };
+    assert_eq!(status("OLD_1"), AgreementStatus::Cancelled);
+    assert_eq!(status("OLD_2"), AgreementStatus::Cancelled);
+    assert_eq!(status("NEW_1"), AgreementStatus::Draft);
+}
+
+#[test]
+fn test_expire_stale_drafts_respects_limit_and_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let admin = Address::generate(&env);
+    initialize_contract_state(&env, &client, &admin);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    create_draft_agreement(&env, &client, "OLD_1", 1_000);
+    create_draft_agreement(&env, &client, "OLD_2", 1_000);
+    env.ledger().with_mut(|li| li.timestamp = 200_000);
+
+    let result = client.try_expire_stale_drafts(&Address::generate(&env), &100_000, &10);
+    assert_eq!(result, Err(Ok(RentalError::Unauthorized)));
+
+    assert_eq!(client.expire_stale_drafts(&admin, &100_000, &1), 1);
+    assert_eq!(client.expire_stale_drafts(&admin, &100_000, &1), 1);
+    assert_eq!(client.expire_stale_drafts(&admin, &100_000, &1), 0);
+}
+
+#[test]
+fn test_created_at_recorded_and_survives_activation() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = setup_contract(&env);
+
+    env.ledger().with_mut(|li| li.timestamp = 5_000);
+    let (tenant, id) = create_property_lease(&env, &client, "CREATED_1", "PROP_C", 5_000, 50_000);
+    assert_eq!(client.get_created_at(&id), 5_000);
+
+    env.ledger().with_mut(|li| li.timestamp = 6_000);
+    client.sign_agreement(&tenant, &id);
+
+    let agreement = client.get_agreement(&id).unwrap();
+    assert_eq!(agreement.status, AgreementStatus::Active);
+    assert_eq!(agreement.created_at, 5_000);
+    assert_eq!(agreement.signed_at, Some(6_000));
+    assert_eq!(
+        client.try_get_created_at(&String::from_str(&env, "MISSING")),
+        Err(Ok(RentalError::AgreementNotFound))
+    );
+}
+
+#[test]
+fn test_audit_log_records_lifecycle_in_order() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = setup_contract(&env);
+
+    let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let id = String::from_str(&env, "AUDIT_1");
+
+    env.ledger().with_mut(|li| li.timestamp = 100);
+    client.create_agreement(&AgreementInput {
+        agreement_id: id.clone(),
+        landlord: landlord.clone(),
+        tenant: tenant.clone(),
+        agent: None,
+        terms: AgreementTerms {
+            monthly_rent: 1000,
+            security_deposit: 0,
+            start_date: 100,
+            end_date: 1_000_000,
+            agent_commission_rate: 0,
+        },
+        payment_token: token.clone(),
+        token_decimals: 7,
+        metadata_uri: String::from_str(&env, ""),
+        attributes: Vec::new(&env),
+    });
+
+    env.ledger().with_mut(|li| li.timestamp = 200);
+    client.submit_agreement(&landlord, &id);
+    env.ledger().with_mut(|li| li.timestamp = 300);
+    client.sign_agreement(&tenant, &id);
+
+    env.ledger().with_mut(|li| li.timestamp = 400);
+    soroban_sdk::token::StellarAssetClient::new(&env, &token).mint(&tenant, &1000);
+    client.make_payment_with_token(&id, &1000, &token);
+
+    assert_eq!(
+        client.get_audit_log(&id),
+        vec![
+            &env,
+            (100u64, symbol_short!("created")),
+            (200u64, symbol_short!("submitted")),
+            (300u64, symbol_short!("signed")),
+            (400u64, symbol_short!("paid")),
+        ]
+    );
+    assert_eq!(
+        client
+            .get_audit_log(&String::from_str(&env, "MISSING"))
+            .len(),
+        0
+    );
+}
+
+fn create_active_agreement_with_agent(
+    env: &Env,
+    client: &ContractClient,
+    agreement_id: &str,
+    agent: Option<Address>,
+) -> (Address, Address, String) {
+    let tenant = Address::generate(env);
+    let landlord = Address::generate(env);
+    let id = String::from_str(env, agreement_id);
+
+    client.create_agreement(&AgreementInput {
+        agreement_id: id.clone(),
+        landlord: landlord.clone(),
+        tenant: tenant.clone(),
+        agent,
+        terms: AgreementTerms {
+            monthly_rent: 1000,
+            security_deposit: 0,
+            start_date: 100,
+            end_date: 1_000_000,
+            agent_commission_rate: 5,
+        },
+        payment_token: Address::generate(env),
+        token_decimals: 7,
+        metadata_uri: String::from_str(env, ""),
+        attributes: Vec::new(env),
+    });
+    client.submit_agreement(&landlord, &id);
+    client.sign_agreement(&tenant, &id);
+
+    (landlord, tenant, id)
+}
+
+fn authorizers(env: &Env) -> Vec<Address> {
+    let mut signers = Vec::new(env);
+    for (address, _) in env.auths() {
+        signers.push_back(address);
+    }
+    signers
+}
+
+#[test]
+fn test_amend_rent_below_threshold_needs_landlord_and_tenant() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let admin = Address::generate(&env);
+    initialize_contract_state(&env, &client, &admin);
+    client.set_coauth_threshold(&5_000);
+
+    let agent = Address::generate(&env);
+    let (landlord, tenant, id) =
+        create_active_agreement_with_agent(&env, &client, "AMEND_1", Some(agent.clone()));
+
+    client.amend_rent(&id, &1_500);
+
+    let signers = authorizers(&env);
+    assert_eq!(signers.len(), 2);
+    assert!(signers.contains(&landlord));
+    assert!(signers.contains(&tenant));
+    assert_eq!(client.get_agreement(&id).unwrap().monthly_rent, 1_500);
+}
+
+#[test]
+fn test_amend_rent_above_threshold_needs_agent() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let admin = Address::generate(&env);
+    initialize_contract_state(&env, &client, &admin);
+    client.set_coauth_threshold(&5_000);
+
+    let agent = Address::generate(&env);
+    let (_, _, id) =
+        create_active_agreement_with_agent(&env, &client, "AMEND_2", Some(agent.clone()));
+
+    client.amend_rent(&id, &6_000);
+
+    let signers = authorizers(&env);
+    assert_eq!(signers.len(), 3);
+    assert!(signers.contains(&agent));
+    assert_eq!(client.get_agreement(&id).unwrap().monthly_rent, 6_000);
+
+    let (_, _, no_agent) = create_active_agreement_with_agent(&env, &client, "AMEND_3", None);
+    let result = client.try_amend_rent(&no_agent, &6_000);
+    assert_eq!(result, Err(Ok(RentalError::InvalidParties)));
+}
+
+#[test]
+fn test_amend_rent_within_increase_cap() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let admin = Address::generate(&env);
+    initialize_contract_state(&env, &client, &admin);
+    client.set_max_increase(&Some(1_000));
+
+    let (_, _, id) = create_active_agreement_with_agent(&env, &client, "CAP_1", None);
+
+    client.amend_rent(&id, &1_100);
+    assert_eq!(client.get_agreement(&id).unwrap().monthly_rent, 1_100);
+}
+
+#[test]
+fn test_amend_rent_over_increase_cap_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let admin = Address::generate(&env);
+    initialize_contract_state(&env, &client, &admin);
+    client.set_max_increase(&Some(1_000));
+
+    let (_, _, id) = create_active_agreement_with_agent(&env, &client, "CAP_2", None);
+
+    let result = client.try_amend_rent(&id, &1_101);
+    assert_eq!(result, Err(Ok(RentalError::IncreaseTooLarge)));
+    assert_eq!(client.get_agreement(&id).unwrap().monthly_rent, 1_000);
+}
+
+#[test]
+fn test_amend_rent_uncapped_allows_any_increase() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let admin = Address::generate(&env);
+    initialize_contract_state(&env, &client, &admin);
+
+    let (_, _, id) = create_active_agreement_with_agent(&env, &client, "CAP_3", None);
+
+    client.amend_rent(&id, &5_000);
+    assert_eq!(client.get_agreement(&id).unwrap().monthly_rent, 5_000);
+    assert_eq!(client.get_policy().max_increase_bps, None);
+}
+
+#[test]
+fn test_clone_agreement_copies_terms_for_new_tenant() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let admin = Address::generate(&env);
+    initialize_contract_state(&env, &client, &admin);
+
+    let agent = Address::generate(&env);
+    let (landlord, tenant, source_id) =
+        create_active_agreement_with_agent(&env, &client, "TEMPLATE_1", Some(agent));
+
+    let new_tenant = Address::generate(&env);
+    let new_id = String::from_str(&env, "TEMPLATE_2");
+    client.clone_agreement(&source_id, &new_id, &new_tenant);
+
+    let source = client.get_agreement(&source_id).unwrap();
+    let clone = client.get_agreement(&new_id).unwrap();
+
+    assert_eq!(clone.agreement_id, new_id);
+    assert_eq!(clone.tenant, new_tenant);
+    assert_ne!(clone.tenant, tenant);
+    assert_eq!(clone.landlord, landlord);
+    assert_eq!(clone.status, AgreementStatus::Draft);
+    assert_eq!(clone.monthly_rent, source.monthly_rent);
+    assert_eq!(clone.security_deposit, source.security_deposit);
+    assert_eq!(clone.start_date, source.start_date);
+    assert_eq!(clone.end_date, source.end_date);
+    assert_eq!(clone.agent_commission_rate, source.agent_commission_rate);
+    assert_eq!(clone.agent, source.agent);
+    assert_eq!(clone.total_rent_paid, 0);
+    assert_eq!(clone.payment_count, 0);
+
+    let duplicate = client.try_clone_agreement(&source_id, &new_id, &new_tenant);
+    assert_eq!(duplicate, Err(Ok(RentalError::AgreementAlreadyExists)));
+}
+
+#[test]
+fn test_get_agents_lists_distinct_agents() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let admin = Address::generate(&env);
+    initialize_contract_state(&env, &client, &admin);
+
+    let agent_a = Address::generate(&env);
+    let agent_b = Address::generate(&env);
+    create_active_agreement_with_agent(&env, &client, "AGENTS_1", Some(agent_a.clone()));
+    create_active_agreement_with_agent(&env, &client, "AGENTS_2", None);
+    create_active_agreement_with_agent(&env, &client, "AGENTS_3", Some(agent_b.clone()));
+    create_active_agreement_with_agent(&env, &client, "AGENTS_4", Some(agent_a.clone()));
+    create_active_agreement_with_agent(&env, &client, "AGENTS_5", None);
+
+    assert_eq!(client.get_agents(), vec![&env, agent_a, agent_b]);
+}
+
+#[test]
+fn test_get_agreements_batch_aligns_missing_ids() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let admin = Address::generate(&env);
+    initialize_contract_state(&env, &client, &admin);
+
+    create_active_agreement_with_agent(&env, &client, "BATCH_1", None);
+    create_active_agreement_with_agent(&env, &client, "BATCH_3", None);
+
+    let ids = vec![
+        &env,
+        String::from_str(&env, "BATCH_1"),
+        String::from_str(&env, "BATCH_2"),
+        String::from_str(&env, "BATCH_3"),
+    ];
+    let results = client.get_agreements_batch(&ids);
+
+    assert_eq!(results.len(), 3);
+    assert_eq!(
+        results.get(0).unwrap().unwrap().agreement_id,
+        String::from_str(&env, "BATCH_1")
+    );
+    assert_eq!(results.get(1).unwrap(), None);
+    assert_eq!(
+        results.get(2).unwrap().unwrap().agreement_id,
+        String::from_str(&env, "BATCH_3")
+    );
+
+    let mut too_many = Vec::new(&env);
+    for _ in 0..51 {
+        too_many.push_back(String::from_str(&env, "BATCH_1"));
+    }
+    assert_eq!(
+        client.try_get_agreements_batch(&too_many),
+        Err(Ok(RentalError::InvalidInput))
+    );
+}
+
+#[test]
+fn test_mutators_require_initialization() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+
+    let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let id = String::from_str(&env, "INIT_1");
+    let input = AgreementInput {
+        agreement_id: id.clone(),
+        landlord: landlord.clone(),
+        tenant: tenant.clone(),
+        agent: None,
+        terms: AgreementTerms {
+            monthly_rent: 1000,
+            security_deposit: 0,
+            start_date: 100,
+            end_date: 1_000_000,
+            agent_commission_rate: 0,
+        },
+        payment_token: token.clone(),
+        token_decimals: 7,
+        metadata_uri: String::from_str(&env, ""),
+        attributes: Vec::new(&env),
+    };
+
+    assert_eq!(
+        client.try_create_agreement(&input),
+        Err(Ok(RentalError::NotInitialized))
+    );
+    assert_eq!(
+        client.try_make_payment_with_token(&id, &1000, &token),
+        Err(Ok(RentalError::NotInitialized))
+    );
+    assert_eq!(
+        client.try_resolve_dispute(&id, &false),
+        Err(Ok(RentalError::NotInitialized))
+    );
+    assert_eq!(
+        client.try_set_tick_size(&100),
+        Err(Ok(RentalError::NotInitialized))
+    );
+    assert_eq!(
+        client.try_set_property_registry(&Address::generate(&env)),
+        Err(Ok(RentalError::NotInitialized))
+    );
+    assert_eq!(
+        client.try_pause(&String::from_str(&env, "maintenance")),
+        Err(Ok(RentalError::NotInitialized))
+    );
+    // Retired code 2 is not reused
+    assert_eq!(RentalError::NotInitialized as u32, 28);
+    // Read getters stay permissive
+    assert_eq!(client.get_agreement_count(), 0);
+
+    initialize_contract_state(&env, &client, &Address::generate(&env));
+
+    client.create_agreement(&input);
+    client.submit_agreement(&landlord, &id);
+    client.sign_agreement(&tenant, &id);
+    soroban_sdk::token::StellarAssetClient::new(&env, &token).mint(&tenant, &1000);
+    client.make_payment_with_token(&id, &1000, &token);
+
+    assert_eq!(client.get_agreement(&id).unwrap().payment_count, 1);
+}
+
+fn commission_input(env: &Env, agreement_id: &str, rate: u32) -> AgreementInput {
+    AgreementInput {
+        agreement_id: String::from_str(env, agreement_id),
+        landlord: Address::generate(env),
+        tenant: Address::generate(env),
+        agent: Some(Address::generate(env)),
+        terms: AgreementTerms {
+            monthly_rent: 1000,
+            security_deposit: 0,
+            start_date: 100,
+            end_date: 1_000_000,
+            agent_commission_rate: rate,
+        },
+        payment_token: Address::generate(env),
+        token_decimals: 7,
+        metadata_uri: String::from_str(env, ""),
+        attributes: Vec::new(env),
+    }
+}
+
+#[test]
+fn test_commission_bounds_enforced_on_create() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = setup_contract(&env);
+
+    client.set_commission_bounds(&500, &2000);
+
+    client.create_agreement(&commission_input(&env, "COMM_OK", 10));
+    assert!(client.has_agreement(&String::from_str(&env, "COMM_OK")));
+
+    assert_eq!(
+        client.try_create_agreement(&commission_input(&env, "COMM_LOW", 2)),
+        Err(Ok(RentalError::InvalidCommissionRate))
+    );
+    assert_eq!(
+        client.try_create_agreement(&commission_input(&env, "COMM_HIGH", 25)),
+        Err(Ok(RentalError::InvalidCommissionRate))
+    );
+    assert_eq!(
+        client.try_set_commission_bounds(&3000, &2000),
+        Err(Ok(RentalError::InvalidConfig))
+    );
+}
+
+#[test]
+fn test_commission_bounds_skip_agreements_without_agent() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = setup_contract(&env);
+    client.set_commission_bounds(&500, &2000);
+
+    let mut input = commission_input(&env, "NO_AGENT", 0);
+    input.agent = None;
+    client.create_agreement(&input);
+    assert!(client.has_agreement(&String::from_str(&env, "NO_AGENT")));
+}
+
+#[test]
+fn test_min_duration_enforced_on_create() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = setup_contract(&env);
+
+    client.set_min_duration(&500_000);
+
+    client.create_agreement(&commission_input(&env, "TERM_OK", 10));
+    assert!(client.has_agreement(&String::from_str(&env, "TERM_OK")));
+
+    let mut short = commission_input(&env, "TERM_SHORT", 10);
+    short.terms.end_date = short.terms.start_date + 499_999;
+    assert_eq!(
+        client.try_create_agreement(&short),
+        Err(Ok(RentalError::DurationTooShort))
+    );
+}
+
+#[test]
+fn test_no_min_duration_accepts_short_lease() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = setup_contract(&env);
+
+    let mut short = commission_input(&env, "TERM_ANY", 10);
+    short.terms.end_date = short.terms.start_date + 1;
+    client.create_agreement(&short);
+
+    assert!(client.has_agreement(&String::from_str(&env, "TERM_ANY")));
+    assert_eq!(client.get_policy().min_duration, 0);
+}
+
+#[test]
+fn test_tick_size_enforced_on_create_and_amend() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = setup_contract(&env);
+
+    client.set_tick_size(&100);
+
+    client.create_agreement(&commission_input(&env, "TICK_OK", 10));
+    assert!(client.has_agreement(&String::from_str(&env, "TICK_OK")));
+
+    let mut off_tick = commission_input(&env, "TICK_OFF", 10);
+    off_tick.terms.monthly_rent = 1_050;
+    assert_eq!(
+        client.try_create_agreement(&off_tick),
+        Err(Ok(RentalError::InvalidTick))
+    );
+
+    let (_, _, id) = create_active_agreement_with_agent(&env, &client, "TICK_AMEND", None);
+    assert_eq!(
+        client.try_amend_rent(&id, &1_150),
+        Err(Ok(RentalError::InvalidTick))
+    );
+    client.amend_rent(&id, &1_200);
+    assert_eq!(client.get_agreement(&id).unwrap().monthly_rent, 1_200);
+}
+
+#[test]
+fn test_get_agreement_property_resolves_through_registry() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = setup_contract(&env);
+
+    let registry_id = env.register(property_registry::PropertyRegistryContract, ());
+    let registry = property_registry::PropertyRegistryContractClient::new(&env, &registry_id);
+    let registry_admin = Address::generate(&env);
+    registry.initialize(&registry_admin);
+    client.set_property_registry(&registry_id);
+
+    let (_, listed) =
+        create_property_lease(&env, &client, "PROP_LEASE_1", "PROP_LISTED", 100, 10_000);
+    let (_, delisted) =
+        create_property_lease(&env, &client, "PROP_LEASE_2", "PROP_DELISTED", 100, 10_000);
+
+    let landlord = client.get_agreement(&listed).unwrap().landlord;
+    let listed_id = String::from_str(&env, "PROP_LISTED");
+    registry.register_property(
+        &landlord,
+        &listed_id,
+        &String::from_str(&env, "ipfs://hash"),
+    );
+    registry.verify_property(&registry_admin, &listed_id);
+
+    let details = client.get_agreement_property(&listed).unwrap();
+    assert_eq!(details.property_id, listed_id);
+    assert_eq!(details.landlord, landlord);
+    assert!(details.verified);
+
+    // A property missing from the registry still resolves, just without details
+    assert_eq!(client.get_agreement_property(&delisted), None);
+    assert_eq!(
+        client.get_agreement_property(&String::from_str(&env, "MISSING")),
+        None
+    );
+}
+
+#[test]
+fn test_validate_params_mirrors_create_validation() {
+    let env = Env::default();
+    let client = create_contract(&env);
+    env.ledger().with_mut(|li| li.timestamp = 200_000);
+
+    assert_eq!(
+        client.try_validate_params(&1000, &2000, &200_000, &300_000, &5),
+        Ok(Ok(()))
+    );
+    assert_eq!(
+        client.try_validate_params(&0, &2000, &200_000, &300_000, &5),
+        Err(Ok(RentalError::InvalidAmount))
+    );
+    assert_eq!(
+        client.try_validate_params(&1000, &-1, &200_000, &300_000, &5),
+        Err(Ok(RentalError::InvalidAmount))
+    );
+    assert_eq!(
+        client.try_validate_params(&1000, &2000, &300_000, &200_000, &5),
+        Err(Ok(RentalError::InvalidDate))
+    );
+    assert_eq!(
+        client.try_validate_params(&1000, &2000, &10_000, &300_000, &5),
+        Err(Ok(RentalError::InvalidDate))
+    );
+    assert_eq!(
+        client.try_validate_params(&1000, &2000, &200_000, &300_000, &101),
+        Err(Ok(RentalError::InvalidCommissionRate))
+    );
+    assert_eq!(client.get_agreement_count(), 0);
+}
 
-    client.create_agreement(&AgreementInput {
-        agreement_id: agreement_id.clone(),
+#[test]
+fn test_validate_params_applies_commission_bounds() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = setup_contract(&env);
+    client.set_commission_bounds(&500, &2000);
+
+    assert_eq!(
+        client.try_validate_params(&1000, &0, &100, &300_000, &3),
+        Err(Ok(RentalError::InvalidCommissionRate))
+    );
+    assert_eq!(
+        client.try_validate_params(&1000, &0, &100, &300_000, &10),
+        Ok(Ok(()))
+    );
+}
+
+fn allowlist_input(
+    env: &Env,
+    agreement_id: &str,
+    landlord: &Address,
+    tenant: &Address,
+) -> AgreementInput {
+    AgreementInput {
+        agreement_id: String::from_str(env, agreement_id),
         landlord: landlord.clone(),
         tenant: tenant.clone(),
         agent: None,
         terms: AgreementTerms {
             monthly_rent: 1000,
-            security_deposit: 2000,
+            security_deposit: 0,
             start_date: 100,
-            end_date: 200,
+            end_date: 1_000_000,
             agent_commission_rate: 0,
         },
-        payment_token: Address::generate(&env).clone(),
-        metadata_uri: String::from_str(&env, "").clone(),
-        attributes: Vec::new(&env).clone(),
-    });
+        payment_token: Address::generate(env),
+        token_decimals: 7,
+        metadata_uri: String::from_str(env, ""),
+        attributes: Vec::new(env),
+    }
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #7)")]
-fn test_invalid_commission_rate() {
+fn test_tenant_allowlist_restricts_creation() {
     let env = Env::default();
     env.mock_all_auths();
+    let client = setup_contract(&env);
 
-    let client = create_contract(&env);
+    let landlord = Address::generate(&env);
+    let vetted = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    client.add_allowed_tenant(&landlord, &vetted);
+
+    client.create_agreement(&allowlist_input(&env, "ALLOW_1", &landlord, &vetted));
+    assert!(client.has_agreement(&String::from_str(&env, "ALLOW_1")));
+
+    assert_eq!(
+        client.try_create_agreement(&allowlist_input(&env, "ALLOW_2", &landlord, &stranger)),
+        Err(Ok(RentalError::TenantNotAllowed))
+    );
+}
+
+#[test]
+fn test_empty_tenant_allowlist_is_open() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = setup_contract(&env);
 
-    let tenant = Address::generate(&env);
     let landlord = Address::generate(&env);
+    let vetted = Address::generate(&env);
+    let anyone = Address::generate(&env);
 
-    let agreement_id = String::from_str(&env, "BAD_COMMISSION");
+    client.add_allowed_tenant(&landlord, &vetted);
+    client.remove_allowed_tenant(&landlord, &vetted);
+    assert_eq!(client.get_tenant_allowlist(&landlord).len(), 0);
 
-    client.create_agreement(&AgreementInput {
-        agreement_id: agreement_id.clone(),
-        landlord: landlord.clone(),
-        tenant: tenant.clone(),
-        agent: None,
-        terms: AgreementTerms {
-            monthly_rent: 1000,
-            security_deposit: 2000,
-            start_date: 100,
-            end_date: 200,
-            agent_commission_rate: 101,
-        },
-        payment_token: Address::generate(&env).clone(),
-        metadata_uri: String::from_str(&env, "").clone(),
-        attributes: Vec::new(&env).clone(),
-    });
+    client.create_agreement(&allowlist_input(&env, "OPEN_1", &landlord, &anyone));
+    assert!(client.has_agreement(&String::from_str(&env, "OPEN_1")));
+}
+
+#[test]
+fn test_get_progress_over_term() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = setup_contract(&env);
+
+    let period = 30 * 86_400;
+    let start = 1_000;
+    let (tenant, id) = create_property_lease(
+        &env,
+        &client,
+        "PROGRESS_1",
+        "PROP_P",
+        start,
+        start + 12 * period,
+    );
+    client.sign_agreement(&tenant, &id);
+
+    env.ledger().with_mut(|li| li.timestamp = start);
+    assert_eq!(client.get_progress(&id), (0, 12, 0));
+
+    env.ledger()
+        .with_mut(|li| li.timestamp = start + 6 * period + 10);
+    assert_eq!(client.get_progress(&id), (6, 12, 50));
+
+    env.ledger()
+        .with_mut(|li| li.timestamp = start + 13 * period);
+    assert_eq!(client.get_progress(&id), (12, 12, 100));
 }
 
 fn create_pending_agreement(
@@ -599,6 +1512,7 @@ fn create_pending_agreement(
             agent_commission_rate: 0,
         },
         payment_token: Address::generate(env).clone(),
+        token_decimals: 7,
         metadata_uri: String::from_str(env, "").clone(),
         attributes: Vec::new(env).clone(),
     });
@@ -621,7 +1535,7 @@ fn test_sign_agreement_success() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let client = create_contract(&env);
+    let client = setup_contract(&env);
     let tenant = Address::generate(&env);
     let landlord = Address::generate(&env);
 
@@ -644,7 +1558,7 @@ fn test_sign_agreement_not_found() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let client = create_contract(&env);
+    let client = setup_contract(&env);
     let tenant = Address::generate(&env);
 
     client.sign_agreement(&tenant, &String::from_str(&env, "NONEXISTENT"));
@@ -656,7 +1570,7 @@ fn test_sign_agreement_not_tenant() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let client = create_contract(&env);
+    let client = setup_contract(&env);
     let tenant = Address::generate(&env);
     let landlord = Address::generate(&env);
     let impostor = Address::generate(&env);
@@ -673,7 +1587,7 @@ fn test_sign_agreement_invalid_state() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let client = create_contract(&env);
+    let client = setup_contract(&env);
     let tenant = Address::generate(&env);
     let landlord = Address::generate(&env);
 
@@ -692,6 +1606,7 @@ fn test_sign_agreement_invalid_state() {
             agent_commission_rate: 0,
         },
         payment_token: Address::generate(&env).clone(),
+        token_decimals: 7,
         metadata_uri: String::from_str(&env, "").clone(),
         attributes: Vec::new(&env).clone(),
     });
@@ -705,7 +1620,7 @@ fn test_sign_agreement_expired() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let client = create_contract(&env);
+    let client = setup_contract(&env);
     let tenant = Address::generate(&env);
     let landlord = Address::generate(&env);
 
@@ -724,6 +1639,7 @@ fn test_sign_agreement_expired() {
             agent_commission_rate: 0,
         },
         payment_token: Address::generate(&env).clone(),
+        token_decimals: 7,
         metadata_uri: String::from_str(&env, "").clone(),
         attributes: Vec::new(&env).clone(),
     });
@@ -751,7 +1667,7 @@ fn test_sign_agreement_already_signed() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let client = create_contract(&env);
+    let client = setup_contract(&env);
     let tenant = Address::generate(&env);
     let landlord = Address::generate(&env);
 
@@ -768,7 +1684,7 @@ fn test_sign_agreement_event_emission() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let client = create_contract(&env);
+    let client = setup_contract(&env);
     let tenant = Address::generate(&env);
     let landlord = Address::generate(&env);
 
@@ -788,7 +1704,7 @@ fn test_submit_agreement_success() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let client = create_contract(&env);
+    let client = setup_contract(&env);
     let tenant = Address::generate(&env);
     let landlord = Address::generate(&env);
 
@@ -807,6 +1723,7 @@ fn test_submit_agreement_success() {
             agent_commission_rate: 0,
         },
         payment_token: Address::generate(&env).clone(),
+        token_decimals: 7,
         metadata_uri: String::from_str(&env, "").clone(),
         attributes: Vec::new(&env).clone(),
     });
@@ -826,7 +1743,7 @@ fn test_submit_agreement_not_found() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let client = create_contract(&env);
+    let client = setup_contract(&env);
     let landlord = Address::generate(&env);
 
     client.submit_agreement(&landlord, &String::from_str(&env, "NONEXISTENT"));
@@ -838,7 +1755,7 @@ fn test_submit_agreement_unauthorized() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let client = create_contract(&env);
+    let client = setup_contract(&env);
     let tenant = Address::generate(&env);
     let landlord = Address::generate(&env);
     let non_landlord = Address::generate(&env);
@@ -858,6 +1775,7 @@ fn test_submit_agreement_unauthorized() {
             agent_commission_rate: 0,
         },
         payment_token: Address::generate(&env).clone(),
+        token_decimals: 7,
         metadata_uri: String::from_str(&env, "").clone(),
         attributes: Vec::new(&env).clone(),
     });
@@ -871,7 +1789,7 @@ fn test_submit_agreement_invalid_state() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let client = create_contract(&env);
+    let client = setup_contract(&env);
     let tenant = Address::generate(&env);
     let landlord = Address::generate(&env);
 
@@ -886,7 +1804,7 @@ fn test_cancel_agreement_success_draft() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let client = create_contract(&env);
+    let client = setup_contract(&env);
     let tenant = Address::generate(&env);
     let landlord = Address::generate(&env);
 
@@ -905,6 +1823,7 @@ fn test_cancel_agreement_success_draft() {
             agent_commission_rate: 0,
         },
         payment_token: Address::generate(&env).clone(),
+        token_decimals: 7,
         metadata_uri: String::from_str(&env, "").clone(),
         attributes: Vec::new(&env).clone(),
     });
@@ -920,7 +1839,7 @@ fn test_cancel_agreement_success_pending() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let client = create_contract(&env);
+    let client = setup_contract(&env);
     let tenant = Address::generate(&env);
     let landlord = Address::generate(&env);
 
@@ -941,7 +1860,7 @@ fn test_cancel_agreement_not_found() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let client = create_contract(&env);
+    let client = setup_contract(&env);
     let landlord = Address::generate(&env);
 
     client.cancel_agreement(&landlord, &String::from_str(&env, "NONEXISTENT"));
@@ -953,7 +1872,7 @@ fn test_cancel_agreement_unauthorized() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let client = create_contract(&env);
+    let client = setup_contract(&env);
     let tenant = Address::generate(&env);
     let landlord = Address::generate(&env);
     let non_landlord = Address::generate(&env);
@@ -973,6 +1892,7 @@ fn test_cancel_agreement_unauthorized() {
             agent_commission_rate: 0,
         },
         payment_token: Address::generate(&env).clone(),
+        token_decimals: 7,
         metadata_uri: String::from_str(&env, "").clone(),
         attributes: Vec::new(&env).clone(),
     });
@@ -986,7 +1906,7 @@ fn test_cancel_agreement_invalid_state() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let client = create_contract(&env);
+    let client = setup_contract(&env);
     let tenant = Address::generate(&env);
     let landlord = Address::generate(&env);
 
@@ -1005,7 +1925,7 @@ fn test_get_agreement() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let client = create_contract(&env);
+    let client = setup_contract(&env);
     let tenant = Address::generate(&env);
     let landlord = Address::generate(&env);
 
@@ -1024,6 +1944,7 @@ fn test_get_agreement() {
             agent_commission_rate: 0,
         },
         payment_token: Address::generate(&env).clone(),
+        token_decimals: 7,
         metadata_uri: String::from_str(&env, "").clone(),
         attributes: Vec::new(&env).clone(),
     });
@@ -1039,7 +1960,7 @@ fn test_has_agreement() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let client = create_contract(&env);
+    let client = setup_contract(&env);
     let tenant = Address::generate(&env);
     let landlord = Address::generate(&env);
 
@@ -1060,6 +1981,7 @@ fn test_has_agreement() {
             agent_commission_rate: 0,
         },
         payment_token: Address::generate(&env).clone(),
+        token_decimals: 7,
         metadata_uri: String::from_str(&env, "").clone(),
         attributes: Vec::new(&env).clone(),
     });
@@ -1072,7 +1994,7 @@ fn test_get_agreement_count() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let client = create_contract(&env);
+    let client = setup_contract(&env);
     let tenant = Address::generate(&env);
     let landlord = Address::generate(&env);
 
@@ -1091,6 +2013,7 @@ fn test_get_agreement_count() {
             agent_commission_rate: 0,
         },
         payment_token: Address::generate(&env).clone(),
+        token_decimals: 7,
         metadata_uri: String::from_str(&env, "").clone(),
         attributes: Vec::new(&env).clone(),
     });
@@ -1110,6 +2033,7 @@ fn test_get_agreement_count() {
             agent_commission_rate: 0,
         },
         payment_token: Address::generate(&env).clone(),
+        token_decimals: 7,
         metadata_uri: String::from_str(&env, "").clone(),
         attributes: Vec::new(&env).clone(),
     });
@@ -1131,7 +2055,7 @@ proptest! {
         let env = Env::default();
         env.mock_all_auths();
 
-        let client = create_contract(&env);
+        let client = setup_contract(&env);
         let tenant = Address::generate(&env);
         let landlord = Address::generate(&env);
         let payment_token = Address::generate(&env);
@@ -1151,6 +2075,7 @@ proptest! {
             agent_commission_rate,
         },
         payment_token: payment_token.clone(),
+        token_decimals: 7,
         metadata_uri: String::from_str(&env, "").clone(),
         attributes: Vec::new(&env).clone(),
     });
@@ -1224,6 +2149,7 @@ fn test_contract_paused_operations() {
             agent_commission_rate: 10,
         },
         payment_token: payment_token.clone(),
+        token_decimals: 7,
         metadata_uri: String::from_str(&env, "").clone(),
         attributes: Vec::new(&env).clone(),
     });
@@ -1247,6 +2173,7 @@ fn test_contract_paused_operations() {
             agent_commission_rate: 10,
         },
         payment_token: payment_token.clone(),
+        token_decimals: 7,
         metadata_uri: String::from_str(&env, "").clone(),
         attributes: Vec::new(&env).clone(),
     });
@@ -1288,13 +2215,63 @@ fn test_pause_unpause_events_emitted() {
 
     let pause_res = client.try_pause(&String::from_str(&env, "security patching"));
     assert!(pause_res.is_ok());
+    // The pause event plus the config-change broadcast
     let events_after_pause = env.events().all();
-    assert_eq!(events_after_pause.len(), 1);
+    assert_eq!(events_after_pause.len(), 2);
 
     let unpause_res = client.try_unpause();
     assert!(unpause_res.is_ok());
     let events_after_unpause = env.events().all();
-    assert_eq!(events_after_unpause.len(), 1);
+    assert_eq!(events_after_unpause.len(), 2);
+}
+
+/// Keys of the `cfg_changed` events emitted by the last invocation.
+fn config_changed_keys(env: &Env) -> soroban_sdk::Vec<Symbol> {
+    let mut keys = soroban_sdk::Vec::new(env);
+    for (_, topics, _) in env.events().all().iter() {
+        let name: Symbol = topics.get(0).unwrap().into_val(env);
+        if name == Symbol::new(env, "cfg_changed") {
+            keys.push_back(topics.get(1).unwrap().into_val(env));
+        }
+    }
+    keys
+}
+
+#[test]
+fn test_admin_setters_broadcast_config_changes() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+
+    let admin = Address::generate(&env);
+    let fee_collector = Address::generate(&env);
+    client.initialize(
+        &admin,
+        &Config {
+            fee_bps: 100,
+            fee_collector: fee_collector.clone(),
+            paused: false,
+        },
+    );
+
+    client.pause(&String::from_str(&env, "maintenance"));
+    assert_eq!(
+        config_changed_keys(&env),
+        vec![&env, symbol_short!("paused")]
+    );
+
+    client.unpause();
+    assert_eq!(
+        config_changed_keys(&env),
+        vec![&env, symbol_short!("paused")]
+    );
+
+    client.update_config(&Config {
+        fee_bps: 250,
+        fee_collector,
+        paused: false,
+    });
+    assert_eq!(config_changed_keys(&env), vec![&env, symbol_short!("fees")]);
 }
 
 #[test]
@@ -1395,3 +2372,489 @@ fn test_unpause_unauthorized() {
         }])
         .unpause();
 }
+
+#[test]
+fn test_can_transition_allows_lifecycle_edges() {
+    use crate::agreement::can_transition;
+    use AgreementStatus::*;
+
+    assert!(can_transition(Draft, Pending));
+    assert!(can_transition(Draft, Active));
+    assert!(can_transition(Draft, Cancelled));
+    assert!(can_transition(Pending, Active));
+    assert!(can_transition(Pending, Cancelled));
+    assert!(can_transition(Active, Completed));
+    assert!(can_transition(Active, Terminated));
+    assert!(can_transition(Active, Disputed));
+    assert!(can_transition(Disputed, Active));
+    assert!(can_transition(Disputed, Terminated));
+}
+
+#[test]
+fn test_can_transition_rejects_other_edges() {
+    use crate::agreement::can_transition;
+    use AgreementStatus::*;
+
+    assert!(!can_transition(Draft, Completed));
+    assert!(!can_transition(Pending, Draft));
+    assert!(!can_transition(Active, Pending));
+    assert!(!can_transition(Active, Cancelled));
+    assert!(!can_transition(Active, Active));
+    assert!(!can_transition(Disputed, Completed));
+    assert!(!can_transition(Completed, Active));
+    assert!(!can_transition(Cancelled, Pending));
+    assert!(!can_transition(Terminated, Active));
+}
+
+#[soroban_sdk::contract]
+pub struct MockCreateHook;
+
+#[soroban_sdk::contractimpl]
+impl MockCreateHook {
+    pub fn on_agreement_created(env: Env, agreement_id: String) {
+        let mut received: Vec<String> = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("received"))
+            .unwrap_or(Vec::new(&env));
+        received.push_back(agreement_id);
+        env.storage()
+            .instance()
+            .set(&symbol_short!("received"), &received);
+    }
+
+    pub fn received(env: Env) -> Vec<String> {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("received"))
+            .unwrap_or(Vec::new(&env))
+    }
+}
+
+#[test]
+fn test_create_hook_receives_agreement_id() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = setup_contract(&env);
+
+    let hook_id = env.register(MockCreateHook, ());
+    let hook = MockCreateHookClient::new(&env, &hook_id);
+    client.set_create_hook(&Some(hook_id.clone()));
+    assert_eq!(client.get_create_hook(), Some(hook_id));
+
+    create_draft_agreement(&env, &client, "HOOK_001", 1000);
+    create_draft_agreement(&env, &client, "HOOK_002", 1000);
+
+    assert_eq!(
+        hook.received(),
+        vec![
+            &env,
+            String::from_str(&env, "HOOK_001"),
+            String::from_str(&env, "HOOK_002")
+        ]
+    );
+}
+
+#[test]
+fn test_create_without_hook_skips_callback() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = setup_contract(&env);
+
+    assert_eq!(client.get_create_hook(), None);
+    create_draft_agreement(&env, &client, "HOOK_003", 1000);
+    assert!(client
+        .get_agreement(&String::from_str(&env, "HOOK_003"))
+        .is_some());
+}
+
+#[test]
+fn test_get_agreement_flat_matches_stored_agreement() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = setup_contract(&env);
+
+    create_draft_agreement(&env, &client, "FLAT_001", 1000);
+    let id = String::from_str(&env, "FLAT_001");
+    let agreement = client.get_agreement(&id).unwrap();
+
+    let flat = client.get_agreement_flat(&id);
+    assert_eq!(flat.0, agreement.agreement_id);
+    assert_eq!(flat.1, agreement.landlord);
+    assert_eq!(flat.2, agreement.tenant);
+    assert_eq!(flat.3, agreement.monthly_rent);
+    assert_eq!(flat.4, agreement.security_deposit);
+    assert_eq!(flat.5, agreement.start_date);
+    assert_eq!(flat.6, agreement.end_date);
+    assert_eq!(flat.7, agreement.agent_commission_rate);
+    assert_eq!(flat.8, agreement.payment_count);
+}
+
+#[test]
+fn test_get_agreement_flat_missing_agreement() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = setup_contract(&env);
+
+    let result = client.try_get_agreement_flat(&String::from_str(&env, "NOPE"));
+    assert_eq!(result, Err(Ok(RentalError::AgreementNotFound)));
+}
+
+#[cfg(feature = "testutils")]
+#[test]
+fn test_seed_active_agreement_then_pay_rent() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = setup_contract(&env);
+
+    let landlord = Address::generate(&env);
+    let tenant = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    soroban_sdk::token::StellarAssetClient::new(&env, &token).mint(&tenant, &1000);
+
+    let id = String::from_str(&env, "SEED_001");
+    client.seed_active_agreement(&id, &landlord, &tenant, &1000, &token);
+    assert_eq!(
+        client.get_agreement(&id).unwrap().status,
+        AgreementStatus::Active
+    );
+
+    client.make_payment_with_token(&id, &1000, &token);
+    assert_eq!(client.get_agreement(&id).unwrap().total_rent_paid, 1000);
+}
+
+#[cfg(not(feature = "testutils"))]
+#[test]
+fn test_seed_active_agreement_gated_off_without_feature() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = setup_contract(&env);
+
+    let result = env.try_invoke_contract::<(), soroban_sdk::Error>(
+        &client.address,
+        &soroban_sdk::Symbol::new(&env, "seed_active_agreement"),
+        vec![
+            &env,
+            String::from_str(&env, "SEED_002").into_val(&env),
+            Address::generate(&env).into_val(&env),
+            Address::generate(&env).into_val(&env),
+            1000i128.into_val(&env),
+            Address::generate(&env).into_val(&env),
+        ],
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_get_stats_tracks_agreements_and_payments() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = setup_contract(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+
+    let activate = |id: &str, rent: i128| {
+        let id = String::from_str(&env, id);
+        let landlord = Address::generate(&env);
+        let tenant = Address::generate(&env);
+        client.create_agreement(&AgreementInput {
+            agreement_id: id.clone(),
+            landlord: landlord.clone(),
+            tenant: tenant.clone(),
+            agent: None,
+            terms: AgreementTerms {
+                monthly_rent: rent,
+                security_deposit: 0,
+                start_date: 0,
+                end_date: 1_000_000,
+                agent_commission_rate: 0,
+            },
+            payment_token: token.clone(),
+            token_decimals: 7,
+            metadata_uri: String::from_str(&env, ""),
+            attributes: Vec::new(&env),
+        });
+        client.submit_agreement(&landlord, &id);
+        client.sign_agreement(&tenant, &id);
+        soroban_sdk::token::StellarAssetClient::new(&env, &token).mint(&tenant, &10_000);
+        id
+    };
+
+    let first = activate("STATS_001", 1000);
+    let second = activate("STATS_002", 500);
+    create_draft_agreement(&env, &client, "STATS_003", 1000);
+
+    client.make_payment_with_token(&first, &1000, &token);
+    client.make_payment_with_token(&first, &1000, &token);
+    client.make_payment_with_token(&second, &500, &token);
+
+    let stats = client.get_stats();
+    assert_eq!(stats.total_agreements, 3);
+    assert_eq!(stats.active_agreements, 2);
+    assert_eq!(stats.total_payments, 3);
+    assert_eq!(client.get_rent_volume(&token), 2500);
+    assert_eq!(client.get_rent_volume(&Address::generate(&env)), 0);
+
+    // Leaving Active drops the agreement from the active count
+    client.raise_dispute(&second, &client.get_agreement(&second).unwrap().tenant);
+    assert_eq!(client.get_stats().active_agreements, 1);
+}
+
+#[test]
+fn test_private_agreement_hidden_from_listing() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let admin = Address::generate(&env);
+    initialize_contract_state(&env, &client, &admin);
+
+    let (_, _, public_id) = create_active_agreement_with_agent(&env, &client, "LIST_PUB", None);
+    let (landlord, tenant, private_id) =
+        create_active_agreement_with_agent(&env, &client, "LIST_PRIV", None);
+    client.set_public(&private_id, &false);
+
+    let listed = client.list_agreements(&None, &None, &0, &10);
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed.get(0).unwrap().agreement_id, public_id);
+
+    let active = client.list_agreements(
+        &Some(Address::generate(&env)),
+        &Some(AgreementStatus::Active),
+        &0,
+        &10,
+    );
+    assert_eq!(active.len(), 1);
+
+    assert_eq!(
+        client.list_agreements(&Some(tenant), &None, &0, &10).len(),
+        2
+    );
+    assert_eq!(
+        client
+            .list_agreements(&Some(landlord), &Some(AgreementStatus::Active), &0, &10)
+            .len(),
+        2
+    );
+
+    let direct = client.get_agreement(&private_id).unwrap();
+    assert!(!direct.public);
+}
+
+#[test]
+fn test_commission_corrected_on_draft() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = setup_contract(&env);
+    let id = String::from_str(&env, "COMM_FIX");
+
+    client.create_agreement(&commission_input(&env, "COMM_FIX", 50));
+    client.set_commission(&id, &5);
+    assert_eq!(client.get_agreement(&id).unwrap().agent_commission_rate, 5);
+
+    assert_eq!(
+        client.try_set_commission(&id, &101),
+        Err(Ok(RentalError::InvalidCommissionRate))
+    );
+}
+
+#[test]
+fn test_commission_locked_once_active() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let admin = Address::generate(&env);
+    initialize_contract_state(&env, &client, &admin);
+
+    let (_, _, id) = create_active_agreement_with_agent(&env, &client, "COMM_LIVE", None);
+
+    assert_eq!(
+        client.try_set_commission(&id, &5),
+        Err(Ok(RentalError::InvalidState))
+    );
+}
+
+fn dated_input(env: &Env, agreement_id: &str, start_date: u64) -> AgreementInput {
+    let mut input = commission_input(env, agreement_id, 10);
+    input.terms.start_date = start_date;
+    input
+}
+
+#[test]
+fn test_future_start_accepted_when_required() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = setup_contract(&env);
+    env.ledger().with_mut(|li| li.timestamp = 10_000);
+    client.set_require_future_start(&true);
+
+    client.create_agreement(&dated_input(&env, "START_NEXT", 10_000));
+    assert!(client.has_agreement(&String::from_str(&env, "START_NEXT")));
+}
+
+#[test]
+fn test_past_start_rejected_when_required() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = setup_contract(&env);
+    env.ledger().with_mut(|li| li.timestamp = 10_000);
+    client.set_require_future_start(&true);
+
+    assert_eq!(
+        client.try_create_agreement(&dated_input(&env, "START_PAST", 9_999)),
+        Err(Ok(RentalError::StartInPast))
+    );
+}
+
+#[test]
+fn test_past_start_accepted_by_default() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = setup_contract(&env);
+    env.ledger().with_mut(|li| li.timestamp = 10_000);
+
+    client.create_agreement(&dated_input(&env, "START_PAST", 9_999));
+    assert!(client.has_agreement(&String::from_str(&env, "START_PAST")));
+}
+
+/// Try `create_agreement` with only the tenant's authorization mocked.
+fn create_with_tenant_auth_only(
+    env: &Env,
+    client: &ContractClient<'_>,
+    input: &AgreementInput,
+) -> bool {
+    client
+        .mock_auths(&[MockAuth {
+            address: &input.tenant,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "create_agreement",
+                args: (input.clone(),).into_val(env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_create_agreement(input)
+        .is_ok()
+}
+
+#[test]
+fn test_landlord_auth_required_on_create_when_enabled() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = setup_contract(&env);
+    client.set_require_landlord_auth(&true);
+
+    let input = commission_input(&env, "LL_AUTH_1", 10);
+    client.create_agreement(&input);
+
+    let auths = env.auths();
+    assert!(auths.iter().any(|(addr, _)| *addr == input.tenant));
+    assert!(auths.iter().any(|(addr, _)| *addr == input.landlord));
+    assert!(client.has_agreement(&input.agreement_id));
+}
+
+#[test]
+fn test_tenant_only_create_rejected_when_landlord_auth_required() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = setup_contract(&env);
+    client.set_require_landlord_auth(&true);
+
+    let input = commission_input(&env, "LL_AUTH_2", 10);
+    assert!(!create_with_tenant_auth_only(&env, &client, &input));
+    assert!(!client.has_agreement(&input.agreement_id));
+}
+
+#[test]
+fn test_tenant_only_create_allowed_by_default() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = setup_contract(&env);
+
+    let input = commission_input(&env, "LL_AUTH_3", 10);
+    assert!(create_with_tenant_auth_only(&env, &client, &input));
+    assert!(client.has_agreement(&input.agreement_id));
+}
+
+fn categorized_input(env: &Env, agreement_id: &str, category: &str) -> AgreementInput {
+    let mut input = commission_input(env, agreement_id, 10);
+    input.attributes.push_back(Attribute {
+        trait_type: String::from_str(env, "category"),
+        value: String::from_str(env, category),
+    });
+    input
+}
+
+#[test]
+fn test_agreements_filtered_by_category() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = setup_contract(&env);
+
+    client.create_agreement(&categorized_input(&env, "CAT_HOME_1", "residential"));
+    client.create_agreement(&categorized_input(&env, "CAT_SHOP", "commercial"));
+    client.create_agreement(&commission_input(&env, "CAT_HOME_2", 10));
+
+    let commercial = String::from_str(&env, "commercial");
+    assert_eq!(
+        client.get_agreements_by_category(&commercial, &0, &10),
+        vec![&env, String::from_str(&env, "CAT_SHOP")]
+    );
+
+    // Agreements without a category attribute are residential
+    let residential = String::from_str(&env, "residential");
+    assert_eq!(
+        client.get_agreements_by_category(&residential, &0, &10),
+        vec![
+            &env,
+            String::from_str(&env, "CAT_HOME_1"),
+            String::from_str(&env, "CAT_HOME_2")
+        ]
+    );
+    assert_eq!(
+        client
+            .get_agreement(&String::from_str(&env, "CAT_SHOP"))
+            .unwrap()
+            .category,
+        commercial
+    );
+}
+
+#[test]
+fn test_unknown_category_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = setup_contract(&env);
+
+    assert_eq!(
+        client.try_create_agreement(&categorized_input(&env, "CAT_BAD", "industrial")),
+        Err(Ok(RentalError::InvalidInput))
+    );
+}
+
+#[test]
+fn test_first_period_charge_depends_on_proration_basis() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = setup_contract(&env);
+
+    client.create_agreement(&commission_input(&env, "PRORATE_30", 10));
+    client.create_agreement(&commission_input(&env, "PRORATE_ACT", 10));
+    let thirty_day = String::from_str(&env, "PRORATE_30");
+    let actual = String::from_str(&env, "PRORATE_ACT");
+    client.set_proration_basis(&actual, &ProrationBasis::Actual);
+
+    assert_eq!(
+        client.get_agreement(&thirty_day).unwrap().proration_basis,
+        ProrationBasis::ThirtyDay
+    );
+
+    // 15 of 30 days is exactly half; 15 of 365/12 days is a little less
+    assert_eq!(client.get_first_period_charge(&thirty_day, &15), 500);
+    assert_eq!(client.get_first_period_charge(&actual, &15), 493);
+
+    assert_eq!(client.get_first_period_charge(&thirty_day, &30), 1000);
+    assert_eq!(client.get_first_period_charge(&actual, &30), 1000);
+}
@@ -0,0 +1,579 @@
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, String,
+};
+
+const PERIOD: u64 = 30 * 86_400;
+const START: u64 = 1_000;
+
+fn create_contract(env: &Env) -> ContractClient<'_> {
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(env, &contract_id);
+    client.initialize(
+        &Address::generate(env),
+        &Config {
+            fee_bps: 0,
+            fee_collector: Address::generate(env),
+            paused: false,
+        },
+    );
+    client
+}
+
+struct Lease {
+    id: String,
+    tenant: Address,
+    landlord: Address,
+    token: Address,
+}
+
+/// Create an Active 12-period lease at 1000 rent with a 500 early-termination fee.
+fn create_active_lease(env: &Env, client: &ContractClient<'_>) -> Lease {
+    create_lease_with_allocation(env, client, AllocationPolicy::ArrearsFirst)
+}
+
+fn create_lease_with_allocation(
+    env: &Env,
+    client: &ContractClient<'_>,
+    allocation: AllocationPolicy,
+) -> Lease {
+    let tenant = Address::generate(env);
+    let landlord = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    let id = String::from_str(env, "BILLING_1");
+
+    env.ledger().with_mut(|li| li.timestamp = START);
+    client.create_agreement(&AgreementInput {
+        agreement_id: id.clone(),
+        landlord: landlord.clone(),
+        tenant: tenant.clone(),
+        agent: None,
+        terms: AgreementTerms {
+            monthly_rent: 1000,
+            security_deposit: 0,
+            start_date: START,
+            end_date: START + 12 * PERIOD,
+            agent_commission_rate: 0,
+        },
+        payment_token: token.clone(),
+        token_decimals: 7,
+        metadata_uri: String::from_str(env, ""),
+        attributes: Vec::new(env),
+    });
+    client.set_early_termination_fee(&id, &500);
+    client.set_allocation_policy(&id, &allocation);
+    client.submit_agreement(&landlord, &id);
+    client.sign_agreement(&tenant, &id);
+
+    token::StellarAssetClient::new(env, &token).mint(&tenant, &100_000);
+
+    Lease {
+        id,
+        tenant,
+        landlord,
+        token,
+    }
+}
+
+#[test]
+fn test_payoff_quote_for_current_lease() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let lease = create_active_lease(&env, &client);
+
+    // Three periods due and paid; nine remain
+    env.ledger()
+        .with_mut(|li| li.timestamp = START + 2 * PERIOD);
+    for _ in 0..3 {
+        client.make_payment_with_token(&lease.id, &1000, &lease.token);
+    }
+
+    assert_eq!(client.get_payoff_quote(&lease.id), 9 * 1000 + 500);
+}
+
+#[test]
+fn test_make_payment_rejects_zero_and_negative_amounts() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let lease = create_active_lease(&env, &client);
+
+    for amount in [0, -1000] {
+        assert_eq!(
+            client.try_make_payment_with_token(&lease.id, &amount, &lease.token),
+            Err(Ok(RentalError::InvalidAmount))
+        );
+    }
+    let agreement = client.get_agreement(&lease.id).unwrap();
+    assert_eq!(agreement.total_rent_paid, 0);
+    assert_eq!(agreement.payment_count, 0);
+}
+
+#[test]
+fn test_payoff_quote_reduced_by_credit() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let lease = create_active_lease(&env, &client);
+
+    // One period due, but two periods' rent paid up front
+    client.make_payment_with_token(&lease.id, &2000, &lease.token);
+
+    assert_eq!(client.get_payoff_quote(&lease.id), 11 * 1000 + 500 - 1000);
+}
+
+#[test]
+fn test_enforce_arrears_terminates_at_threshold() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let lease = create_active_lease(&env, &client);
+    client.set_max_arrears_periods(&3);
+
+    // First period paid; the next three fall due unpaid
+    client.make_payment_with_token(&lease.id, &1000, &lease.token);
+    env.ledger()
+        .with_mut(|li| li.timestamp = START + 3 * PERIOD);
+    assert_eq!(client.get_arrears(&lease.id), 3);
+
+    client.enforce_arrears(&lease.id);
+
+    let agreement = client.get_agreement(&lease.id).unwrap();
+    assert_eq!(agreement.status, AgreementStatus::Terminated);
+}
+
+#[test]
+fn test_enforce_arrears_counts_and_refunds_prepaid_rent() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let lease = create_active_lease(&env, &client);
+    let balances = token::Client::new(&env, &lease.token);
+    client.set_max_arrears_periods(&2);
+
+    // Period 1 left unpaid; periods 2 to 5 prepaid
+    client.prepay_rent(&lease.id, &lease.token, &4);
+    env.ledger().with_mut(|li| li.timestamp = START + PERIOD);
+    assert_eq!(client.get_arrears(&lease.id), 1);
+    assert_eq!(
+        client.try_enforce_arrears(&lease.id),
+        Err(Ok(RentalError::ArrearsThresholdNotReached))
+    );
+
+    client.set_max_arrears_periods(&1);
+    client.enforce_arrears(&lease.id);
+
+    // Period 2 was drawn into escrow and the unused three months refunded
+    assert_eq!(client.get_remaining_prepaid(&lease.id), 0);
+    assert_eq!(client.get_escrow_balance(&lease.id), 1000);
+    assert_eq!(balances.balance(&lease.tenant), 100_000 - 1000);
+}
+
+#[test]
+fn test_arrears_first_payment_clears_oldest_period() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let lease = create_active_lease(&env, &client);
+
+    // Three periods due, one payment: periods 2 and 3 stay open
+    env.ledger()
+        .with_mut(|li| li.timestamp = START + 2 * PERIOD);
+    client.make_payment_with_token(&lease.id, &1000, &lease.token);
+
+    assert_eq!(client.get_arrears(&lease.id), 2);
+    assert_eq!(client.get_overdue_periods(&lease.id), 1);
+}
+
+#[test]
+fn test_current_first_payment_covers_current_period() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let lease = create_lease_with_allocation(&env, &client, AllocationPolicy::CurrentFirst);
+
+    // Three periods due, one payment: it covers period 3, leaving 1 and 2 overdue
+    env.ledger()
+        .with_mut(|li| li.timestamp = START + 2 * PERIOD);
+    client.make_payment_with_token(&lease.id, &1000, &lease.token);
+
+    assert_eq!(client.get_arrears(&lease.id), 2);
+    assert_eq!(client.get_overdue_periods(&lease.id), 2);
+
+    // With the current period covered, the next payment clears the oldest
+    client.make_payment_with_token(&lease.id, &1000, &lease.token);
+    assert_eq!(client.get_arrears(&lease.id), 1);
+    assert_eq!(client.get_overdue_periods(&lease.id), 1);
+}
+
+#[test]
+fn test_enforce_arrears_below_threshold_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let lease = create_active_lease(&env, &client);
+    client.set_max_arrears_periods(&3);
+
+    env.ledger().with_mut(|li| li.timestamp = START + PERIOD);
+    assert_eq!(client.get_arrears(&lease.id), 2);
+
+    let result = client.try_enforce_arrears(&lease.id);
+    assert_eq!(result, Err(Ok(RentalError::ArrearsThresholdNotReached)));
+    let agreement = client.get_agreement(&lease.id).unwrap();
+    assert_eq!(agreement.status, AgreementStatus::Active);
+}
+
+#[test]
+fn test_outstanding_balance_for_lease_in_arrears() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let lease = create_active_lease(&env, &client);
+
+    // Three periods due, one paid
+    client.make_payment_with_token(&lease.id, &1000, &lease.token);
+    env.ledger()
+        .with_mut(|li| li.timestamp = START + 2 * PERIOD);
+
+    assert_eq!(client.get_outstanding_balance(&lease.id), 2000);
+}
+
+#[test]
+fn test_outstanding_balance_zero_when_current() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let lease = create_active_lease(&env, &client);
+
+    client.make_payment_with_token(&lease.id, &1000, &lease.token);
+
+    assert_eq!(client.get_outstanding_balance(&lease.id), 0);
+}
+
+#[test]
+fn test_outstanding_balance_zero_when_over_prepaid() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let lease = create_active_lease(&env, &client);
+
+    // The current period is unpaid, but three future periods are prepaid
+    client.prepay_rent(&lease.id, &lease.token, &3);
+
+    assert_eq!(client.get_outstanding_balance(&lease.id), 0);
+}
+
+#[test]
+fn test_due_reminders_only_include_near_due_agreements() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let lease = create_active_lease(&env, &client);
+
+    // A second lease whose first rent is due months away
+    let later = String::from_str(&env, "BILLING_2");
+    let landlord = Address::generate(&env);
+    let tenant = Address::generate(&env);
+    client.create_agreement(&AgreementInput {
+        agreement_id: later.clone(),
+        landlord: landlord.clone(),
+        tenant: tenant.clone(),
+        agent: None,
+        terms: AgreementTerms {
+            monthly_rent: 1000,
+            security_deposit: 0,
+            start_date: START + 6 * PERIOD,
+            end_date: START + 18 * PERIOD,
+            agent_commission_rate: 0,
+        },
+        payment_token: lease.token.clone(),
+        token_decimals: 7,
+        metadata_uri: String::from_str(&env, ""),
+        attributes: Vec::new(&env),
+    });
+    client.submit_agreement(&landlord, &later);
+    client.sign_agreement(&tenant, &later);
+
+    // The first period is paid, so the next falls due at START + PERIOD
+    client.make_payment_with_token(&lease.id, &1000, &lease.token);
+    env.ledger()
+        .with_mut(|li| li.timestamp = START + PERIOD - 86_400);
+
+    let reminders = client.get_due_reminders(&(3 * 86_400), &0, &10);
+    assert_eq!(
+        reminders,
+        soroban_sdk::vec![&env, (lease.id.clone(), START + PERIOD)]
+    );
+
+    // Paging past the first agreement skips it
+    assert_eq!(client.get_due_reminders(&(3 * 86_400), &1, &10).len(), 0);
+    assert_eq!(client.get_due_reminders(&86_399, &0, &10).len(), 0);
+}
+
+#[test]
+fn test_approved_break_terminates_with_penalty() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let lease = create_active_lease(&env, &client);
+
+    client.request_break(&lease.id, &lease.tenant);
+    assert!(client.get_agreement(&lease.id).unwrap().break_requested);
+
+    client.approve_break(&lease.id, &lease.landlord, &700);
+
+    let agreement = client.get_agreement(&lease.id).unwrap();
+    assert_eq!(agreement.status, AgreementStatus::Terminated);
+    assert!(!agreement.break_requested);
+    assert_eq!(agreement.early_termination_fee, 700);
+}
+
+#[test]
+fn test_unapproved_break_leaves_lease_active() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let lease = create_active_lease(&env, &client);
+
+    // No approval without a request
+    assert_eq!(
+        client.try_approve_break(&lease.id, &lease.landlord, &0),
+        Err(Ok(RentalError::InvalidState))
+    );
+
+    client.request_break(&lease.id, &lease.tenant);
+    assert_eq!(
+        client.get_agreement(&lease.id).unwrap().status,
+        AgreementStatus::Active
+    );
+    client.make_payment_with_token(&lease.id, &1000, &lease.token);
+}
+
+#[test]
+fn test_break_finalized_after_notice_period() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    client.set_notice_period(&PERIOD);
+    let lease = create_active_lease(&env, &client);
+
+    client.request_break(&lease.id, &lease.tenant);
+    env.ledger().with_mut(|li| li.timestamp = START + PERIOD);
+    client.finalize_break(&lease.id);
+
+    let agreement = client.get_agreement(&lease.id).unwrap();
+    assert_eq!(agreement.status, AgreementStatus::Terminated);
+    assert!(!agreement.break_requested);
+    assert_eq!(agreement.early_termination_fee, 500);
+}
+
+#[test]
+fn test_break_not_finalized_during_notice_period() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    client.set_notice_period(&PERIOD);
+    let lease = create_active_lease(&env, &client);
+
+    client.request_break(&lease.id, &lease.tenant);
+    env.ledger()
+        .with_mut(|li| li.timestamp = START + PERIOD - 1);
+
+    assert_eq!(
+        client.try_finalize_break(&lease.id),
+        Err(Ok(RentalError::NoticePeriodActive))
+    );
+    assert_eq!(
+        client.get_agreement(&lease.id).unwrap().status,
+        AgreementStatus::Active
+    );
+}
+
+#[test]
+fn test_dispute_drops_pending_break() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    client.set_notice_period(&PERIOD);
+    let lease = create_active_lease(&env, &client);
+
+    client.request_break(&lease.id, &lease.tenant);
+    client.raise_dispute(&lease.id, &lease.landlord);
+    env.ledger().with_mut(|li| li.timestamp = START + PERIOD);
+
+    assert_eq!(
+        client.try_finalize_break(&lease.id),
+        Err(Ok(RentalError::AgreementNotActive))
+    );
+    assert_eq!(
+        client.try_approve_break(&lease.id, &lease.landlord, &0),
+        Err(Ok(RentalError::AgreementNotActive))
+    );
+    let agreement = client.get_agreement(&lease.id).unwrap();
+    assert_eq!(agreement.status, AgreementStatus::Disputed);
+    assert!(!agreement.break_requested);
+    assert_eq!(agreement.break_requested_at, None);
+}
+
+#[test]
+fn test_break_request_on_inactive_agreement_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let lease = create_active_lease(&env, &client);
+    client.request_break(&lease.id, &lease.tenant);
+    client.approve_break(&lease.id, &lease.landlord, &0);
+
+    assert_eq!(
+        client.try_request_break(&lease.id, &lease.tenant),
+        Err(Ok(RentalError::AgreementNotActive))
+    );
+}
+
+#[test]
+fn test_reconcile_passes_for_consistent_agreement() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let lease = create_active_lease(&env, &client);
+
+    assert_eq!(client.reconcile(&lease.id, &0, &50, &0), 0);
+    client.make_payment_with_token(&lease.id, &1000, &lease.token);
+    client.make_payment_with_token(&lease.id, &1000, &lease.token);
+    assert_eq!(client.reconcile(&lease.id, &0, &50, &0), 2000);
+}
+
+#[test]
+fn test_reconcile_pages_through_records() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let lease = create_active_lease(&env, &client);
+    for _ in 0..3 {
+        client.make_payment_with_token(&lease.id, &1000, &lease.token);
+    }
+
+    let carried = client.reconcile(&lease.id, &0, &2, &0);
+    assert_eq!(carried, 2000);
+    assert_eq!(client.reconcile(&lease.id, &2, &2, &carried), 3000);
+
+    // A short carried sum is caught on the last page
+    assert_eq!(
+        client.try_reconcile(&lease.id, &2, &2, &0),
+        Err(Ok(LedgerError::ReconciliationMismatch.into()))
+    );
+}
+
+#[test]
+fn test_reconcile_detects_corrupted_total() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let lease = create_active_lease(&env, &client);
+
+    client.make_payment_with_token(&lease.id, &1000, &lease.token);
+
+    env.as_contract(&client.address, || {
+        let key = crate::storage::DataKey::Agreement(lease.id.clone());
+        let mut agreement: RentAgreement = env.storage().persistent().get(&key).unwrap();
+        agreement.total_rent_paid += 1;
+        env.storage().persistent().set(&key, &agreement);
+    });
+
+    assert_eq!(
+        client.try_reconcile(&lease.id, &0, &50, &0),
+        Err(Ok(LedgerError::ReconciliationMismatch.into()))
+    );
+}
+
+/// Create an Active deposit-free lease like `create_active_lease`, backed by a
+/// guarantor who has approved the contract to spend 5000 of the token.
+fn create_guaranteed_lease(env: &Env, client: &ContractClient<'_>) -> (Lease, Address) {
+    let tenant = Address::generate(env);
+    let landlord = Address::generate(env);
+    let guarantor = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    let id = String::from_str(env, "GUARANTEED_1");
+
+    env.ledger().with_mut(|li| li.timestamp = START);
+    client.create_agreement(&AgreementInput {
+        agreement_id: id.clone(),
+        landlord: landlord.clone(),
+        tenant: tenant.clone(),
+        agent: None,
+        terms: AgreementTerms {
+            monthly_rent: 1000,
+            security_deposit: 0,
+            start_date: START,
+            end_date: START + 12 * PERIOD,
+            agent_commission_rate: 0,
+        },
+        payment_token: token.clone(),
+        token_decimals: 7,
+        metadata_uri: String::from_str(env, ""),
+        attributes: Vec::new(env),
+    });
+    client.set_guarantor(&id, &guarantor);
+    client.submit_agreement(&landlord, &id);
+    client.sign_agreement(&tenant, &id);
+
+    token::StellarAssetClient::new(env, &token).mint(&guarantor, &5_000);
+    token::Client::new(env, &token).approve(&guarantor, &client.address, &5_000, &1_000);
+
+    (
+        Lease {
+            id,
+            tenant,
+            landlord,
+            token,
+        },
+        guarantor,
+    )
+}
+
+#[test]
+fn test_landlord_charges_guarantor_for_arrears() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let (lease, guarantor) = create_guaranteed_lease(&env, &client);
+    let balances = token::Client::new(&env, &lease.token);
+
+    // Three periods due, none paid
+    env.ledger()
+        .with_mut(|li| li.timestamp = START + 2 * PERIOD + 10);
+    assert_eq!(client.get_outstanding_balance(&lease.id), 3000);
+
+    client.charge_guarantor(&lease.id, &lease.token, &2000);
+
+    assert_eq!(balances.balance(&lease.landlord), 2000);
+    assert_eq!(balances.balance(&guarantor), 3000);
+    assert_eq!(client.get_outstanding_balance(&lease.id), 1000);
+    assert_eq!(client.get_payment_history(&lease.id).len(), 1);
+}
+
+#[test]
+fn test_guarantor_charge_capped_at_outstanding_balance() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let (lease, guarantor) = create_guaranteed_lease(&env, &client);
+    let balances = token::Client::new(&env, &lease.token);
+
+    env.ledger()
+        .with_mut(|li| li.timestamp = START + PERIOD + 10);
+    assert_eq!(client.get_outstanding_balance(&lease.id), 2000);
+
+    assert_eq!(
+        client.try_charge_guarantor(&lease.id, &lease.token, &2001),
+        Err(Ok(RentalError::InvalidAmount))
+    );
+    assert_eq!(balances.balance(&guarantor), 5000);
+    assert_eq!(balances.balance(&lease.landlord), 0);
+}
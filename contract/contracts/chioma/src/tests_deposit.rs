@@ -0,0 +1,859 @@
+use super::*;
+use crate::application::APPLICATION_FEE_TIMEOUT;
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger},
+    token, vec, Address, Env, IntoVal, Map, String, Symbol, Val,
+};
+
+fn create_contract(env: &Env) -> ContractClient<'_> {
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(env, &contract_id);
+    client.initialize(
+        &Address::generate(env),
+        &Config {
+            fee_bps: 0,
+            fee_collector: Address::generate(env),
+            paused: false,
+        },
+    );
+    client
+}
+
+struct Lease {
+    id: String,
+    tenant: Address,
+    landlord: Address,
+    token: Address,
+}
+
+/// Create a Pending agreement with a 2000 deposit and fund the tenant for it.
+fn create_lease(env: &Env, client: &ContractClient<'_>, refundable: bool) -> Lease {
+    let tenant = Address::generate(env);
+    let landlord = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    let id = String::from_str(env, "DEPOSIT_1");
+
+    client.create_agreement(&AgreementInput {
+        agreement_id: id.clone(),
+        landlord: landlord.clone(),
+        tenant: tenant.clone(),
+        agent: None,
+        terms: AgreementTerms {
+            monthly_rent: 1000,
+            security_deposit: 2000,
+            start_date: 100,
+            end_date: 10_000,
+            agent_commission_rate: 0,
+        },
+        payment_token: token.clone(),
+        token_decimals: 7,
+        metadata_uri: String::from_str(env, ""),
+        attributes: Vec::new(env),
+    });
+    client.set_deposit_refundable(&id, &refundable);
+    client.submit_agreement(&landlord, &id);
+
+    token::StellarAssetClient::new(env, &token).mint(&tenant, &2000);
+
+    Lease {
+        id,
+        tenant,
+        landlord,
+        token,
+    }
+}
+
+#[test]
+fn test_refundable_deposit_returns_to_tenant_on_settlement() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let lease = create_lease(&env, &client, true);
+    let balance = token::Client::new(&env, &lease.token);
+
+    client.deposit_security(&lease.id);
+    client.sign_agreement(&lease.tenant, &lease.id);
+    assert_eq!(client.get_deposit_held(&lease.id), 2000);
+    assert_eq!(balance.balance(&lease.tenant), 0);
+
+    env.ledger().with_mut(|li| li.timestamp = 10_000);
+    client.settle_agreement(&lease.landlord, &lease.id);
+
+    assert_eq!(balance.balance(&lease.tenant), 2000);
+    assert_eq!(balance.balance(&lease.landlord), 0);
+    assert_eq!(client.get_deposit_held(&lease.id), 0);
+    assert_eq!(
+        client.get_agreement(&lease.id).unwrap().status,
+        AgreementStatus::Completed
+    );
+}
+
+#[test]
+fn test_deposit_at_minimum_accepted() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    client.set_min_deposit(&2000);
+    let lease = create_lease(&env, &client, true);
+
+    client.deposit_security(&lease.id);
+    assert_eq!(client.get_deposit_held(&lease.id), 2000);
+}
+
+#[test]
+fn test_deposit_below_minimum_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    client.set_min_deposit(&2001);
+    let lease = create_lease(&env, &client, true);
+
+    assert_eq!(
+        client.try_deposit_security(&lease.id),
+        Err(Ok(RentalError::InvalidAmount))
+    );
+
+    // Clearing the minimum lets the same deposit through
+    client.set_min_deposit(&0);
+    client.deposit_security(&lease.id);
+    assert_eq!(client.get_deposit_held(&lease.id), 2000);
+}
+
+#[test]
+fn test_non_refundable_deposit_goes_to_landlord_on_settlement() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let lease = create_lease(&env, &client, false);
+    let balance = token::Client::new(&env, &lease.token);
+
+    client.deposit_security(&lease.id);
+    client.sign_agreement(&lease.tenant, &lease.id);
+
+    let result = client.try_release_deposit(&lease.id);
+    assert_eq!(result, Err(Ok(RentalError::InvalidState)));
+
+    env.ledger().with_mut(|li| li.timestamp = 10_000);
+    client.settle_agreement(&lease.tenant, &lease.id);
+
+    assert_eq!(balance.balance(&lease.tenant), 0);
+    assert_eq!(balance.balance(&lease.landlord), 2000);
+    assert_eq!(client.get_deposit_held(&lease.id), 0);
+}
+
+#[test]
+fn test_non_refundable_deposit_goes_to_landlord_on_termination() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let lease = create_lease(&env, &client, false);
+    let balance = token::Client::new(&env, &lease.token);
+
+    client.deposit_security(&lease.id);
+    client.sign_agreement(&lease.tenant, &lease.id);
+    client.request_break(&lease.id, &lease.tenant);
+    client.approve_break(&lease.id, &lease.landlord, &0);
+
+    assert_eq!(balance.balance(&lease.landlord), 2000);
+    assert_eq!(client.get_deposit_held(&lease.id), 0);
+}
+
+#[test]
+fn test_refundable_deposit_released_after_termination() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let lease = create_lease(&env, &client, true);
+    let balance = token::Client::new(&env, &lease.token);
+
+    client.deposit_security(&lease.id);
+    client.sign_agreement(&lease.tenant, &lease.id);
+    client.request_break(&lease.id, &lease.tenant);
+    client.approve_break(&lease.id, &lease.landlord, &0);
+    assert_eq!(client.get_deposit_held(&lease.id), 2000);
+
+    assert_eq!(client.release_deposit(&lease.id), 2000);
+    assert_eq!(balance.balance(&lease.tenant), 2000);
+}
+
+#[test]
+fn test_cancel_returns_held_deposit_to_tenant() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let lease = create_lease(&env, &client, false);
+    let balance = token::Client::new(&env, &lease.token);
+
+    client.deposit_security(&lease.id);
+    client.cancel_agreement(&lease.landlord, &lease.id);
+
+    assert_eq!(balance.balance(&lease.tenant), 2000);
+    assert_eq!(balance.balance(&client.address), 0);
+    assert_eq!(client.get_deposit_held(&lease.id), 0);
+
+    // Nothing is left held, so the agreement can be closed
+    client.close_agreement(&lease.id, &lease.landlord);
+    assert!(!client.has_agreement(&lease.id));
+}
+
+#[test]
+fn test_deposit_release_blocked_during_inspection_period() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    client.set_inspection_period(&500);
+    let lease = create_lease(&env, &client, true);
+    let balance = token::Client::new(&env, &lease.token);
+
+    client.deposit_security(&lease.id);
+    client.sign_agreement(&lease.tenant, &lease.id);
+
+    env.ledger().with_mut(|li| li.timestamp = 10_000);
+    client.settle_agreement(&lease.landlord, &lease.id);
+
+    // Settlement leaves the deposit held for inspection
+    assert_eq!(client.get_deposit_held(&lease.id), 2000);
+    assert_eq!(balance.balance(&lease.tenant), 0);
+
+    env.ledger().with_mut(|li| li.timestamp = 10_499);
+    let result = client.try_release_deposit(&lease.id);
+    assert_eq!(result, Err(Ok(RentalError::InspectionPending)));
+    assert_eq!(client.get_deposit_held(&lease.id), 2000);
+}
+
+#[test]
+fn test_deposit_released_after_inspection_period() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    client.set_inspection_period(&500);
+    let lease = create_lease(&env, &client, true);
+    let balance = token::Client::new(&env, &lease.token);
+
+    client.deposit_security(&lease.id);
+    client.sign_agreement(&lease.tenant, &lease.id);
+
+    env.ledger().with_mut(|li| li.timestamp = 10_000);
+    client.settle_agreement(&lease.landlord, &lease.id);
+    assert_eq!(
+        client.get_agreement(&lease.id).unwrap().completed_at,
+        Some(10_000)
+    );
+
+    env.ledger().with_mut(|li| li.timestamp = 10_500);
+    assert_eq!(client.release_deposit(&lease.id), 2000);
+    assert_eq!(balance.balance(&lease.tenant), 2000);
+    assert_eq!(client.get_deposit_held(&lease.id), 0);
+}
+
+#[test]
+fn test_tenant_claims_deposit_after_inspection_period() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    client.set_inspection_period(&500);
+    let lease = create_lease(&env, &client, true);
+    let balance = token::Client::new(&env, &lease.token);
+
+    client.deposit_security(&lease.id);
+    client.sign_agreement(&lease.tenant, &lease.id);
+    env.ledger().with_mut(|li| li.timestamp = 10_000);
+    client.settle_agreement(&lease.landlord, &lease.id);
+
+    env.ledger().with_mut(|li| li.timestamp = 10_499);
+    assert_eq!(
+        client.try_claim_deposit_refund(&lease.id),
+        Err(Ok(RentalError::InspectionPending))
+    );
+
+    env.ledger().with_mut(|li| li.timestamp = 10_500);
+    assert_eq!(client.claim_deposit_refund(&lease.id), 2000);
+    assert_eq!(balance.balance(&lease.tenant), 2000);
+    assert_eq!(client.get_deposit_held(&lease.id), 0);
+}
+
+#[test]
+fn test_deposit_release_rejected_before_agreement_ends() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let lease = create_lease(&env, &client, true);
+
+    client.deposit_security(&lease.id);
+    assert_eq!(
+        client.try_release_deposit(&lease.id),
+        Err(Ok(RentalError::InvalidState))
+    );
+
+    client.sign_agreement(&lease.tenant, &lease.id);
+    assert_eq!(
+        client.try_release_deposit(&lease.id),
+        Err(Ok(RentalError::InvalidState))
+    );
+    assert_eq!(
+        client.try_claim_deposit_refund(&lease.id),
+        Err(Ok(RentalError::InvalidState))
+    );
+    assert_eq!(client.get_deposit_held(&lease.id), 2000);
+}
+
+#[test]
+fn test_settle_before_end_date_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let lease = create_lease(&env, &client, true);
+
+    client.deposit_security(&lease.id);
+    client.sign_agreement(&lease.tenant, &lease.id);
+
+    let result = client.try_settle_agreement(&lease.landlord, &lease.id);
+    assert_eq!(result, Err(Ok(RentalError::InvalidState)));
+}
+
+#[test]
+fn test_settle_emits_single_consolidated_event() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let lease = create_lease(&env, &client, true);
+
+    client.deposit_security(&lease.id);
+    client.sign_agreement(&lease.tenant, &lease.id);
+    token::StellarAssetClient::new(&env, &lease.token).mint(&lease.tenant, &1000);
+    client.make_payment_with_token(&lease.id, &1000, &lease.token);
+
+    env.ledger().with_mut(|li| li.timestamp = 10_000);
+    client.settle_agreement(&lease.landlord, &lease.id);
+
+    let mut ours = Vec::new(&env);
+    for event in env.events().all().iter() {
+        if event.0 == client.address {
+            ours.push_back(event);
+        }
+    }
+    assert_eq!(ours.len(), 1);
+
+    let (_, topics, data) = ours.get(0).unwrap();
+    let name: Symbol = topics.get(0).unwrap().into_val(&env);
+    assert_eq!(name, Symbol::new(&env, "agr_settled"));
+
+    let fields: Map<Symbol, Val> = data.into_val(&env);
+    let field = |key: &str| fields.get(Symbol::new(&env, key)).unwrap();
+    let status: AgreementStatus = field("status").into_val(&env);
+    let deposit_returned: i128 = field("deposit_returned").into_val(&env);
+    let escrow_disbursed: i128 = field("escrow_disbursed").into_val(&env);
+    assert_eq!(status, AgreementStatus::Completed);
+    assert_eq!(deposit_returned, 2000);
+    assert_eq!(escrow_disbursed, 1000);
+
+    let balances = token::Client::new(&env, &lease.token);
+    assert_eq!(balances.balance(&lease.landlord), 1000);
+    assert_eq!(balances.balance(&lease.tenant), 2000);
+}
+
+#[test]
+fn test_deposit_held_in_configured_vault() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let lease = create_lease(&env, &client, true);
+    let balances = token::Client::new(&env, &lease.token);
+
+    let vault = Address::generate(&env);
+    client.set_vault(&Some(vault.clone()));
+    balances.approve(&vault, &client.address, &2000, &1_000);
+
+    client.deposit_security(&lease.id);
+    assert_eq!(balances.balance(&vault), 2000);
+    assert_eq!(balances.balance(&client.address), 0);
+    assert_eq!(
+        client.get_deposit_custody(&lease.id).unwrap().custodian,
+        vault
+    );
+
+    // Release once the lease has ended
+    client.sign_agreement(&lease.tenant, &lease.id);
+    client.request_break(&lease.id, &lease.tenant);
+    client.approve_break(&lease.id, &lease.landlord, &0);
+    client.release_deposit(&lease.id);
+    assert_eq!(balances.balance(&vault), 0);
+    assert_eq!(balances.balance(&lease.tenant), 2000);
+}
+
+#[test]
+fn test_deposit_self_custodied_without_vault() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let lease = create_lease(&env, &client, true);
+    let balances = token::Client::new(&env, &lease.token);
+
+    client.set_vault(&Some(Address::generate(&env)));
+    client.set_vault(&None);
+    assert_eq!(client.get_vault(), None);
+
+    client.deposit_security(&lease.id);
+    assert_eq!(balances.balance(&client.address), 2000);
+    assert_eq!(
+        client.get_deposit_custody(&lease.id).unwrap().custodian,
+        client.address
+    );
+
+    // Release once the lease has ended
+    client.sign_agreement(&lease.tenant, &lease.id);
+    client.request_break(&lease.id, &lease.tenant);
+    client.approve_break(&lease.id, &lease.landlord, &0);
+    client.release_deposit(&lease.id);
+    assert_eq!(balances.balance(&client.address), 0);
+    assert_eq!(balances.balance(&lease.tenant), 2000);
+}
+
+#[test]
+fn test_escrow_breakdown_reports_each_bucket() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let lease = create_lease(&env, &client, true);
+    let balance = token::Client::new(&env, &lease.token);
+
+    client.deposit_security(&lease.id);
+    client.sign_agreement(&lease.tenant, &lease.id);
+    token::StellarAssetClient::new(&env, &lease.token).mint(&lease.tenant, &1000);
+    client.make_payment_with_token(&lease.id, &1000, &lease.token);
+
+    let breakdown = client.get_escrow_breakdown(&lease.id);
+    assert_eq!(breakdown.deposit, 2000);
+    assert_eq!(breakdown.rent, 1000);
+    assert_eq!(breakdown.prepaid_rent, 0);
+    assert_eq!(breakdown.total, balance.balance(&client.address));
+}
+
+#[test]
+fn test_deposit_applied_to_unpaid_last_month() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let lease = create_lease(&env, &client, true);
+    let balance = token::Client::new(&env, &lease.token);
+    client.set_apply_deposit_to_last(&lease.id, &true);
+
+    client.deposit_security(&lease.id);
+    client.sign_agreement(&lease.tenant, &lease.id);
+
+    // The single period is never paid; the deposit covers it
+    env.ledger().with_mut(|li| li.timestamp = 10_000);
+    client.settle_agreement(&lease.tenant, &lease.id);
+
+    assert_eq!(balance.balance(&lease.landlord), 1000);
+    assert_eq!(balance.balance(&lease.tenant), 1000);
+    assert_eq!(client.get_deposit_held(&lease.id), 0);
+    let agreement = client.get_agreement(&lease.id).unwrap();
+    assert_eq!(agreement.total_rent_paid, 1000);
+    assert_eq!(agreement.payment_count, 1);
+}
+
+#[test]
+fn test_deposit_refunded_when_not_applied_to_last_month() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let lease = create_lease(&env, &client, true);
+    let balance = token::Client::new(&env, &lease.token);
+
+    client.deposit_security(&lease.id);
+    client.sign_agreement(&lease.tenant, &lease.id);
+
+    env.ledger().with_mut(|li| li.timestamp = 10_000);
+    client.settle_agreement(&lease.tenant, &lease.id);
+
+    assert_eq!(balance.balance(&lease.landlord), 0);
+    assert_eq!(balance.balance(&lease.tenant), 2000);
+    assert_eq!(client.get_agreement(&lease.id).unwrap().total_rent_paid, 0);
+}
+
+#[test]
+fn test_application_fee_credited_toward_deposit() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let lease = create_lease(&env, &client, true);
+    let balance = token::Client::new(&env, &lease.token);
+    let property_id = String::from_str(&env, "PROP_1");
+
+    // The tenant applied for the property before the agreement was made
+    client.pay_application_fee(
+        &lease.tenant,
+        &lease.landlord,
+        &property_id,
+        &lease.token,
+        &500,
+    );
+    assert_eq!(balance.balance(&lease.tenant), 1500);
+
+    client.link_property(&lease.landlord, &lease.id, &property_id);
+    client.deposit_security(&lease.id);
+
+    assert_eq!(client.get_deposit_held(&lease.id), 2000);
+    assert_eq!(balance.balance(&lease.tenant), 0);
+    assert_eq!(balance.balance(&client.address), 2000);
+    assert!(client
+        .get_application_fee(&lease.tenant, &property_id)
+        .is_none());
+}
+
+#[test]
+fn test_property_tracks_active_agreement_until_completion() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let lease = create_lease(&env, &client, true);
+    let property_id = String::from_str(&env, "PROP_1");
+
+    client.link_property(&lease.landlord, &lease.id, &property_id);
+    assert!(client.get_property_active_agreement(&property_id).is_none());
+
+    client.deposit_security(&lease.id);
+    client.sign_agreement(&lease.tenant, &lease.id);
+    assert_eq!(
+        client.get_property_active_agreement(&property_id),
+        Some(lease.id.clone())
+    );
+
+    env.ledger().with_mut(|li| li.timestamp = 10_000);
+    client.settle_agreement(&lease.landlord, &lease.id);
+    assert!(client.get_property_active_agreement(&property_id).is_none());
+}
+
+#[test]
+fn test_property_without_lease_has_no_active_agreement() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+
+    assert!(client
+        .get_property_active_agreement(&String::from_str(&env, "PROP_9"))
+        .is_none());
+}
+
+#[test]
+fn test_unused_application_fee_refunded_by_landlord() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let lease = create_lease(&env, &client, true);
+    let balance = token::Client::new(&env, &lease.token);
+    let property_id = String::from_str(&env, "PROP_1");
+
+    client.pay_application_fee(
+        &lease.tenant,
+        &lease.landlord,
+        &property_id,
+        &lease.token,
+        &500,
+    );
+
+    let refunded = client.refund_application_fee(&lease.landlord, &lease.tenant, &property_id);
+
+    assert_eq!(refunded, 500);
+    assert_eq!(balance.balance(&lease.tenant), 2000);
+    assert_eq!(
+        client.try_refund_application_fee(&lease.landlord, &lease.tenant, &property_id),
+        Err(Ok(RentalError::ApplicationFeeNotFound))
+    );
+}
+
+#[test]
+fn test_unused_application_fee_reclaimed_by_applicant_after_timeout() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let lease = create_lease(&env, &client, true);
+    let balance = token::Client::new(&env, &lease.token);
+    let property_id = String::from_str(&env, "PROP_1");
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    client.pay_application_fee(
+        &lease.tenant,
+        &lease.landlord,
+        &property_id,
+        &lease.token,
+        &500,
+    );
+
+    env.ledger()
+        .with_mut(|li| li.timestamp = 1_000 + APPLICATION_FEE_TIMEOUT - 1);
+    assert_eq!(
+        client.try_reclaim_application_fee(&lease.tenant, &property_id),
+        Err(Ok(RentalError::CooldownNotMet))
+    );
+
+    env.ledger()
+        .with_mut(|li| li.timestamp = 1_000 + APPLICATION_FEE_TIMEOUT);
+    let refunded = client.reclaim_application_fee(&lease.tenant, &property_id);
+
+    assert_eq!(refunded, 500);
+    assert_eq!(balance.balance(&lease.tenant), 2000);
+    assert!(client
+        .get_application_fee(&lease.tenant, &property_id)
+        .is_none());
+}
+
+#[test]
+fn test_funding_auto_activates_when_enabled() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let lease = create_lease(&env, &client, true);
+    client.set_auto_activate_on_funding(&lease.id, &true);
+
+    client.deposit_security(&lease.id);
+
+    let mut names = Vec::new(&env);
+    for event in env.events().all().iter() {
+        if event.0 == client.address {
+            let name: Symbol = event.1.get(0).unwrap().into_val(&env);
+            names.push_back(name);
+        }
+    }
+    assert_eq!(
+        names,
+        soroban_sdk::vec![
+            &env,
+            Symbol::new(&env, "dep_recv"),
+            Symbol::new(&env, "agr_signed")
+        ]
+    );
+
+    let agreement = client.get_agreement(&lease.id).unwrap();
+    assert_eq!(agreement.status, AgreementStatus::Active);
+    assert!(agreement.signed_at.is_some());
+}
+
+#[test]
+fn test_funding_leaves_pending_without_auto_activation() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let lease = create_lease(&env, &client, true);
+
+    client.deposit_security(&lease.id);
+    assert_eq!(
+        client.get_agreement(&lease.id).unwrap().status,
+        AgreementStatus::Pending
+    );
+
+    client.sign_agreement(&lease.tenant, &lease.id);
+    assert_eq!(
+        client.get_agreement(&lease.id).unwrap().status,
+        AgreementStatus::Active
+    );
+}
+
+#[test]
+fn test_close_settled_agreement_removes_it() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let lease = create_lease(&env, &client, true);
+
+    client.deposit_security(&lease.id);
+    client.sign_agreement(&lease.tenant, &lease.id);
+    env.ledger().with_mut(|li| li.timestamp = 10_000);
+    client.settle_agreement(&lease.landlord, &lease.id);
+
+    assert_eq!(client.get_stats().total_agreements, 1);
+    client.close_agreement(&lease.id, &lease.tenant);
+
+    assert!(!client.has_agreement(&lease.id));
+    assert_eq!(client.get_audit_log(&lease.id).len(), 0);
+    assert_eq!(client.get_event_seq(&lease.id), 0);
+    assert_eq!(client.get_stats().total_agreements, 0);
+    assert_eq!(
+        client.get_closed_status(&lease.id),
+        Some(AgreementStatus::Completed)
+    );
+    assert_eq!(
+        client.try_close_agreement(&lease.id, &lease.tenant),
+        Err(Ok(RentalError::AgreementNotFound.into()))
+    );
+}
+
+#[test]
+fn test_closed_agreement_id_cannot_be_reused() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let lease = create_lease(&env, &client, true);
+    client.cancel_agreement(&lease.landlord, &lease.id);
+    client.close_agreement(&lease.id, &lease.landlord);
+
+    let result = client.try_create_agreement(&AgreementInput {
+        agreement_id: lease.id.clone(),
+        landlord: lease.landlord.clone(),
+        tenant: lease.tenant.clone(),
+        agent: None,
+        terms: AgreementTerms {
+            monthly_rent: 1000,
+            security_deposit: 0,
+            start_date: 100,
+            end_date: 10_000,
+            agent_commission_rate: 0,
+        },
+        payment_token: lease.token.clone(),
+        token_decimals: 7,
+        metadata_uri: String::from_str(&env, ""),
+        attributes: Vec::new(&env),
+    });
+    assert_eq!(result, Err(Ok(RentalError::AgreementAlreadyExists)));
+    assert_eq!(client.get_landlord_agreement_count(&lease.landlord), 1);
+}
+
+#[test]
+fn test_close_rejected_while_deposit_held() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    client.set_inspection_period(&500);
+    let lease = create_lease(&env, &client, true);
+
+    client.deposit_security(&lease.id);
+    client.sign_agreement(&lease.tenant, &lease.id);
+    env.ledger().with_mut(|li| li.timestamp = 10_000);
+    client.settle_agreement(&lease.landlord, &lease.id);
+
+    assert_eq!(
+        client.try_close_agreement(&lease.id, &lease.landlord),
+        Err(Ok(LedgerError::BalanceRemaining.into()))
+    );
+    assert!(client.has_agreement(&lease.id));
+}
+
+#[test]
+fn test_portfolio_summary_across_landlord_agreements() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let landlord = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+
+    let mut ids = Vec::new(&env);
+    for (name, deposit) in [("PORT_PAID", 2000), ("PORT_LATE", 0)] {
+        let tenant = Address::generate(&env);
+        let id = String::from_str(&env, name);
+        client.create_agreement(&AgreementInput {
+            agreement_id: id.clone(),
+            landlord: landlord.clone(),
+            tenant: tenant.clone(),
+            agent: None,
+            terms: AgreementTerms {
+                monthly_rent: 1000,
+                security_deposit: deposit,
+                start_date: 100,
+                end_date: 100 + 12 * 30 * 86_400,
+                agent_commission_rate: 0,
+            },
+            payment_token: token.clone(),
+            token_decimals: 7,
+            metadata_uri: String::from_str(&env, ""),
+            attributes: Vec::new(&env),
+        });
+        client.submit_agreement(&landlord, &id);
+        token::StellarAssetClient::new(&env, &token).mint(&tenant, &5000);
+        if deposit > 0 {
+            client.deposit_security(&id);
+        }
+        client.sign_agreement(&tenant, &id);
+        ids.push_back(id);
+    }
+
+    // The first period is due on both; only one lease pays it
+    env.ledger().with_mut(|li| li.timestamp = 200);
+    client.make_payment_with_token(&ids.get(0).unwrap(), &1000, &token);
+
+    let summary = client.get_portfolio_summary(&landlord, &token, &0, &50);
+    assert_eq!(summary.agreement_count, 2);
+    assert_eq!(summary.active_count, 2);
+    assert_eq!(summary.total_income, 1000);
+    assert_eq!(summary.total_arrears, 1000);
+    assert_eq!(summary.deposits_held, 2000);
+
+    // A page past the first agreement covers only the second
+    let second = client.get_portfolio_summary(&landlord, &token, &1, &50);
+    assert_eq!(second.agreement_count, 1);
+    assert_eq!(second.total_income, 0);
+    assert_eq!(client.get_landlord_agreement_count(&landlord), 2);
+
+    let stranger = client.get_portfolio_summary(&Address::generate(&env), &token, &0, &50);
+    assert_eq!(stranger.agreement_count, 0);
+
+    // Agreements in another token are left out
+    let other = Address::generate(&env);
+    let summary = client.get_portfolio_summary(&landlord, &other, &0, &50);
+    assert_eq!(summary.agreement_count, 0);
+    assert_eq!(summary.total_income, 0);
+}
+
+#[test]
+fn test_upcoming_renewals_lists_only_leases_ending_soon() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let landlord = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+
+    let month = 30 * 86_400;
+    for (name, end_date, sign) in [
+        ("RENEW_SOON", 100 + 2 * month, true),
+        ("RENEW_LATER", 100 + 12 * month, true),
+        ("RENEW_UNSIGNED", 100 + 2 * month, false),
+    ] {
+        let tenant = Address::generate(&env);
+        let id = String::from_str(&env, name);
+        client.create_agreement(&AgreementInput {
+            agreement_id: id.clone(),
+            landlord: landlord.clone(),
+            tenant: tenant.clone(),
+            agent: None,
+            terms: AgreementTerms {
+                monthly_rent: 1000,
+                security_deposit: 0,
+                start_date: 100,
+                end_date,
+                agent_commission_rate: 0,
+            },
+            payment_token: token.clone(),
+            token_decimals: 7,
+            metadata_uri: String::from_str(&env, ""),
+            attributes: Vec::new(&env),
+        });
+        client.submit_agreement(&landlord, &id);
+        if sign {
+            client.sign_agreement(&tenant, &id);
+        }
+    }
+
+    // One month in, only the two-month lease ends within the next 45 days
+    env.ledger().with_mut(|li| li.timestamp = 100 + month);
+    let renewals = client.get_upcoming_renewals(&landlord, &(45 * 86_400), &0, &50);
+    assert_eq!(renewals, vec![&env, String::from_str(&env, "RENEW_SOON")]);
+    assert_eq!(
+        client
+            .get_upcoming_renewals(&landlord, &(45 * 86_400), &1, &50)
+            .len(),
+        0
+    );
+
+    assert_eq!(
+        client
+            .get_upcoming_renewals(&Address::generate(&env), &(45 * 86_400), &0, &50)
+            .len(),
+        0
+    );
+}
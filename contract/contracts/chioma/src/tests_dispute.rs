@@ -0,0 +1,258 @@
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, String,
+};
+
+fn create_contract(env: &Env) -> ContractClient<'_> {
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(env, &contract_id);
+    client.initialize(
+        &Address::generate(env),
+        &Config {
+            fee_bps: 0,
+            fee_collector: Address::generate(env),
+            paused: false,
+        },
+    );
+    client
+}
+
+struct Lease {
+    id: String,
+    tenant: Address,
+    landlord: Address,
+    token: Address,
+}
+
+/// Create an Active lease at 1000 rent and put it into dispute.
+fn create_disputed_lease(env: &Env, client: &ContractClient<'_>) -> Lease {
+    create_disputed_lease_with_agent(env, client, None)
+}
+
+fn create_disputed_lease_with_agent(
+    env: &Env,
+    client: &ContractClient<'_>,
+    agent: Option<Address>,
+) -> Lease {
+    let tenant = Address::generate(env);
+    let landlord = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    let id = String::from_str(env, "DISPUTE_1");
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    client.create_agreement(&AgreementInput {
+        agreement_id: id.clone(),
+        landlord: landlord.clone(),
+        tenant: tenant.clone(),
+        agent,
+        terms: AgreementTerms {
+            monthly_rent: 1000,
+            security_deposit: 0,
+            start_date: 1_000,
+            end_date: 1_000_000,
+            agent_commission_rate: 0,
+        },
+        payment_token: token.clone(),
+        token_decimals: 7,
+        metadata_uri: String::from_str(env, ""),
+        attributes: Vec::new(env),
+    });
+    client.submit_agreement(&landlord, &id);
+    client.sign_agreement(&tenant, &id);
+    token::StellarAssetClient::new(env, &token).mint(&tenant, &10_000);
+
+    client.raise_dispute(&id, &tenant);
+
+    Lease {
+        id,
+        tenant,
+        landlord,
+        token,
+    }
+}
+
+fn status(client: &ContractClient<'_>, id: &String) -> AgreementStatus {
+    client.get_agreement(id).unwrap().status
+}
+
+#[test]
+fn test_payments_resume_only_after_both_acknowledge() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let lease = create_disputed_lease(&env, &client);
+
+    client.resolve_dispute(&lease.id, &false);
+    assert_eq!(
+        status(&client, &lease.id),
+        AgreementStatus::ResolutionPending
+    );
+
+    client.acknowledge_resolution(&lease.id, &lease.landlord);
+    assert_eq!(
+        client.try_make_payment_with_token(&lease.id, &1000, &lease.token),
+        Err(Ok(RentalError::AgreementNotActive))
+    );
+    let resolution = client.get_resolution(&lease.id).unwrap();
+    assert!(resolution.landlord_acknowledged);
+    assert!(!resolution.tenant_acknowledged);
+
+    client.acknowledge_resolution(&lease.id, &lease.tenant);
+    assert_eq!(status(&client, &lease.id), AgreementStatus::Active);
+    assert_eq!(client.get_resolution(&lease.id), None);
+
+    client.make_payment_with_token(&lease.id, &1000, &lease.token);
+    assert_eq!(client.get_agreement(&lease.id).unwrap().payment_count, 1);
+}
+
+#[test]
+fn test_terminating_resolution_applies_after_both_acknowledge() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let lease = create_disputed_lease(&env, &client);
+
+    client.resolve_dispute(&lease.id, &true);
+    client.acknowledge_resolution(&lease.id, &lease.tenant);
+    assert_eq!(
+        status(&client, &lease.id),
+        AgreementStatus::ResolutionPending
+    );
+
+    client.acknowledge_resolution(&lease.id, &lease.landlord);
+    assert_eq!(status(&client, &lease.id), AgreementStatus::Terminated);
+}
+
+#[test]
+fn test_resolve_dispute_rejected_while_paused() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let lease = create_disputed_lease(&env, &client);
+
+    client.pause(&String::from_str(&env, "maintenance"));
+    assert_eq!(
+        client.try_resolve_dispute(&lease.id, &true),
+        Err(Ok(RentalError::ContractPaused))
+    );
+}
+
+#[test]
+fn test_admin_finalizes_resolution_a_party_ignores() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let lease = create_disputed_lease(&env, &client);
+
+    assert_eq!(
+        client.try_finalize_resolution(&lease.id),
+        Err(Ok(RentalError::InvalidState))
+    );
+
+    // The tenant never acknowledges
+    client.resolve_dispute(&lease.id, &true);
+    client.acknowledge_resolution(&lease.id, &lease.landlord);
+    client.finalize_resolution(&lease.id);
+
+    assert_eq!(status(&client, &lease.id), AgreementStatus::Terminated);
+    assert_eq!(client.get_resolution(&lease.id), None);
+}
+
+#[test]
+fn test_acknowledge_resolution_rejects_outsiders_and_undecided_disputes() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let lease = create_disputed_lease(&env, &client);
+
+    // Nothing to acknowledge until the dispute is decided
+    assert_eq!(
+        client.try_acknowledge_resolution(&lease.id, &lease.tenant),
+        Err(Ok(RentalError::InvalidState))
+    );
+
+    client.resolve_dispute(&lease.id, &false);
+    assert_eq!(
+        client.try_acknowledge_resolution(&lease.id, &Address::generate(&env)),
+        Err(Ok(RentalError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_dispute_escalates_after_window() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    client.set_dispute_escalation_window(&500);
+    let lease = create_disputed_lease(&env, &client);
+    assert!(!client.is_dispute_escalated(&lease.id));
+
+    env.ledger().with_mut(|li| li.timestamp = 1_500);
+    client.escalate_dispute(&lease.id, &lease.landlord);
+    assert!(client.is_dispute_escalated(&lease.id));
+
+    client.resolve_dispute(&lease.id, &true);
+    assert!(!client.is_dispute_escalated(&lease.id));
+}
+
+#[test]
+fn test_agent_arbitrates_until_dispute_escalated() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let agent = Address::generate(&env);
+    let lease = create_disputed_lease_with_agent(&env, &client, Some(agent.clone()));
+    client.set_dispute_escalation_window(&500);
+
+    assert_eq!(
+        client.try_arbitrate_dispute(&lease.id, &lease.landlord, &false),
+        Err(Ok(RentalError::Unauthorized))
+    );
+
+    env.ledger().with_mut(|li| li.timestamp = 1_500);
+    client.escalate_dispute(&lease.id, &lease.tenant);
+    assert_eq!(
+        client.try_arbitrate_dispute(&lease.id, &agent, &false),
+        Err(Ok(RentalError::InvalidState))
+    );
+
+    // The admin still decides it
+    client.resolve_dispute(&lease.id, &false);
+    assert_eq!(
+        status(&client, &lease.id),
+        AgreementStatus::ResolutionPending
+    );
+}
+
+#[test]
+fn test_agent_resolves_unescalated_dispute() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let agent = Address::generate(&env);
+    let lease = create_disputed_lease_with_agent(&env, &client, Some(agent.clone()));
+
+    client.arbitrate_dispute(&lease.id, &agent, &true);
+    client.acknowledge_resolution(&lease.id, &lease.tenant);
+    client.acknowledge_resolution(&lease.id, &lease.landlord);
+    assert_eq!(status(&client, &lease.id), AgreementStatus::Terminated);
+}
+
+#[test]
+fn test_dispute_escalation_rejected_before_window() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    client.set_dispute_escalation_window(&500);
+    let lease = create_disputed_lease(&env, &client);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_499);
+    assert_eq!(
+        client.try_escalate_dispute(&lease.id, &lease.tenant),
+        Err(Ok(RentalError::InvalidState))
+    );
+    assert!(!client.is_dispute_escalated(&lease.id));
+}
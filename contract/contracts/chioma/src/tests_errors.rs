@@ -144,7 +144,7 @@ fn test_error_log_operation_name() {
         &details,
     );
     client.log_error(
-        &RentalError::PaymentFailed,
+        &RentalError::InsufficientPayment,
         &String::from_str(&env, "make_payment"),
         &details,
     );
@@ -214,13 +214,13 @@ fn test_error_log_various_types() {
         &details,
     );
     client.log_error(
-        &RentalError::PaymentInsufficientFunds,
+        &RentalError::InsufficientPayment,
         &String::from_str(&env, "payment"),
         &details,
     );
     client.log_error(
-        &RentalError::EscrowNotFound,
-        &String::from_str(&env, "escrow"),
+        &RentalError::TimelockNotFound,
+        &String::from_str(&env, "timelock"),
         &details,
     );
     client.log_error(
@@ -232,8 +232,8 @@ fn test_error_log_various_types() {
     let logs = client.get_error_logs(&10);
     assert_eq!(logs.len(), 4);
     assert_eq!(logs.get(0).unwrap().error_code, 13);
-    assert_eq!(logs.get(1).unwrap().error_code, 201);
-    assert_eq!(logs.get(2).unwrap().error_code, 401);
+    assert_eq!(logs.get(1).unwrap().error_code, 22);
+    assert_eq!(logs.get(2).unwrap().error_code, 301);
     assert_eq!(logs.get(3).unwrap().error_code, 801);
 }
 
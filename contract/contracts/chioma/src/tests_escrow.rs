@@ -0,0 +1,867 @@
+use super::*;
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{Address as _, Ledger},
+    token, vec, Address, Env, IntoVal, Map, String, Symbol, Val,
+};
+
+const PERIOD: u64 = 30 * 86_400;
+const START: u64 = 1_000;
+
+fn create_contract(env: &Env) -> ContractClient<'_> {
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(env, &contract_id);
+    client.initialize(
+        &Address::generate(env),
+        &Config {
+            fee_bps: 0,
+            fee_collector: Address::generate(env),
+            paused: false,
+        },
+    );
+    client
+}
+
+struct Lease {
+    id: String,
+    tenant: Address,
+    landlord: Address,
+    token: Address,
+}
+
+/// Create an Active 12-period lease at 1000 rent starting at `START`.
+fn create_active_lease(env: &Env, client: &ContractClient<'_>) -> Lease {
+    create_lease(env, client, false)
+}
+
+fn create_lease(env: &Env, client: &ContractClient<'_>, dual_approval: bool) -> Lease {
+    let tenant = Address::generate(env);
+    let landlord = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    let id = String::from_str(env, "PREPAY_1");
+
+    env.ledger().with_mut(|li| li.timestamp = START);
+    client.create_agreement(&AgreementInput {
+        agreement_id: id.clone(),
+        landlord: landlord.clone(),
+        tenant: tenant.clone(),
+        agent: None,
+        terms: AgreementTerms {
+            monthly_rent: 1000,
+            security_deposit: 0,
+            start_date: START,
+            end_date: START + 12 * PERIOD,
+            agent_commission_rate: 0,
+        },
+        payment_token: token.clone(),
+        token_decimals: 7,
+        metadata_uri: String::from_str(env, ""),
+        attributes: Vec::new(env),
+    });
+    client.set_dual_release_approval(&id, &dual_approval);
+    client.submit_agreement(&landlord, &id);
+    client.sign_agreement(&tenant, &id);
+
+    token::StellarAssetClient::new(env, &token).mint(&tenant, &10_000);
+    Lease {
+        id,
+        tenant,
+        landlord,
+        token,
+    }
+}
+
+#[test]
+fn test_prepaid_rent_disbursed_as_periods_come_due() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let lease = create_active_lease(&env, &client);
+    let balances = token::Client::new(&env, &lease.token);
+
+    assert_eq!(client.prepay_rent(&lease.id, &lease.token, &2), 2_000);
+    assert_eq!(balances.balance(&client.address), 2_000);
+
+    // Nothing earmarked is due yet
+    assert_eq!(client.disburse_prepaid_rent(&lease.id), 0);
+    assert_eq!(balances.balance(&lease.landlord), 0);
+
+    env.ledger()
+        .with_mut(|li| li.timestamp = START + 2 * PERIOD);
+    assert_eq!(client.disburse_prepaid_rent(&lease.id), 2);
+
+    // Drawn rent joins the escrowed rent, disbursed like any payment
+    assert_eq!(balances.balance(&lease.landlord), 0);
+    assert_eq!(client.get_escrow_balance(&lease.id), 2_000);
+    let agreement = client.get_agreement(&lease.id).unwrap();
+    assert_eq!(agreement.total_rent_paid, 2_000);
+    assert_eq!(agreement.payment_count, 2);
+
+    assert_eq!(client.disburse(&lease.id, &lease.landlord), 2_000);
+    assert_eq!(balances.balance(&lease.landlord), 2_000);
+    assert_eq!(balances.balance(&client.address), 0);
+
+    // The prepaid run is exhausted
+    env.ledger()
+        .with_mut(|li| li.timestamp = START + 3 * PERIOD);
+    assert_eq!(client.disburse_prepaid_rent(&lease.id), 0);
+}
+
+#[test]
+fn test_prepaid_rent_not_drawn_for_periods_paid_directly() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let lease = create_active_lease(&env, &client);
+    let balances = token::Client::new(&env, &lease.token);
+
+    // Periods 1 and 2 paid directly, then two more months prepaid from period 2
+    client.make_payment_with_token(&lease.id, &1000, &lease.token);
+    client.make_payment_with_token(&lease.id, &1000, &lease.token);
+    client.prepay_rent(&lease.id, &lease.token, &2);
+
+    // Period 2 is already paid, so nothing is drawn for it
+    env.ledger().with_mut(|li| li.timestamp = START + PERIOD);
+    assert_eq!(client.disburse_prepaid_rent(&lease.id), 0);
+    assert_eq!(client.get_remaining_prepaid(&lease.id), 2_000);
+
+    env.ledger()
+        .with_mut(|li| li.timestamp = START + 2 * PERIOD);
+    assert_eq!(client.get_arrears(&lease.id), 0);
+    assert_eq!(client.disburse(&lease.id, &lease.landlord), 3_000);
+
+    let agreement = client.get_agreement(&lease.id).unwrap();
+    assert_eq!(agreement.total_rent_paid, 3_000);
+    assert_eq!(agreement.payment_count, 3);
+    assert_eq!(balances.balance(&lease.landlord), 3_000);
+    assert_eq!(client.get_remaining_prepaid(&lease.id), 1_000);
+}
+
+#[test]
+fn test_prepay_rent_rejects_foreign_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let lease = create_active_lease(&env, &client);
+    let other = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+
+    let result = client.try_prepay_rent(&lease.id, &other, &2);
+    assert_eq!(result, Err(Ok(RentalError::TokenNotSupported)));
+}
+
+#[test]
+fn test_prepay_rent_rejects_overflowing_month_count() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let lease = create_active_lease(&env, &client);
+
+    let result = client.try_prepay_rent(&lease.id, &lease.token, &u32::MAX);
+    assert_eq!(result, Err(Ok(RentalError::InvalidAmount)));
+}
+
+#[test]
+fn test_remaining_prepaid_after_one_period_consumed() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let lease = create_active_lease(&env, &client);
+
+    assert_eq!(client.get_remaining_prepaid(&lease.id), 0);
+    client.prepay_rent(&lease.id, &lease.token, &2);
+    assert_eq!(client.get_remaining_prepaid(&lease.id), 2_000);
+
+    env.ledger().with_mut(|li| li.timestamp = START + PERIOD);
+    assert_eq!(client.disburse_prepaid_rent(&lease.id), 1);
+
+    assert_eq!(client.get_remaining_prepaid(&lease.id), 1_000);
+}
+
+#[test]
+fn test_early_termination_refunds_unused_prepaid_rent() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let lease = create_active_lease(&env, &client);
+    let balances = token::Client::new(&env, &lease.token);
+
+    client.prepay_rent(&lease.id, &lease.token, &3);
+    assert_eq!(balances.balance(&lease.tenant), 7_000);
+
+    // One prepaid period has come due when the break is approved
+    env.ledger().with_mut(|li| li.timestamp = START + PERIOD);
+    client.request_break(&lease.id, &lease.tenant);
+    client.approve_break(&lease.id, &lease.landlord, &0);
+
+    // The period due was drawn into escrow; the rest went back
+    assert_eq!(balances.balance(&lease.tenant), 9_000);
+    assert_eq!(client.get_escrow_balance(&lease.id), 1_000);
+    client.disburse(&lease.id, &lease.landlord);
+    assert_eq!(balances.balance(&lease.landlord), 1_000);
+    assert_eq!(balances.balance(&client.address), 0);
+    assert_eq!(client.get_remaining_prepaid(&lease.id), 0);
+    assert_eq!(
+        client.get_agreement(&lease.id).unwrap().total_rent_paid,
+        1_000
+    );
+}
+
+#[test]
+fn test_terminating_resolution_refunds_prepaid_rent() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let lease = create_active_lease(&env, &client);
+    let balances = token::Client::new(&env, &lease.token);
+
+    client.prepay_rent(&lease.id, &lease.token, &3);
+    client.raise_dispute(&lease.id, &lease.landlord);
+    client.resolve_dispute(&lease.id, &true);
+    client.acknowledge_resolution(&lease.id, &lease.tenant);
+    client.acknowledge_resolution(&lease.id, &lease.landlord);
+
+    assert_eq!(
+        client.get_agreement(&lease.id).unwrap().status,
+        AgreementStatus::Terminated
+    );
+    assert_eq!(client.get_remaining_prepaid(&lease.id), 0);
+    assert_eq!(balances.balance(&lease.tenant), 10_000);
+    assert_eq!(balances.balance(&client.address), 0);
+}
+
+#[test]
+fn test_event_seq_counts_agreement_events() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    assert_eq!(client.get_event_seq(&String::from_str(&env, "PREPAY_1")), 0);
+
+    // Created, submitted and signed
+    let lease = create_active_lease(&env, &client);
+    assert_eq!(client.get_event_seq(&lease.id), 3);
+
+    client.make_payment_with_token(&lease.id, &1000, &lease.token);
+    assert_eq!(client.get_event_seq(&lease.id), 4);
+
+    client.disburse(&lease.id, &lease.landlord);
+    assert_eq!(client.get_event_seq(&lease.id), 5);
+
+    client.request_break(&lease.id, &lease.tenant);
+    client.approve_break(&lease.id, &lease.landlord, &0);
+    assert_eq!(client.get_event_seq(&lease.id), 7);
+}
+
+#[test]
+fn test_release_escrow_rejects_foreign_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let lease = create_active_lease(&env, &client);
+
+    // Another token held by the contract, e.g. someone else's application fee
+    let other = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    token::StellarAssetClient::new(&env, &other).mint(&client.address, &5000);
+
+    assert_eq!(
+        client.try_release_escrow_with_token(&lease.id, &other),
+        Err(Ok(RentalError::TokenNotSupported))
+    );
+    assert_eq!(token::Client::new(&env, &other).balance(&lease.landlord), 0);
+    assert_eq!(
+        token::Client::new(&env, &other).balance(&client.address),
+        5000
+    );
+}
+
+#[test]
+fn test_disburse_requires_both_approvals() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let lease = create_lease(&env, &client, true);
+    let balances = token::Client::new(&env, &lease.token);
+
+    client.make_payment_with_token(&lease.id, &1000, &lease.token);
+    assert_eq!(client.get_escrow_balance(&lease.id), 1000);
+
+    client.approve_disbursement(&lease.id, &lease.landlord);
+    assert_eq!(
+        client.try_disburse(&lease.id, &lease.landlord),
+        Err(Ok(RentalError::InsufficientApprovals))
+    );
+    assert_eq!(
+        client.try_release_escrow_with_token(&lease.id, &lease.token),
+        Err(Ok(RentalError::InsufficientApprovals))
+    );
+    assert_eq!(balances.balance(&lease.landlord), 0);
+
+    client.approve_disbursement(&lease.id, &lease.tenant);
+    assert_eq!(client.disburse(&lease.id, &lease.tenant), 1000);
+
+    assert_eq!(balances.balance(&lease.landlord), 1000);
+    assert_eq!(client.get_escrow_balance(&lease.id), 0);
+    assert_eq!(client.get_disbursement_approvals(&lease.id).len(), 0);
+}
+
+#[test]
+fn test_disburse_without_dual_approval_mode() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let lease = create_active_lease(&env, &client);
+    let balances = token::Client::new(&env, &lease.token);
+
+    client.make_payment_with_token(&lease.id, &1000, &lease.token);
+    assert_eq!(client.disburse(&lease.id, &lease.landlord), 1000);
+    assert_eq!(balances.balance(&lease.landlord), 1000);
+}
+
+/// Create another Active lease for an existing landlord, paid in the same token.
+fn create_sibling_lease(env: &Env, client: &ContractClient<'_>, lease: &Lease, id: &str) -> String {
+    let id = String::from_str(env, id);
+    let tenant = Address::generate(env);
+    client.create_agreement(&AgreementInput {
+        agreement_id: id.clone(),
+        landlord: lease.landlord.clone(),
+        tenant: tenant.clone(),
+        agent: None,
+        terms: AgreementTerms {
+            monthly_rent: 1000,
+            security_deposit: 0,
+            start_date: START,
+            end_date: START + 12 * PERIOD,
+            agent_commission_rate: 0,
+        },
+        payment_token: lease.token.clone(),
+        token_decimals: 7,
+        metadata_uri: String::from_str(env, ""),
+        attributes: Vec::new(env),
+    });
+    client.submit_agreement(&lease.landlord, &id);
+    client.sign_agreement(&tenant, &id);
+    token::StellarAssetClient::new(env, &lease.token).mint(&tenant, &10_000);
+    id
+}
+
+#[test]
+fn test_sweep_disbursable_skips_escrow_inside_dispute_window() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let lease = create_active_lease(&env, &client);
+    let sibling = create_sibling_lease(&env, &client, &lease, "PREPAY_2");
+    let balances = token::Client::new(&env, &lease.token);
+    client.set_escrow_dispute_window(&(7 * 86_400));
+
+    // The first lease is paid at start; the second ten days later
+    client.make_payment_with_token(&lease.id, &1000, &lease.token);
+    env.ledger()
+        .with_mut(|li| li.timestamp = START + 10 * 86_400);
+    client.make_payment_with_token(&sibling, &1000, &lease.token);
+
+    let swept = client.sweep_disbursable(&lease.landlord, &lease.token, &0, &50);
+
+    assert_eq!(swept, 1000);
+    assert_eq!(balances.balance(&lease.landlord), 1000);
+    assert_eq!(client.get_escrow_balance(&lease.id), 0);
+    assert_eq!(client.get_escrow_balance(&sibling), 1000);
+
+    // A page starting at the second lease covers only it
+    env.ledger()
+        .with_mut(|li| li.timestamp = START + 20 * 86_400);
+    assert_eq!(
+        client.sweep_disbursable(&lease.landlord, &lease.token, &1, &50),
+        1000
+    );
+}
+
+#[test]
+fn test_dispute_window_applies_to_every_disbursement() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let lease = create_active_lease(&env, &client);
+    client.set_escrow_dispute_window(&(7 * 86_400));
+    client.make_payment_with_token(&lease.id, &1000, &lease.token);
+
+    assert_eq!(
+        client.try_disburse(&lease.id, &lease.landlord),
+        Err(Ok(RentalError::CooldownNotMet))
+    );
+    assert_eq!(
+        client.try_disburse_accumulated(&lease.id, &lease.token),
+        Err(Ok(RentalError::CooldownNotMet))
+    );
+    assert_eq!(
+        client.try_release_escrow_with_token(&lease.id, &lease.token),
+        Err(Ok(RentalError::CooldownNotMet))
+    );
+
+    env.ledger()
+        .with_mut(|li| li.timestamp = START + 7 * 86_400);
+    assert_eq!(client.disburse(&lease.id, &lease.landlord), 1000);
+}
+
+#[test]
+fn test_disputed_escrow_is_not_disbursed() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let lease = create_active_lease(&env, &client);
+    client.make_payment_with_token(&lease.id, &1000, &lease.token);
+    client.raise_dispute(&lease.id, &lease.tenant);
+
+    assert_eq!(
+        client.try_disburse(&lease.id, &lease.landlord),
+        Err(Ok(RentalError::InvalidState))
+    );
+    assert_eq!(client.get_escrow_balance(&lease.id), 1000);
+}
+
+#[test]
+fn test_disburse_accumulated_batches_payments() {
+    use soroban_sdk::testutils::Events;
+    use soroban_sdk::{IntoVal, Symbol};
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+
+    let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let id = String::from_str(&env, "BATCH_1");
+
+    env.ledger().with_mut(|li| li.timestamp = START);
+    client.create_agreement(&AgreementInput {
+        agreement_id: id.clone(),
+        landlord: landlord.clone(),
+        tenant: tenant.clone(),
+        agent: Some(agent.clone()),
+        terms: AgreementTerms {
+            monthly_rent: 1000,
+            security_deposit: 0,
+            start_date: START,
+            end_date: START + 12 * PERIOD,
+            agent_commission_rate: 10,
+        },
+        payment_token: token.clone(),
+        token_decimals: 7,
+        metadata_uri: String::from_str(&env, ""),
+        attributes: Vec::new(&env),
+    });
+    client.submit_agreement(&landlord, &id);
+    client.sign_agreement(&tenant, &id);
+    token::StellarAssetClient::new(&env, &token).mint(&tenant, &10_000);
+
+    for _ in 0..3 {
+        client.make_payment_with_token(&id, &1000, &token);
+    }
+
+    assert_eq!(client.disburse_accumulated(&id, &token), 3000);
+
+    // One transfer each to the agent and the landlord
+    let mut transfers = 0;
+    for (contract, topics, _) in env.events().all().iter() {
+        let name: Symbol = topics.get(0).unwrap().into_val(&env);
+        if contract == token && name == Symbol::new(&env, "transfer") {
+            transfers += 1;
+        }
+    }
+    assert_eq!(transfers, 2);
+
+    let balances = token::Client::new(&env, &token);
+    assert_eq!(balances.balance(&landlord), 2700);
+    assert_eq!(balances.balance(&agent), 300);
+    assert_eq!(client.get_escrow_breakdown(&id).rent, 0);
+}
+
+#[test]
+fn test_disburse_accumulated_rejects_other_tokens() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let lease = create_active_lease(&env, &client);
+    client.make_payment_with_token(&lease.id, &1000, &lease.token);
+
+    let other = Address::generate(&env);
+    assert_eq!(
+        client.try_disburse_accumulated(&lease.id, &other),
+        Err(Ok(RentalError::TokenNotSupported))
+    );
+}
+
+/// Data of the last rent payment event this contract published.
+fn last_payment_event(env: &Env, client: &ContractClient<'_>) -> soroban_sdk::Map<Symbol, Val> {
+    last_event(env, client, "payment_made_with_token")
+}
+
+fn last_event(
+    env: &Env,
+    client: &ContractClient<'_>,
+    event: &str,
+) -> soroban_sdk::Map<Symbol, Val> {
+    use soroban_sdk::testutils::Events;
+
+    let mut data = None;
+    for (contract, topics, value) in env.events().all().iter() {
+        let name: Symbol = topics.get(0).unwrap().into_val(env);
+        if contract == client.address && name == Symbol::new(env, event) {
+            data = Some(value);
+        }
+    }
+    data.unwrap().into_val(env)
+}
+
+#[test]
+fn test_payment_event_includes_amount_by_default() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let lease = create_active_lease(&env, &client);
+
+    client.make_payment_with_token(&lease.id, &1000, &lease.token);
+
+    let fields = last_payment_event(&env, &client);
+    let amount: i128 = fields
+        .get(Symbol::new(&env, "amount"))
+        .unwrap()
+        .into_val(&env);
+    let number: u32 = fields
+        .get(Symbol::new(&env, "payment_number"))
+        .unwrap()
+        .into_val(&env);
+    assert_eq!(amount, 1000);
+    assert_eq!(number, 1);
+}
+
+#[test]
+fn test_payment_event_hides_amount_when_opted_out() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let lease = create_active_lease(&env, &client);
+
+    client.set_emit_amounts(&lease.id, &false);
+    client.make_payment_with_token(&lease.id, &1000, &lease.token);
+    client.make_payment_with_token(&lease.id, &1000, &lease.token);
+
+    let fields = last_payment_event(&env, &client);
+    let amount: i128 = fields
+        .get(Symbol::new(&env, "amount"))
+        .unwrap()
+        .into_val(&env);
+    let number: u32 = fields
+        .get(Symbol::new(&env, "payment_number"))
+        .unwrap()
+        .into_val(&env);
+    assert_eq!(amount, 0);
+    assert_eq!(number, 2);
+    assert_eq!(client.get_escrow_balance(&lease.id), 2000);
+}
+
+#[test]
+fn test_prepaid_events_hide_amounts_when_opted_out() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let lease = create_active_lease(&env, &client);
+    let amount = |fields: soroban_sdk::Map<Symbol, Val>| -> i128 {
+        fields
+            .get(Symbol::new(&env, "amount"))
+            .unwrap()
+            .into_val(&env)
+    };
+
+    client.set_emit_amounts(&lease.id, &false);
+    client.prepay_rent(&lease.id, &lease.token, &3);
+    assert_eq!(amount(last_event(&env, &client, "rent_prepaid")), 0);
+
+    env.ledger().with_mut(|li| li.timestamp = START + PERIOD);
+    client.request_break(&lease.id, &lease.tenant);
+    client.approve_break(&lease.id, &lease.landlord, &0);
+    assert_eq!(amount(last_event(&env, &client, "prepaid_out")), 0);
+    assert_eq!(amount(last_event(&env, &client, "prepaid_back")), 0);
+
+    // Only the events are masked; the funds still moved
+    assert_eq!(client.get_escrow_balance(&lease.id), 1_000);
+    assert_eq!(client.get_remaining_prepaid(&lease.id), 0);
+}
+
+/// Token that calls back into the rental contract's `disburse` from inside
+/// a transfer once armed.
+#[contract]
+pub struct ReentrantToken;
+
+#[contractimpl]
+impl ReentrantToken {
+    pub fn mint(env: Env, to: Address, amount: i128) {
+        let mut balances = Self::balances(&env);
+        balances.set(to.clone(), Self::balance(env.clone(), to) + amount);
+        env.storage().instance().set(&0u32, &balances);
+    }
+
+    pub fn balance(env: Env, id: Address) -> i128 {
+        Self::balances(&env).get(id).unwrap_or(0)
+    }
+
+    pub fn arm(env: Env, target: Address, agreement_id: String, caller: Address) {
+        env.storage()
+            .instance()
+            .set(&1u32, &(target, agreement_id, caller));
+    }
+
+    /// Whether the reentrant call went through.
+    pub fn reentered(env: Env) -> bool {
+        env.storage().instance().get(&2u32).unwrap_or(false)
+    }
+
+    pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+        let mut balances = Self::balances(&env);
+        balances.set(from.clone(), balances.get(from).unwrap_or(0) - amount);
+        balances.set(to.clone(), balances.get(to).unwrap_or(0) + amount);
+        env.storage().instance().set(&0u32, &balances);
+
+        let armed: Option<(Address, String, Address)> = env.storage().instance().get(&1u32);
+        if let Some((target, agreement_id, caller)) = armed {
+            env.storage().instance().remove(&1u32);
+            let result = env.try_invoke_contract::<i128, soroban_sdk::Error>(
+                &target,
+                &Symbol::new(&env, "disburse"),
+                vec![&env, agreement_id.into_val(&env), caller.into_val(&env)],
+            );
+            env.storage().instance().set(&2u32, &result.is_ok());
+        }
+    }
+
+    fn balances(env: &Env) -> Map<Address, i128> {
+        env.storage().instance().get(&0u32).unwrap_or(Map::new(env))
+    }
+}
+
+#[test]
+fn test_reentrant_disburse_pays_out_once() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
+    let token_id = env.register(ReentrantToken, ());
+    let token = ReentrantTokenClient::new(&env, &token_id);
+    let id = String::from_str(&env, "REENTER_1");
+
+    env.ledger().with_mut(|li| li.timestamp = START);
+    client.create_agreement(&AgreementInput {
+        agreement_id: id.clone(),
+        landlord: landlord.clone(),
+        tenant: tenant.clone(),
+        agent: None,
+        terms: AgreementTerms {
+            monthly_rent: 1000,
+            security_deposit: 0,
+            start_date: START,
+            end_date: START + 12 * PERIOD,
+            agent_commission_rate: 0,
+        },
+        payment_token: token_id.clone(),
+        token_decimals: 7,
+        metadata_uri: String::from_str(&env, ""),
+        attributes: Vec::new(&env),
+    });
+    client.submit_agreement(&landlord, &id);
+    client.sign_agreement(&tenant, &id);
+    token.mint(&tenant, &2000);
+    client.make_payment_with_token(&id, &1000, &token_id);
+
+    token.arm(&client.address, &id, &landlord);
+    assert_eq!(client.disburse(&id, &landlord), 1000);
+
+    // The host refuses the nested call before it reaches the lock, whose own
+    // `Reentrancy` error is covered by the next test
+    assert!(!token.reentered());
+    assert_eq!(token.balance(&landlord), 1000);
+    assert_eq!(client.get_escrow_balance(&id), 0);
+}
+
+#[test]
+fn test_disburse_rejected_while_lock_held() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let lease = create_active_lease(&env, &client);
+    let balances = token::Client::new(&env, &lease.token);
+    client.make_payment_with_token(&lease.id, &1000, &lease.token);
+
+    env.as_contract(&client.address, || {
+        env.storage()
+            .instance()
+            .set(&crate::storage::DataKey::ReentrancyGuard, &true);
+    });
+
+    assert_eq!(
+        client.try_disburse(&lease.id, &lease.landlord),
+        Err(Ok(RentalError::Reentrancy))
+    );
+    assert_eq!(balances.balance(&lease.landlord), 0);
+    assert_eq!(client.get_escrow_balance(&lease.id), 1000);
+}
+
+/// Create an Active 12-period lease like `create_active_lease`, with an agent
+/// on a 10% commission and the landlord funded to prepay it.
+fn create_agent_lease(env: &Env, client: &ContractClient<'_>) -> (Lease, Address) {
+    let tenant = Address::generate(env);
+    let landlord = Address::generate(env);
+    let agent = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    let id = String::from_str(env, "AGENT_1");
+
+    env.ledger().with_mut(|li| li.timestamp = START);
+    client.create_agreement(&AgreementInput {
+        agreement_id: id.clone(),
+        landlord: landlord.clone(),
+        tenant: tenant.clone(),
+        agent: Some(agent.clone()),
+        terms: AgreementTerms {
+            monthly_rent: 1000,
+            security_deposit: 0,
+            start_date: START,
+            end_date: START + 12 * PERIOD,
+            agent_commission_rate: 10,
+        },
+        payment_token: token.clone(),
+        token_decimals: 7,
+        metadata_uri: String::from_str(env, ""),
+        attributes: Vec::new(env),
+    });
+    client.submit_agreement(&landlord, &id);
+    client.sign_agreement(&tenant, &id);
+
+    let minter = token::StellarAssetClient::new(env, &token);
+    minter.mint(&tenant, &10_000);
+    minter.mint(&landlord, &1_200);
+    (
+        Lease {
+            id,
+            tenant,
+            landlord,
+            token,
+        },
+        agent,
+    )
+}
+
+#[test]
+fn test_unearned_commission_refunded_on_early_termination() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let (lease, agent) = create_agent_lease(&env, &client);
+    let balance = token::Client::new(&env, &lease.token);
+
+    // 10% of 1000 rent over 12 periods
+    assert_eq!(client.fund_commission_reserve(&lease.id), 1_200);
+    assert_eq!(balance.balance(&lease.landlord), 0);
+    assert_eq!(
+        client.get_agreement(&lease.id).unwrap().commission_reserve,
+        1_200
+    );
+
+    // Break the lease halfway through the term
+    env.ledger()
+        .with_mut(|li| li.timestamp = START + 6 * PERIOD);
+    client.request_break(&lease.id, &lease.tenant);
+    client.approve_break(&lease.id, &lease.landlord, &0);
+
+    assert_eq!(balance.balance(&lease.landlord), 600);
+    assert_eq!(balance.balance(&agent), 600);
+    assert_eq!(balance.balance(&client.address), 0);
+    assert_eq!(
+        client.get_agreement(&lease.id).unwrap().commission_reserve,
+        0
+    );
+}
+
+#[test]
+fn test_commission_reserve_replaces_cut_on_disbursement() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let (lease, agent) = create_agent_lease(&env, &client);
+    let balance = token::Client::new(&env, &lease.token);
+    client.fund_commission_reserve(&lease.id);
+
+    assert_eq!(
+        client.try_fund_commission_reserve(&lease.id),
+        Err(Ok(RentalError::InvalidState))
+    );
+
+    client.make_payment_with_token(&lease.id, &1000, &lease.token);
+    client.disburse_accumulated(&lease.id, &lease.token);
+
+    // The agent was prepaid, so the landlord receives the full rent
+    assert_eq!(balance.balance(&lease.landlord), 1000);
+    assert_eq!(balance.balance(&agent), 0);
+}
+
+#[test]
+fn test_commission_reserve_rejected_once_rent_is_paid() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let (lease, _) = create_agent_lease(&env, &client);
+
+    client.make_payment_with_token(&lease.id, &1000, &lease.token);
+    assert_eq!(
+        client.try_fund_commission_reserve(&lease.id),
+        Err(Ok(RentalError::InvalidState))
+    );
+}
+
+#[test]
+fn test_escrow_breakdown_includes_commission_reserve() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let (lease, _) = create_agent_lease(&env, &client);
+    client.fund_commission_reserve(&lease.id);
+    client.make_payment_with_token(&lease.id, &1000, &lease.token);
+
+    let breakdown = client.get_escrow_breakdown(&lease.id);
+    assert_eq!(breakdown.commission_reserve, 1_200);
+    assert_eq!(breakdown.rent, 1000);
+    assert_eq!(
+        breakdown.total,
+        token::Client::new(&env, &lease.token).balance(&client.address)
+    );
+}
+
+#[test]
+fn test_every_disbursement_pays_agent_commission() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let (lease, agent) = create_agent_lease(&env, &client);
+    let balance = token::Client::new(&env, &lease.token);
+
+    client.make_payment_with_token(&lease.id, &1000, &lease.token);
+    assert_eq!(client.disburse(&lease.id, &lease.tenant), 1000);
+    assert_eq!(balance.balance(&agent), 100);
+    assert_eq!(balance.balance(&lease.landlord), 1_200 + 900);
+
+    client.make_payment_with_token(&lease.id, &1000, &lease.token);
+    client.release_escrow_with_token(&lease.id, &lease.token);
+    assert_eq!(balance.balance(&agent), 200);
+    assert_eq!(balance.balance(&lease.landlord), 1_200 + 1_800);
+    assert_eq!(client.get_escrow_balance(&lease.id), 0);
+}
@@ -109,6 +109,7 @@ fn test_create_agreement_with_token() {
             agent_commission_rate: 0,
         },
         payment_token: token_addr.clone(),
+        token_decimals: 7,
         metadata_uri: String::from_str(&env, "").clone(),
         attributes: Vec::new(&env).clone(),
     });
@@ -167,6 +168,7 @@ fn test_make_payment_with_different_token() {
             agent_commission_rate: 0,
         },
         payment_token: base_token.clone(),
+        token_decimals: 7,
         metadata_uri: String::from_str(&env, "").clone(),
         attributes: Vec::new(&env).clone(),
     });
@@ -186,8 +188,103 @@ fn test_make_payment_with_different_token() {
     assert_eq!(agreement.total_rent_paid, 1100);
 }
 
+#[test]
+fn test_get_tvl_reports_each_token_balance() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let admin = Address::generate(&env);
+    initialize_contract(&env, &client, &admin);
+
+    let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
+    let usdc = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let eurt = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+
+    for (id, token, rent) in [("TVL_1", &usdc, 1000i128), ("TVL_2", &eurt, 700i128)] {
+        let agreement_id = String::from_str(&env, id);
+        client.create_agreement(&AgreementInput {
+            agreement_id: agreement_id.clone(),
+            tenant: tenant.clone(),
+            landlord: landlord.clone(),
+            agent: None,
+            terms: AgreementTerms {
+                monthly_rent: rent,
+                security_deposit: 0,
+                start_date: 100,
+                end_date: 1000000,
+                agent_commission_rate: 0,
+            },
+            payment_token: token.clone(),
+            token_decimals: 7,
+            metadata_uri: String::from_str(&env, ""),
+            attributes: Vec::new(&env),
+        });
+        client.submit_agreement(&landlord, &agreement_id);
+        client.sign_agreement(&tenant, &agreement_id);
+
+        soroban_sdk::token::StellarAssetClient::new(&env, token).mint(&tenant, &rent);
+        client.make_payment_with_token(&agreement_id, &rent, token);
+    }
+
+    let mut tokens = Vec::new(&env);
+    tokens.push_back(usdc.clone());
+    tokens.push_back(eurt.clone());
+
+    let tvl = client.get_tvl(&tokens);
+    assert_eq!(tvl.len(), 2);
+    assert_eq!(tvl.get(0).unwrap(), (usdc, 1000));
+    assert_eq!(tvl.get(1).unwrap(), (eurt, 700));
+}
+
 // ─── Issue #651: Agreement Lifecycle Tests ────────────────────────────────
 
+#[test]
+fn test_format_amount_seven_decimals() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    let cases = [
+        (10_000_000, "1.0000000"),
+        (12_345_678, "1.2345678"),
+        (5_000, "0.0005000"),
+        (1, "0.0000001"),
+        (0, "0.0000000"),
+        (-25_000_000, "-2.5000000"),
+    ];
+    for (amount, expected) in cases {
+        assert_eq!(
+            client.format_amount(&amount, &7),
+            String::from_str(&env, expected)
+        );
+    }
+}
+
+#[test]
+fn test_format_amount_two_decimals() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    let cases = [
+        (150_050, "1500.50"),
+        (99, "0.99"),
+        (5, "0.05"),
+        (-7, "-0.07"),
+    ];
+    for (amount, expected) in cases {
+        assert_eq!(
+            client.format_amount(&amount, &2),
+            String::from_str(&env, expected)
+        );
+    }
+    assert_eq!(client.format_amount(&42, &0), String::from_str(&env, "42"));
+    assert_eq!(client.format_amount(&0, &0), String::from_str(&env, "0"));
+}
+
 #[test]
 fn test_create_agreement_success() {
     let env = Env::default();
@@ -214,6 +311,7 @@ fn test_create_agreement_success() {
             agent_commission_rate: 0,
         },
         payment_token: token_addr.clone(),
+        token_decimals: 7,
         metadata_uri: String::from_str(&env, "").clone(),
         attributes: Vec::new(&env).clone(),
     });
@@ -250,6 +348,7 @@ fn test_validate_agreement_monthly_rent_positive() {
             agent_commission_rate: 0,
         },
         payment_token: token_addr.clone(),
+        token_decimals: 7,
         metadata_uri: String::from_str(&env, "").clone(),
         attributes: Vec::new(&env).clone(),
     });
@@ -283,6 +382,7 @@ fn test_validate_agreement_security_deposit_nonnegative() {
             agent_commission_rate: 0,
         },
         payment_token: token_addr.clone(),
+        token_decimals: 7,
         metadata_uri: String::from_str(&env, "").clone(),
         attributes: Vec::new(&env).clone(),
     });
@@ -316,6 +416,7 @@ fn test_validate_agreement_start_before_end() {
             agent_commission_rate: 0,
         },
         payment_token: token_addr.clone(),
+        token_decimals: 7,
         metadata_uri: String::from_str(&env, "").clone(),
         attributes: Vec::new(&env).clone(),
     });
@@ -349,6 +450,7 @@ fn test_validate_agreement_commission_rate_max_100() {
             agent_commission_rate: 101,
         },
         payment_token: token_addr.clone(),
+        token_decimals: 7,
         metadata_uri: String::from_str(&env, "").clone(),
         attributes: Vec::new(&env).clone(),
     });
@@ -382,6 +484,7 @@ fn test_sign_agreement_transitions_to_pending() {
             agent_commission_rate: 0,
         },
         payment_token: token_addr.clone(),
+        token_decimals: 7,
         metadata_uri: String::from_str(&env, "").clone(),
         attributes: Vec::new(&env).clone(),
     });
@@ -419,6 +522,7 @@ fn test_cancel_agreement_from_draft() {
             agent_commission_rate: 0,
         },
         payment_token: token_addr.clone(),
+        token_decimals: 7,
         metadata_uri: String::from_str(&env, "").clone(),
         attributes: Vec::new(&env).clone(),
     });
@@ -465,6 +569,7 @@ fn test_duplicate_agreement_prevention() {
             agent_commission_rate: 0,
         },
         payment_token: token_addr.clone(),
+        token_decimals: 7,
         metadata_uri: String::from_str(&env, "").clone(),
         attributes: Vec::new(&env).clone(),
     };
@@ -616,6 +721,7 @@ fn test_create_agreement_with_token_stores_token() {
             agent_commission_rate: 0,
         },
         payment_token: token_addr.clone(),
+        token_decimals: 7,
         metadata_uri: String::from_str(&env, "").clone(),
         attributes: Vec::new(&env).clone(),
     });
@@ -623,3 +729,205 @@ fn test_create_agreement_with_token_stores_token() {
     let fetched_token = client.get_agreement_token(&agreement_id);
     assert_eq!(fetched_token, token_addr);
 }
+
+#[test]
+fn test_cancel_refunds_escrowed_rent_to_tenant() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let admin = Address::generate(&env);
+    initialize_contract(&env, &client, &admin);
+
+    let tenant = Address::generate(&env);
+    let landlord = Address::generate(&env);
+    let usdc = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let agreement_id = String::from_str(&env, "ESCROW_REFUND");
+
+    client.create_agreement(&AgreementInput {
+        agreement_id: agreement_id.clone(),
+        tenant: tenant.clone(),
+        landlord: landlord.clone(),
+        agent: None,
+        terms: AgreementTerms {
+            monthly_rent: 1000,
+            security_deposit: 0,
+            start_date: 100,
+            end_date: 1000000,
+            agent_commission_rate: 0,
+        },
+        payment_token: usdc.clone(),
+        token_decimals: 7,
+        metadata_uri: String::from_str(&env, ""),
+        attributes: Vec::new(&env),
+    });
+    client.submit_agreement(&landlord, &agreement_id);
+    client.sign_agreement(&tenant, &agreement_id);
+
+    soroban_sdk::token::StellarAssetClient::new(&env, &usdc).mint(&tenant, &1000);
+    client.make_payment_with_token(&agreement_id, &1000, &usdc);
+
+    // Rent is only taken once Active; put the agreement back in Pending to
+    // cancel it with rent still held
+    let mut agreement = client.get_agreement(&agreement_id).unwrap();
+    agreement.status = AgreementStatus::Pending;
+    env.as_contract(&client.address, || {
+        env.storage().persistent().set(
+            &crate::storage::DataKey::Agreement(agreement_id.clone()),
+            &agreement,
+        );
+    });
+
+    let balances = soroban_sdk::token::Client::new(&env, &usdc);
+    assert_eq!(client.get_escrow_balance(&agreement_id), 1000);
+    assert_eq!(balances.balance(&tenant), 0);
+
+    client.cancel_agreement(&landlord, &agreement_id);
+
+    assert_eq!(balances.balance(&tenant), 1000);
+    assert_eq!(balances.balance(&client.address), 0);
+    assert_eq!(client.get_escrow_balance(&agreement_id), 0);
+    assert_eq!(
+        client.get_agreement(&agreement_id).unwrap().status,
+        AgreementStatus::Cancelled
+    );
+}
+
+/// Active lease at 1000 rent in `usdc`, whose tenant holds 500 of a second
+/// token, `eurc`, worth two `usdc` each. Returns (id, tenant, landlord, usdc, eurc).
+fn create_two_token_lease(
+    env: &Env,
+    client: &ContractClient<'_>,
+) -> (String, Address, Address, Address, Address) {
+    let tenant = Address::generate(env);
+    let landlord = Address::generate(env);
+    let usdc = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    let eurc = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    let agreement_id = String::from_str(env, "TWO_TOKENS");
+
+    client.create_agreement(&AgreementInput {
+        agreement_id: agreement_id.clone(),
+        tenant: tenant.clone(),
+        landlord: landlord.clone(),
+        agent: None,
+        terms: AgreementTerms {
+            monthly_rent: 1000,
+            security_deposit: 0,
+            start_date: 100,
+            end_date: 1000000,
+            agent_commission_rate: 0,
+        },
+        payment_token: usdc.clone(),
+        token_decimals: 7,
+        metadata_uri: String::from_str(env, ""),
+        attributes: Vec::new(env),
+    });
+    client.submit_agreement(&landlord, &agreement_id);
+    client.sign_agreement(&tenant, &agreement_id);
+
+    client.set_exchange_rate(&eurc, &usdc, &2_000_000_000_000_000_000);
+    soroban_sdk::token::StellarAssetClient::new(env, &eurc).mint(&tenant, &500);
+    (agreement_id, tenant, landlord, usdc, eurc)
+}
+
+#[test]
+fn test_cancel_refunds_rent_paid_in_second_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let admin = Address::generate(&env);
+    initialize_contract(&env, &client, &admin);
+    let (agreement_id, tenant, landlord, _, eurc) = create_two_token_lease(&env, &client);
+    let balances = soroban_sdk::token::Client::new(&env, &eurc);
+
+    client.make_payment_with_token(&agreement_id, &500, &eurc);
+    assert_eq!(client.get_escrow_balance(&agreement_id), 0);
+    assert_eq!(client.get_token_escrow_balance(&agreement_id, &eurc), 500);
+
+    // Put the agreement back in Pending to cancel it with the rent still held
+    let mut agreement = client.get_agreement(&agreement_id).unwrap();
+    agreement.status = AgreementStatus::Pending;
+    env.as_contract(&client.address, || {
+        env.storage().persistent().set(
+            &crate::storage::DataKey::Agreement(agreement_id.clone()),
+            &agreement,
+        );
+    });
+    client.cancel_agreement(&landlord, &agreement_id);
+
+    assert_eq!(balances.balance(&tenant), 500);
+    assert_eq!(balances.balance(&client.address), 0);
+    assert_eq!(client.get_token_escrow_balance(&agreement_id, &eurc), 0);
+}
+
+#[test]
+fn test_rent_paid_in_second_token_disbursed_in_that_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let admin = Address::generate(&env);
+    initialize_contract(&env, &client, &admin);
+    let (agreement_id, _, landlord, usdc, eurc) = create_two_token_lease(&env, &client);
+    let balances = soroban_sdk::token::Client::new(&env, &eurc);
+
+    client.make_payment_with_token(&agreement_id, &500, &eurc);
+    assert_eq!(
+        client.try_release_escrow_with_token(&agreement_id, &Address::generate(&env)),
+        Err(Ok(RentalError::TokenNotSupported))
+    );
+
+    client.release_escrow_with_token(&agreement_id, &eurc);
+
+    assert_eq!(balances.balance(&landlord), 500);
+    assert_eq!(balances.balance(&client.address), 0);
+    assert_eq!(client.get_token_escrow_balance(&agreement_id, &eurc), 0);
+    assert_eq!(
+        soroban_sdk::token::Client::new(&env, &usdc).balance(&landlord),
+        0
+    );
+}
+
+#[test]
+fn test_amount_due_in_converts_rent_to_pay_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let admin = Address::generate(&env);
+    initialize_contract(&env, &client, &admin);
+
+    let token_a = Address::generate(&env);
+    let token_b = Address::generate(&env);
+    let agreement_id = String::from_str(&env, "QUOTE1");
+    client.create_agreement(&AgreementInput {
+        agreement_id: agreement_id.clone(),
+        tenant: Address::generate(&env),
+        landlord: Address::generate(&env),
+        agent: None,
+        terms: AgreementTerms {
+            monthly_rent: 1000,
+            security_deposit: 0,
+            start_date: 100,
+            end_date: 1000000,
+            agent_commission_rate: 0,
+        },
+        payment_token: token_a.clone(),
+        token_decimals: 7,
+        metadata_uri: String::from_str(&env, ""),
+        attributes: Vec::new(&env),
+    });
+
+    // Unquoted pairs have no figure until the admin sets a rate
+    assert_eq!(
+        client.try_amount_due_in(&agreement_id, &token_b),
+        Err(Ok(RentalError::RateNotFound))
+    );
+
+    client.set_exchange_rate(&token_a, &token_b, &2_500_000_000_000_000_000); // 2.5
+    assert_eq!(client.amount_due_in(&agreement_id, &token_b), 2500);
+    assert_eq!(client.amount_due_in(&agreement_id, &token_a), 1000);
+}
@@ -49,6 +49,7 @@ fn make_input(
             agent_commission_rate: 5,
         },
         payment_token: payment_token.clone(),
+        token_decimals: 7,
         metadata_uri: String::from_str(env, ""),
         attributes: Vec::new(env),
     }
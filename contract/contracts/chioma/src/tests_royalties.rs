@@ -50,6 +50,7 @@ fn create_agreement_with_token(
             agent_commission_rate: 0,
         },
         payment_token: token.clone(),
+        token_decimals: 7,
         metadata_uri: String::from_str(env, ""),
         attributes: Vec::new(env),
     });
@@ -79,6 +80,7 @@ fn test_set_and_get_royalty() {
             agent_commission_rate: 0,
         },
         payment_token: token.clone(),
+        token_decimals: 7,
         metadata_uri: String::from_str(&env, "").clone(),
         attributes: Vec::new(&env).clone(),
     });
@@ -115,6 +117,7 @@ fn test_calculate_royalty() {
             agent_commission_rate: 0,
         },
         payment_token: token.clone(),
+        token_decimals: 7,
         metadata_uri: String::from_str(&env, "").clone(),
         attributes: Vec::new(&env).clone(),
     });
@@ -150,6 +153,7 @@ fn test_transfer_with_royalty() {
             agent_commission_rate: 0,
         },
         payment_token: token_address.clone(),
+        token_decimals: 7,
         metadata_uri: String::from_str(&env, "").clone(),
         attributes: Vec::new(&env).clone(),
     });
@@ -211,6 +215,7 @@ fn test_invalid_royalty_percentage_fails() {
             agent_commission_rate: 0,
         },
         payment_token: token.clone(),
+        token_decimals: 7,
         metadata_uri: String::from_str(&env, "").clone(),
         attributes: Vec::new(&env).clone(),
     });
@@ -384,6 +384,14 @@ fn test_pause_and_unpause_success() {
     assert!(!client.is_paused());
 }
 
+#[test]
+fn test_is_paused_defaults_to_false_before_initialize() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    assert!(!client.is_paused());
+}
+
 #[test]
 fn test_pause_metadata_and_reasons() {
     let env = Env::default();
@@ -471,6 +479,7 @@ fn test_operations_blocked_when_paused() {
             agent_commission_rate: 0,
         },
         payment_token: Address::generate(&env),
+        token_decimals: 7,
         metadata_uri: String::from_str(&env, ""),
         attributes: Vec::new(&env),
     });
@@ -34,6 +34,65 @@ pub enum AgreementStatus {
     Cancelled,
     Terminated,
     Disputed,
+    /// Dispute decided; waiting for both parties to acknowledge the outcome.
+    ResolutionPending,
+}
+
+/// Which unpaid period a rent payment is credited to while the tenant is in arrears.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AllocationPolicy {
+    /// Clear the oldest overdue period first.
+    ArrearsFirst,
+    /// Cover the current period first, leaving older periods overdue.
+    CurrentFirst,
+}
+
+/// Day count used to pro-rate rent for a partial period.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ProrationBasis {
+    /// Every period counts as 30 days: `rent * days / 30`.
+    ThirtyDay,
+    /// Actual days over a 365-day year: `rent * 12 * days / 365`.
+    Actual,
+}
+
+/// A decided dispute awaiting acknowledgment from both parties.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeResolution {
+    /// Status the agreement moves to once both parties acknowledge.
+    pub outcome: AgreementStatus,
+    pub landlord_acknowledged: bool,
+    pub tenant_acknowledged: bool,
+}
+
+/// Contract-wide totals for dashboards.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractStats {
+    /// Agreements created and not yet closed.
+    pub total_agreements: u32,
+    /// Agreements currently in the Active status.
+    pub active_agreements: u32,
+    /// Rent payments recorded, including prepaid and deposit-funded periods.
+    pub total_payments: u32,
+}
+
+/// One landlord's totals across their agreements in a single payment token.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PortfolioSummary {
+    pub agreement_count: u32,
+    /// Agreements currently in the Active status.
+    pub active_count: u32,
+    /// Rent paid across the agreements, in the summary's token.
+    pub total_income: i128,
+    /// Rent owed right now on Active agreements.
+    pub total_arrears: i128,
+    /// Security deposits currently held.
+    pub deposits_held: i128,
 }
 
 // ─── Multi-Sig Types ──────────────────────────────────────────────────────────
@@ -99,11 +158,141 @@ pub struct RentAgreement {
     pub status: AgreementStatus,
     pub total_rent_paid: i128,
     pub payment_count: u32,
+    pub created_at: u64,
     pub signed_at: Option<u64>,
+    /// When the agreement was settled and marked Completed.
+    pub completed_at: Option<u64>,
     pub payment_token: Address,
+    pub token_decimals: u32,
+    /// Whether the security deposit returns to the tenant at settlement.
+    pub deposit_refundable: bool,
+    /// Fee owed by the tenant for ending the lease before `end_date`.
+    pub early_termination_fee: i128,
+    /// Whether escrowed rent is only disbursed once landlord and tenant both approve.
+    pub dual_release_approval: bool,
+    /// Whether settlement applies the held deposit to the final period's unpaid rent.
+    pub apply_deposit_to_last: bool,
+    /// Whether funding the deposit on a Pending agreement activates it immediately.
+    pub auto_activate_on_funding: bool,
+    /// Whether the tenant has asked to break the lease, pending landlord approval.
+    pub break_requested: bool,
+    /// When the pending break was requested; its notice period runs from here.
+    pub break_requested_at: Option<u64>,
+    /// Whether rent payment events carry the paid amount; off hides it for privacy.
+    pub emit_amounts: bool,
+    /// Party backing a deposit-free lease, chargeable for the tenant's arrears.
+    pub guarantor: Option<Address>,
+    /// Whether listing queries show the agreement to non-parties.
+    pub public: bool,
+    /// Day count used when rent is pro-rated for a partial period.
+    pub proration_basis: ProrationBasis,
+    /// Grouping set at creation: residential, commercial or short_term.
+    pub category: String,
+    /// Which period a payment made while in arrears is credited to.
+    pub allocation_policy: AllocationPolicy,
+    /// Periods credited ahead of older unpaid ones under `CurrentFirst`.
+    pub periods_paid_ahead: Vec<u32>,
+    /// Full-term agent commission prepaid by the landlord, held until the
+    /// lease ends and then split pro rata between agent and landlord.
+    pub commission_reserve: i128,
     pub next_payment_due: u64,
     pub metadata_uri: String,
     pub attributes: Vec<Attribute>,
+    /// Property registry id this lease is for, once linked by the landlord.
+    pub property_id: Option<String>,
+}
+
+/// Deployment-wide guardrails on agreements, set by the admin.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AgreementPolicy {
+    /// Rent above which `amend_rent` also requires the agent's authorization (0 = off).
+    pub coauth_threshold: i128,
+    /// Lowest agent commission rate accepted on new agreements.
+    pub min_commission_bps: u32,
+    /// Highest agent commission rate accepted on new agreements.
+    pub max_commission_bps: u32,
+    /// Largest rent increase `amend_rent` accepts, in bps of the prior rent (None = uncapped).
+    pub max_increase_bps: Option<u32>,
+    /// Seconds after completion during which a held deposit cannot be released (0 = off).
+    pub inspection_period: u64,
+    /// Shortest lease term, in seconds, accepted on new agreements (0 = no minimum).
+    pub min_duration: u64,
+    /// Unpaid periods after which the landlord may terminate a lease (0 = off).
+    pub max_arrears_periods: u32,
+    /// Seconds after a rent payment during which its escrow cannot be swept (0 = off).
+    pub escrow_dispute_window: u64,
+    /// Unit every rent must be a multiple of (0 or 1 = any amount).
+    pub tick_size: i128,
+    /// Seconds a dispute stays with its arbiter before a party may escalate it (0 = off).
+    pub dispute_escalation_window: u64,
+    /// Smallest security deposit accepted, quoted at 7 decimals (0 = no minimum).
+    pub min_deposit: i128,
+    /// Whether new agreements must start no earlier than their creation time.
+    pub require_future_start: bool,
+    /// Whether creating an agreement needs the landlord's authorization too.
+    pub require_landlord_auth: bool,
+    /// Notice, in seconds, after which a requested break may be finalized
+    /// without landlord approval (0 = breaks need approval).
+    pub notice_period: u64,
+}
+
+/// Where an agreement's security deposit is held and how much.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DepositCustody {
+    pub amount: i128,
+    /// The contract itself, or the vault configured when the deposit was made.
+    pub custodian: Address,
+}
+
+/// Rent a tenant has paid ahead into escrow, drawn one period at a time.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PrepaidRent {
+    /// Prepaid rent not yet disbursed to the landlord.
+    pub amount: i128,
+    /// Next rent period (1-based) the prepaid balance will pay for.
+    pub next_period: u32,
+}
+
+/// Application fee a prospective tenant has paid toward a landlord's property.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ApplicationFee {
+    pub landlord: Address,
+    pub token: Address,
+    pub amount: i128,
+    /// When the fee was last paid into.
+    pub paid_at: u64,
+}
+
+/// Funds the contract holds for an agreement, by earmark.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowBreakdown {
+    /// Security deposit held by the contract (excludes vault custody).
+    pub deposit: i128,
+    /// Rent paid and awaiting disbursement to the landlord.
+    pub rent: i128,
+    /// Rent prepaid for future periods.
+    pub prepaid_rent: i128,
+    /// Agent commission prepaid by the landlord and not yet settled.
+    pub commission_reserve: i128,
+    /// Sum of all buckets.
+    pub total: i128,
+}
+
+/// Property record as returned by the property registry's `get_property`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PropertyDetails {
+    pub property_id: String,
+    pub landlord: Address,
+    pub metadata_hash: String,
+    pub verified: bool,
+    pub registered_at: u64,
+    pub verified_at: Option<u64>,
 }
 
 #[contracttype]
@@ -201,6 +390,8 @@ pub struct DepositInterestConfig {
     pub annual_rate: u32,
     pub compounding_frequency: CompoundingFrequency,
     pub interest_recipient: InterestRecipient,
+    /// Seconds the deposit must be held before interest starts accruing.
+    pub interest_grace: u64,
 }
 
 /// A single interest-accrual snapshot.
@@ -223,6 +414,8 @@ pub struct DepositInterest {
     pub total_with_interest: i128,
     pub last_accrual_date: u64,
     pub accrual_history: Vec<InterestAccrual>,
+    /// When interest tracking started; the grace period runs from here.
+    pub held_since: u64,
 }
 
 #[contracttype]
@@ -302,6 +495,8 @@ pub struct AgreementInput {
     pub agent: Option<Address>,
     pub terms: AgreementTerms,
     pub payment_token: Address,
+    /// Decimal places of `payment_token`, used by clients to format amounts (0–18).
+    pub token_decimals: u32,
     pub metadata_uri: String,
     pub attributes: Vec<Attribute>,
 }
@@ -1,4 +1,4 @@
-use soroban_sdk::{contracttype, Address, Env, Map, String};
+use soroban_sdk::{contracttype, token, Address, Env, Map, String};
 
 use crate::errors::DisputeError;
 use crate::events;
@@ -6,7 +6,8 @@ use crate::rate_limit;
 use crate::storage::DataKey;
 use crate::types::{
     AppealStatus, AppealVote, Arbiter, ArbiterStats, ContractState, Dispute, DisputeAppeal,
-    DisputeOutcome, TimeoutConfig, Vote, VotingWeight, WeightedDisputeVotes, WeightedVote,
+    DisputeBond, DisputeOutcome, PostedBond, TimeoutConfig, Vote, VotingWeight,
+    WeightedDisputeVotes, WeightedVote,
 };
 
 const APPEAL_WINDOW_SECONDS: u64 = 7 * 24 * 60 * 60;
@@ -57,6 +58,81 @@ pub fn set_timeout_config(
     Ok(())
 }
 
+pub fn get_dispute_bond(env: &Env) -> Option<DisputeBond> {
+    env.storage().instance().get(&DataKey::DisputeBond)
+}
+
+/// Require raisers to post `amount` of `token` as a bond; zero disables it.
+pub fn set_dispute_bond(
+    env: &Env,
+    admin: Address,
+    token: Address,
+    amount: i128,
+) -> Result<(), DisputeError> {
+    let state: ContractState = env
+        .storage()
+        .instance()
+        .get(&DataKey::State)
+        .ok_or(DisputeError::NotInitialized)?;
+
+    admin.require_auth();
+    if admin != state.admin {
+        return Err(DisputeError::Unauthorized);
+    }
+
+    if amount < 0 {
+        return Err(DisputeError::InvalidBondConfig);
+    }
+
+    if amount == 0 {
+        env.storage().instance().remove(&DataKey::DisputeBond);
+    } else {
+        env.storage()
+            .instance()
+            .set(&DataKey::DisputeBond, &DisputeBond { token, amount });
+    }
+    env.storage().instance().extend_ttl(500000, 500000);
+    Ok(())
+}
+
+pub fn get_posted_bond(env: &Env, agreement_id: String) -> Option<PostedBond> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PostedBond(agreement_id))
+}
+
+/// Return a posted bond to the raiser if they won, otherwise forfeit it to
+/// the other party.
+fn settle_bond(env: &Env, agreement_id: &String, outcome: &DisputeOutcome) {
+    let key = DataKey::PostedBond(agreement_id.clone());
+    let bond: PostedBond = match env.storage().persistent().get(&key) {
+        Some(bond) => bond,
+        None => return,
+    };
+
+    let raiser_won = bond.raised_by_landlord == (*outcome == DisputeOutcome::FavorLandlord);
+    let recipient = if raiser_won {
+        bond.raiser.clone()
+    } else {
+        bond.counterparty.clone()
+    };
+
+    token::Client::new(env, &bond.token).transfer(
+        &env.current_contract_address(),
+        &recipient,
+        &bond.amount,
+    );
+    env.storage().persistent().remove(&key);
+
+    events::bond_settled(
+        env,
+        agreement_id.clone(),
+        recipient,
+        bond.amount,
+        raiser_won,
+    );
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum AgreementStatus {
@@ -151,6 +227,29 @@ pub fn add_arbiter(env: &Env, admin: Address, arbiter: Address) -> Result<(), Di
     Ok(())
 }
 
+/// Return the agreement ids of all disputes that have not yet been resolved.
+pub fn get_open_disputes(env: &Env) -> soroban_sdk::Vec<String> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::OpenDisputes)
+        .unwrap_or(soroban_sdk::Vec::new(env))
+}
+
+fn save_open_disputes(env: &Env, open: &soroban_sdk::Vec<String>) {
+    env.storage().persistent().set(&DataKey::OpenDisputes, open);
+    env.storage()
+        .persistent()
+        .extend_ttl(&DataKey::OpenDisputes, 500000, 500000);
+}
+
+fn mark_dispute_closed(env: &Env, agreement_id: &String) {
+    let mut open = get_open_disputes(env);
+    if let Some(index) = open.first_index_of(agreement_id) {
+        open.remove(index);
+        save_open_disputes(env, &open);
+    }
+}
+
 pub fn raise_dispute(
     env: &Env,
     raiser: Address,
@@ -210,6 +309,42 @@ pub fn raise_dispute(
     env.storage().persistent().set(&key, &dispute);
     env.storage().persistent().extend_ttl(&key, 500000, 500000);
 
+    let mut open = get_open_disputes(env);
+    open.push_back(agreement_id.clone());
+    save_open_disputes(env, &open);
+
+    // Hold the bond until the dispute resolves
+    if let Some(bond) = get_dispute_bond(env) {
+        token::Client::new(env, &bond.token).transfer(
+            &raiser,
+            env.current_contract_address(),
+            &bond.amount,
+        );
+
+        let raised_by_landlord = raiser == agreement.landlord;
+        let counterparty = if raised_by_landlord {
+            agreement.tenant.clone()
+        } else {
+            agreement.landlord.clone()
+        };
+        let bond_key = DataKey::PostedBond(agreement_id.clone());
+        env.storage().persistent().set(
+            &bond_key,
+            &PostedBond {
+                raiser: raiser.clone(),
+                counterparty,
+                raised_by_landlord,
+                token: bond.token,
+                amount: bond.amount,
+            },
+        );
+        env.storage()
+            .persistent()
+            .extend_ttl(&bond_key, 500000, 500000);
+
+        events::bond_posted(env, agreement_id.clone(), raiser, bond.amount);
+    }
+
     events::dispute_raised(env, agreement_id, details_hash);
 
     Ok(())
@@ -324,6 +459,9 @@ pub fn resolve_dispute(env: &Env, agreement_id: String) -> Result<DisputeOutcome
         DisputeOutcome::FavorTenant
     };
 
+    settle_bond(env, &agreement_id, &outcome);
+    mark_dispute_closed(env, &agreement_id);
+
     events::dispute_resolved(
         env,
         agreement_id,
@@ -371,6 +509,9 @@ pub fn resolve_dispute_on_timeout(
         DisputeOutcome::FavorTenant
     };
 
+    settle_bond(env, &agreement_id, &outcome);
+    mark_dispute_closed(env, &agreement_id);
+
     events::dispute_timeout(env, agreement_id.clone());
     events::dispute_resolved(
         env,
@@ -979,6 +1120,9 @@ pub fn resolve_dispute_weighted(
         .persistent()
         .extend_ttl(&dispute_key, 500000, 500000);
 
+    settle_bond(env, &dispute_id, &outcome);
+    mark_dispute_closed(env, &dispute_id);
+
     events::dispute_resolved_by_weight(env, dispute_id, outcome.clone(), total_weight);
 
     Ok(outcome)
@@ -77,6 +77,25 @@ pub struct DisputeTimeout {
     pub agreement_id: String,
 }
 
+#[contractevent(topics = ["bond_posted"])]
+pub struct BondPosted {
+    #[topic]
+    pub agreement_id: String,
+    #[topic]
+    pub raiser: Address,
+    pub amount: i128,
+}
+
+#[contractevent(topics = ["bond_settled"])]
+pub struct BondSettled {
+    #[topic]
+    pub agreement_id: String,
+    #[topic]
+    pub recipient: Address,
+    pub amount: i128,
+    pub returned: bool,
+}
+
 pub(crate) fn contract_initialized(env: &Env, admin: Address, min_votes_required: u32) {
     ContractInitialized {
         admin,
@@ -146,6 +165,31 @@ pub(crate) fn dispute_timeout(env: &Env, agreement_id: String) {
     DisputeTimeout { agreement_id }.publish(env);
 }
 
+pub(crate) fn bond_posted(env: &Env, agreement_id: String, raiser: Address, amount: i128) {
+    BondPosted {
+        agreement_id,
+        raiser,
+        amount,
+    }
+    .publish(env);
+}
+
+pub(crate) fn bond_settled(
+    env: &Env,
+    agreement_id: String,
+    recipient: Address,
+    amount: i128,
+    returned: bool,
+) {
+    BondSettled {
+        agreement_id,
+        recipient,
+        amount,
+        returned,
+    }
+    .publish(env);
+}
+
 // ── Weighted Voting Events ─────────────────────────────────────────────────
 
 #[contractevent(topics = ["weighted_vote_cast"])]
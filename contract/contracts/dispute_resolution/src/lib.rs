@@ -17,16 +17,18 @@ mod tests_rate_limit;
 
 pub use dispute::{
     add_arbiter, calculate_voting_weight, cancel_appeal, create_appeal, get_appeal, get_arbiter,
-    get_arbiter_count, get_dispute, get_dispute_votes_weighted, get_timeout_config, get_vote,
-    get_voting_weight, raise_dispute, resolve_appeal, resolve_dispute, resolve_dispute_on_timeout,
-    resolve_dispute_weighted, set_arbiter_stats, set_timeout_config, vote_on_appeal,
-    vote_on_dispute, vote_on_dispute_weighted,
+    get_arbiter_count, get_dispute, get_dispute_bond, get_dispute_votes_weighted, get_posted_bond,
+    get_timeout_config, get_vote, get_voting_weight, raise_dispute, resolve_appeal,
+    resolve_dispute, resolve_dispute_on_timeout, resolve_dispute_weighted, set_arbiter_stats,
+    set_dispute_bond, set_timeout_config, vote_on_appeal, vote_on_dispute,
+    vote_on_dispute_weighted,
 };
 pub use errors::DisputeError;
 pub use storage::DataKey;
 pub use types::{
     AppealStatus, AppealVote, Arbiter, ArbiterStats, ContractState, Dispute, DisputeAppeal,
-    DisputeOutcome, TimeoutConfig, Vote, VotingWeight, WeightedDisputeVotes, WeightedVote,
+    DisputeBond, DisputeOutcome, PostedBond, TimeoutConfig, Vote, VotingWeight,
+    WeightedDisputeVotes, WeightedVote,
 };
 
 #[contract]
@@ -164,6 +166,14 @@ impl DisputeResolutionContract {
         dispute::resolve_dispute_on_timeout(&env, agreement_id)
     }
 
+    /// Get the agreement ids of all disputes awaiting resolution.
+    ///
+    /// # Returns
+    /// * `Vec<String>` - Agreement ids in the order their disputes were raised
+    pub fn get_open_disputes(env: Env) -> Vec<String> {
+        dispute::get_open_disputes(&env)
+    }
+
     /// Get information about a specific dispute.
     ///
     /// # Arguments
@@ -218,6 +228,33 @@ impl DisputeResolutionContract {
         dispute::get_timeout_config(&env)
     }
 
+    /// Require parties to post a bond of `amount` in `token` when raising a
+    /// dispute (admin only). The bond is returned to the raiser if the dispute
+    /// resolves in their favor and forfeited to the other party otherwise.
+    /// An `amount` of zero disables the bond.
+    ///
+    /// # Errors
+    /// * `NotInitialized` - If the contract hasn't been initialized
+    /// * `Unauthorized` - If caller is not the admin
+    /// * `InvalidBondConfig` - If `amount` is negative
+    pub fn set_dispute_bond(
+        env: Env,
+        admin: Address,
+        token: Address,
+        amount: i128,
+    ) -> Result<(), DisputeError> {
+        dispute::set_dispute_bond(&env, admin, token, amount)
+    }
+
+    pub fn get_dispute_bond(env: Env) -> Option<DisputeBond> {
+        dispute::get_dispute_bond(&env)
+    }
+
+    /// Get the bond held for an open dispute, if any.
+    pub fn get_posted_bond(env: Env, agreement_id: String) -> Option<PostedBond> {
+        dispute::get_posted_bond(&env, agreement_id)
+    }
+
     pub fn create_appeal(
         env: Env,
         appellant: Address,
@@ -16,6 +16,11 @@ pub enum DataKey {
     AppealFeePaid(String),
     AppealFeeRefunded(String),
     TimeoutConfig,
+    // Dispute bonds
+    DisputeBond,
+    PostedBond(String),
+    // Agreement ids of unresolved disputes
+    OpenDisputes,
     // Weighted voting
     ArbiterStats(Address),
     WeightedVote(String, Address),
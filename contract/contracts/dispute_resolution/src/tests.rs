@@ -1152,3 +1152,165 @@ fn test_dispute_timeout_not_reached() {
     let result = client.try_resolve_dispute_on_timeout(&agreement_id);
     assert_eq!(result, Err(Ok(DisputeError::TimeoutNotReached)));
 }
+
+// ── Dispute Bond Tests ─────────────────────────────────────────────────────
+
+/// Register the mock chioma contract with an active agreement between a
+/// fresh tenant and landlord.
+fn setup_mock_chioma(env: &Env) -> (Address, Address, Address) {
+    let mock_chioma = env.register(MockChiomaContract, ());
+    let tenant = Address::generate(env);
+    let landlord = Address::generate(env);
+
+    let agreement = RentAgreement {
+        agreement_id: String::from_str(env, "agreement_001"),
+        landlord: landlord.clone(),
+        tenant: tenant.clone(),
+        agent: None,
+        monthly_rent: 1000,
+        security_deposit: 2000,
+        start_date: 0,
+        end_date: 1_000_000,
+        agent_commission_rate: 0,
+        status: crate::dispute::AgreementStatus::Active,
+        total_rent_paid: 0,
+        payment_count: 0,
+        signed_at: None,
+        payment_token: Address::generate(env),
+        next_payment_due: 0,
+        payment_history: soroban_sdk::Map::new(env),
+    };
+    env.as_contract(&mock_chioma, || {
+        env.storage().instance().set(&0u32, &agreement);
+    });
+
+    (mock_chioma, tenant, landlord)
+}
+
+/// Set up a contract with a 500-unit dispute bond, three arbiters, and a
+/// funded tenant.
+fn setup_bonded_dispute(
+    env: &Env,
+) -> (
+    DisputeResolutionContractClient<'_>,
+    soroban_sdk::token::Client<'_>,
+    Address,
+    Address,
+    soroban_sdk::Vec<Address>,
+) {
+    let client = create_contract(env);
+    let admin = Address::generate(env);
+    let (mock_chioma, tenant, landlord) = setup_mock_chioma(env);
+
+    env.mock_all_auths();
+    client.initialize(&admin, &3, &mock_chioma);
+
+    let sac = env.register_stellar_asset_contract_v2(Address::generate(env));
+    soroban_sdk::token::StellarAssetClient::new(env, &sac.address()).mint(&tenant, &1000);
+    client.set_dispute_bond(&admin, &sac.address(), &500);
+
+    let mut arbiters = soroban_sdk::Vec::new(env);
+    for _ in 0..3 {
+        let arbiter = Address::generate(env);
+        client.add_arbiter(&admin, &arbiter);
+        arbiters.push_back(arbiter);
+    }
+
+    let token = soroban_sdk::token::Client::new(env, &sac.address());
+    (client, token, tenant, landlord, arbiters)
+}
+
+#[test]
+fn test_raise_dispute_posts_bond() {
+    let env = Env::default();
+    let (client, token, tenant, _landlord, _arbiters) = setup_bonded_dispute(&env);
+    let agreement_id = String::from_str(&env, "agreement_001");
+
+    client.raise_dispute(&tenant, &agreement_id, &String::from_str(&env, "QmBond"));
+
+    assert_eq!(token.balance(&tenant), 500);
+    assert_eq!(token.balance(&client.address), 500);
+    let bond = client.get_posted_bond(&agreement_id).unwrap();
+    assert_eq!(bond.raiser, tenant);
+    assert_eq!(bond.amount, 500);
+}
+
+#[test]
+fn test_bond_returned_when_raiser_wins() {
+    let env = Env::default();
+    let (client, token, tenant, landlord, arbiters) = setup_bonded_dispute(&env);
+    let agreement_id = String::from_str(&env, "agreement_001");
+
+    client.raise_dispute(&tenant, &agreement_id, &String::from_str(&env, "QmBond"));
+    for arbiter in arbiters.iter() {
+        client.vote_on_dispute(&arbiter, &agreement_id, &false);
+    }
+
+    let outcome = client.resolve_dispute(&agreement_id);
+    assert_eq!(outcome, DisputeOutcome::FavorTenant);
+    assert_eq!(token.balance(&tenant), 1000);
+    assert_eq!(token.balance(&landlord), 0);
+    assert_eq!(token.balance(&client.address), 0);
+    assert!(client.get_posted_bond(&agreement_id).is_none());
+}
+
+#[test]
+fn test_bond_forfeited_when_raiser_loses() {
+    let env = Env::default();
+    let (client, token, tenant, landlord, arbiters) = setup_bonded_dispute(&env);
+    let agreement_id = String::from_str(&env, "agreement_001");
+
+    client.raise_dispute(&tenant, &agreement_id, &String::from_str(&env, "QmBond"));
+    for arbiter in arbiters.iter() {
+        client.vote_on_dispute(&arbiter, &agreement_id, &true);
+    }
+
+    let outcome = client.resolve_dispute(&agreement_id);
+    assert_eq!(outcome, DisputeOutcome::FavorLandlord);
+    assert_eq!(token.balance(&tenant), 500);
+    assert_eq!(token.balance(&landlord), 500);
+    assert_eq!(token.balance(&client.address), 0);
+}
+
+#[test]
+fn test_open_disputes_empty_when_none_raised() {
+    let env = Env::default();
+    let (client, _token, _tenant, _landlord, _arbiters) = setup_bonded_dispute(&env);
+
+    assert!(client.get_open_disputes().is_empty());
+}
+
+#[test]
+fn test_open_disputes_tracks_raised_and_resolved() {
+    let env = Env::default();
+    let (client, token, tenant, landlord, arbiters) = setup_bonded_dispute(&env);
+    soroban_sdk::token::StellarAssetClient::new(&env, &token.address).mint(&landlord, &500);
+    let first = String::from_str(&env, "agreement_001");
+    let second = String::from_str(&env, "agreement_002");
+
+    client.raise_dispute(&tenant, &first, &String::from_str(&env, "QmFirst"));
+    client.raise_dispute(&landlord, &second, &String::from_str(&env, "QmSecond"));
+    assert_eq!(
+        client.get_open_disputes(),
+        soroban_sdk::vec![&env, first.clone(), second.clone()]
+    );
+
+    for arbiter in arbiters.iter() {
+        client.vote_on_dispute(&arbiter, &first, &false);
+    }
+    client.resolve_dispute(&first);
+
+    assert_eq!(client.get_open_disputes(), soroban_sdk::vec![&env, second]);
+}
+
+#[test]
+fn test_set_dispute_bond_rejects_negative_amount() {
+    let env = Env::default();
+    let client = create_contract(&env);
+    let admin = Address::generate(&env);
+    env.mock_all_auths();
+    client.initialize(&admin, &3, &Address::generate(&env));
+
+    let result = client.try_set_dispute_bond(&admin, &Address::generate(&env), &-1);
+    assert_eq!(result, Err(Ok(DisputeError::InvalidBondConfig)));
+}
@@ -73,6 +73,26 @@ pub struct TimeoutConfig {
     pub payment_timeout_days: u64,
 }
 
+/// Admin-configured bond a party must post to raise a dispute.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeBond {
+    pub token: Address,
+    pub amount: i128,
+}
+
+/// Bond held in escrow for an open dispute.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PostedBond {
+    pub raiser: Address,
+    /// The other party to the agreement; receives the bond if the raiser loses
+    pub counterparty: Address,
+    pub raised_by_landlord: bool,
+    pub token: Address,
+    pub amount: i128,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Arbiter {
@@ -113,8 +113,71 @@ impl DisputeHandler {
         EscrowStorage::clear_approvals(&env, &escrow_id);
 
         // INTERACTIONS: Token transfer from escrow contract to release target
-        let token_client = token::Client::new(&env, &escrow.token);
-        token_client.transfer(&env.current_contract_address(), &release_to, &escrow.amount);
+        if escrow.amount > 0 {
+            let token_client = token::Client::new(&env, &escrow.token);
+            token_client.transfer(&env.current_contract_address(), &release_to, &escrow.amount);
+        }
+
+        Ok(())
+    }
+
+    /// Contest a damage deduction (depositor only).
+    /// Must be called within the contest window following the deduction.
+    ///
+    /// CHECKS:
+    /// - Escrow must exist and be Released
+    /// - Caller must be depositor
+    /// - A deduction must have been recorded
+    /// - Contest window must still be open
+    /// - Dispute reason must not be empty
+    ///
+    /// EFFECTS:
+    /// - Update escrow status to Disputed
+    /// - Store dispute reason and timestamp
+    /// - Emit DeductionContested event
+    pub fn contest_deduction(
+        env: Env,
+        escrow_id: BytesN<32>,
+        caller: Address,
+        reason: String,
+    ) -> Result<(), EscrowError> {
+        // CHECKS: Get and validate escrow
+        let mut escrow = EscrowStorage::get(&env, &escrow_id).ok_or(EscrowError::EscrowNotFound)?;
+
+        // Verify caller is depositor
+        AccessControl::is_depositor(&escrow, &caller)?;
+
+        // Only a settled escrow can have its deduction contested
+        if escrow.status != EscrowStatus::Released {
+            return Err(EscrowError::InvalidState);
+        }
+
+        // Authorize the contest
+        caller.require_auth();
+
+        if reason.is_empty() {
+            return Err(EscrowError::EmptyDisputeReason);
+        }
+
+        let deduction =
+            EscrowStorage::get_deduction(&env, &escrow_id).ok_or(EscrowError::DeductionNotFound)?;
+
+        let window_seconds = EscrowStorage::DEDUCTION_CONTEST_WINDOW_DAYS.saturating_mul(86_400);
+        let deadline = deduction.deducted_at.saturating_add(window_seconds);
+        let now = env.ledger().timestamp();
+        if now > deadline {
+            return Err(EscrowError::ContestWindowClosed);
+        }
+
+        // EFFECTS: Deducted funds have already left escrow, so nothing remains
+        // to release; the dispute records the contest for the arbiter.
+        escrow.status = EscrowStatus::Disputed;
+        escrow.amount = 0;
+        escrow.disputed_at = Some(now);
+        escrow.dispute_reason = Some(reason.clone());
+        EscrowStorage::save(&env, &escrow);
+
+        events::deduction_contested(&env, escrow_id, caller, deduction.damage_amount, reason);
 
         Ok(())
     }
@@ -168,12 +231,14 @@ impl DisputeHandler {
         ];
         EscrowStorage::clear_approval_counts(&env, &escrow_id, &targets, &signers);
 
-        let token_client = token::Client::new(&env, &escrow.token);
-        token_client.transfer(
-            &env.current_contract_address(),
-            &escrow.depositor,
-            &escrow.amount,
-        );
+        if escrow.amount > 0 {
+            let token_client = token::Client::new(&env, &escrow.token);
+            token_client.transfer(
+                &env.current_contract_address(),
+                &escrow.depositor,
+                &escrow.amount,
+            );
+        }
 
         events::dispute_timeout(&env, escrow_id);
         Ok(())
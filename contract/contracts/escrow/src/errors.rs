@@ -40,4 +40,8 @@ pub enum EscrowError {
     RateLimitExceeded = 16,
     /// Cooldown period not met
     CooldownNotMet = 17,
+    /// No damage deduction has been recorded for this escrow
+    DeductionNotFound = 18,
+    /// The window for contesting a deduction has closed
+    ContestWindowClosed = 19,
 }
@@ -8,7 +8,9 @@ use crate::errors::EscrowError;
 use crate::events;
 use crate::rate_limit;
 use crate::storage::EscrowStorage;
-use crate::types::{Escrow, EscrowStatus, ReleaseApproval, ReleaseRecord, TimeoutConfig};
+use crate::types::{
+    DeductionRecord, Escrow, EscrowStatus, ReleaseApproval, ReleaseRecord, TimeoutConfig,
+};
 
 /// Core escrow contract implementation.
 #[contract]
@@ -235,6 +237,16 @@ impl EscrowContract {
         DisputeHandler::resolve_dispute(env, escrow_id, caller, release_to)
     }
 
+    /// Contest a damage deduction within the contest window.
+    pub fn contest_deduction(
+        env: Env,
+        escrow_id: BytesN<32>,
+        caller: Address,
+        reason: soroban_sdk::String,
+    ) -> Result<(), EscrowError> {
+        DisputeHandler::contest_deduction(env, escrow_id, caller, reason)
+    }
+
     /// Refund escrow to depositor if escrow timeout has elapsed.
     /// Intended for stale escrows that are not released yet.
     pub fn release_escrow_on_timeout(env: Env, escrow_id: BytesN<32>) -> Result<(), EscrowError> {
@@ -537,6 +549,16 @@ impl EscrowContract {
                 reason: reason.clone(),
             };
             EscrowStorage::add_release_record(&env, &escrow_id, damage_record);
+
+            // Keep the deduction on record so the depositor can contest it
+            EscrowStorage::save_deduction(
+                &env,
+                &DeductionRecord {
+                    escrow_id: escrow_id.clone(),
+                    damage_amount,
+                    deducted_at: env.ledger().timestamp(),
+                },
+            );
         }
 
         // Record refund release in history
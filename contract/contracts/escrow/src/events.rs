@@ -1,5 +1,5 @@
 //! Contract events for escrow lifecycle and timeout handling.
-use soroban_sdk::{contractevent, Address, BytesN, Env};
+use soroban_sdk::{contractevent, Address, BytesN, Env, String};
 
 #[contractevent(topics = ["escrow_timeout"])]
 pub struct EscrowTimeout {
@@ -29,6 +29,15 @@ pub struct DamageDeduction {
     pub refund_amount: i128,
 }
 
+#[contractevent(topics = ["deduction_contested"])]
+pub struct DeductionContested {
+    #[topic]
+    pub escrow_id: BytesN<32>,
+    pub depositor: Address,
+    pub damage_amount: i128,
+    pub reason: String,
+}
+
 pub(crate) fn escrow_timeout(env: &Env, escrow_id: BytesN<32>) {
     EscrowTimeout { escrow_id }.publish(env);
 }
@@ -59,3 +68,19 @@ pub(crate) fn damage_deduction(
     }
     .publish(env);
 }
+
+pub(crate) fn deduction_contested(
+    env: &Env,
+    escrow_id: BytesN<32>,
+    depositor: Address,
+    damage_amount: i128,
+    reason: String,
+) {
+    DeductionContested {
+        escrow_id,
+        depositor,
+        damage_amount,
+        reason,
+    }
+    .publish(env);
+}
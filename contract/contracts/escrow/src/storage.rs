@@ -2,7 +2,9 @@
 //! Implements single-responsibility getter/setter helpers.
 use soroban_sdk::{Address, BytesN, Env, Vec};
 
-use crate::types::{DataKey, Escrow, ReleaseApproval, ReleaseRecord, TimeoutConfig};
+use crate::types::{
+    DataKey, DeductionRecord, Escrow, ReleaseApproval, ReleaseRecord, TimeoutConfig,
+};
 
 /// Escrow storage management.
 pub struct EscrowStorage;
@@ -11,6 +13,7 @@ impl EscrowStorage {
     pub const DEFAULT_ESCROW_TIMEOUT_DAYS: u64 = 14;
     pub const DEFAULT_DISPUTE_TIMEOUT_DAYS: u64 = 30;
     pub const DEFAULT_PAYMENT_TIMEOUT_DAYS: u64 = 7;
+    pub const DEDUCTION_CONTEST_WINDOW_DAYS: u64 = 7;
 
     /// Retrieve an escrow by ID.
     /// Returns None if escrow doesn't exist.
@@ -173,4 +176,16 @@ impl EscrowStorage {
         let key = DataKey::ReleaseHistory(escrow_id.clone());
         env.storage().persistent().set(&key, &history);
     }
+
+    /// Retrieve the deduction record for an escrow, if any.
+    pub fn get_deduction(env: &Env, escrow_id: &BytesN<32>) -> Option<DeductionRecord> {
+        let key = DataKey::Deduction(escrow_id.clone());
+        env.storage().persistent().get::<_, DeductionRecord>(&key)
+    }
+
+    /// Persist the deduction record for an escrow.
+    pub fn save_deduction(env: &Env, record: &DeductionRecord) {
+        let key = DataKey::Deduction(record.escrow_id.clone());
+        env.storage().persistent().set(&key, record);
+    }
 }
@@ -634,6 +634,74 @@ fn test_damage_deduction_insufficient_approvals() {
     assert!(result.is_err());
 }
 
+fn setup_deducted_escrow(
+    env: &Env,
+) -> (
+    EscrowContractClient<'_>,
+    soroban_sdk::BytesN<32>,
+    Address,
+    i128,
+) {
+    let (client, depositor, beneficiary, arbiter, token_address) = setup_test(env);
+    let amount = 1000i128;
+    let damage_amount = 200i128;
+
+    let escrow_id = client.create(&depositor, &beneficiary, &arbiter, &amount, &token_address);
+    let token_admin = TokenAdminClient::new(env, &token_address);
+    token_admin.mint(&depositor, &amount);
+    client.fund_escrow(&escrow_id, &depositor);
+
+    client.approve_partial_release(&escrow_id, &beneficiary, &depositor);
+    client.approve_partial_release(&escrow_id, &arbiter, &depositor);
+    client.release_with_deduction(
+        &escrow_id,
+        &damage_amount,
+        &soroban_sdk::String::from_str(env, "Damaged furniture"),
+    );
+
+    (client, escrow_id, depositor, damage_amount)
+}
+
+#[test]
+fn test_contest_deduction_within_window() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, escrow_id, depositor, _) = setup_deducted_escrow(&env);
+
+    env.ledger().with_mut(|li| li.timestamp += 3 * 86_400);
+
+    let reason = soroban_sdk::String::from_str(&env, "Damage predates tenancy");
+    client.contest_deduction(&escrow_id, &depositor, &reason);
+
+    let escrow = client.get_escrow(&escrow_id);
+    assert_eq!(escrow.status, EscrowStatus::Disputed);
+    assert_eq!(escrow.dispute_reason, Some(reason));
+}
+
+#[test]
+fn test_contest_deduction_after_window_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, escrow_id, depositor, _) = setup_deducted_escrow(&env);
+
+    env.ledger().with_mut(|li| li.timestamp += 8 * 86_400);
+
+    let result = client.try_contest_deduction(
+        &escrow_id,
+        &depositor,
+        &soroban_sdk::String::from_str(&env, "Too late"),
+    );
+    assert_eq!(
+        result,
+        Err(Ok(crate::errors::EscrowError::ContestWindowClosed))
+    );
+
+    let escrow = client.get_escrow(&escrow_id);
+    assert_eq!(escrow.status, EscrowStatus::Released);
+}
+
 #[test]
 fn test_partial_release_invalid_recipient() {
     let env = Env::default();
@@ -82,6 +82,18 @@ pub struct ReleaseRecord {
     pub reason: String,
 }
 
+/// Records a damage deduction so the depositor can contest it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct DeductionRecord {
+    /// Unique identifier for the escrow
+    pub escrow_id: BytesN<32>,
+    /// Amount deducted and paid to the beneficiary
+    pub damage_amount: i128,
+    /// Timestamp when the deduction was executed
+    pub deducted_at: u64,
+}
+
 /// Rate limiting configuration.
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[contracttype]
@@ -122,6 +134,8 @@ pub enum DataKey {
     TimeoutConfig,
     /// Store release history for an escrow: DataKey::ReleaseHistory(escrow_id)
     ReleaseHistory(BytesN<32>),
+    /// Latest damage deduction for an escrow: DataKey::Deduction(escrow_id)
+    Deduction(BytesN<32>),
     /// Rate limiting configuration
     RateLimitConfig,
     /// User call count for rate limiting: DataKey::UserCallCount(user, function_name)
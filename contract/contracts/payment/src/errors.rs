@@ -55,4 +55,22 @@ pub enum PaymentError {
     PaymentNotLate = 34,
     /// Caller is not the landlord
     NotLandlord = 35,
+    /// Split table is empty or its shares do not sum to 10000 bps
+    InvalidSplitTable = 36,
+    /// Token transfer to the landlord failed
+    LandlordTransferFailed = 37,
+    /// Token transfer to the agent or another split recipient failed
+    AgentTransferFailed = 38,
+    /// Token transfer of the platform fee failed
+    PlatformTransferFailed = 39,
+    /// Late fee tiers are empty or their day thresholds are not strictly ascending
+    InvalidLateFeeTiers = 40,
+    /// Tenant's token balance is below the amount due
+    InsufficientBalance = 41,
+    /// Agreement already has its maximum number of rent payments
+    PaymentLimitReached = 42,
+    /// Caller is not the agreement's agent
+    NotAgent = 43,
+    /// Amount calculation overflowed
+    Overflow = 44,
 }
@@ -1,4 +1,4 @@
-use soroban_sdk::{contractevent, Env, String};
+use soroban_sdk::{contractevent, Address, Env, String};
 
 #[contractevent(topics = ["late_fee_config_set"])]
 pub struct LateFeeConfigSet {
@@ -126,3 +126,44 @@ pub(crate) fn recurring_payment_cancelled(env: &Env, recurring_id: String) {
 pub(crate) fn recurring_payment_failed(env: &Env, recurring_id: String) {
     RecurringPaymentFailed { recurring_id }.publish(env);
 }
+
+#[contractevent(topics = ["payment_disputed"])]
+pub struct PaymentDisputed {
+    #[topic]
+    pub agreement_id: String,
+    #[topic]
+    pub landlord: Address,
+    pub failed_attempts: u32,
+}
+
+pub(crate) fn payment_disputed(
+    env: &Env,
+    agreement_id: String,
+    landlord: Address,
+    failed_attempts: u32,
+) {
+    PaymentDisputed {
+        agreement_id,
+        landlord,
+        failed_attempts,
+    }
+    .publish(env);
+}
+
+#[contractevent(topics = ["commission_claimed"])]
+pub struct CommissionClaimed {
+    #[topic]
+    pub agreement_id: String,
+    #[topic]
+    pub agent: Address,
+    pub amount: i128,
+}
+
+pub(crate) fn commission_claimed(env: &Env, agreement_id: String, agent: Address, amount: i128) {
+    CommissionClaimed {
+        agreement_id,
+        agent,
+        amount,
+    }
+    .publish(env);
+}
@@ -9,11 +9,11 @@
 //!
 //!   late_fee = min(late_fee, max_late_fee)
 
-use soroban_sdk::{Env, String};
+use soroban_sdk::{Env, String, Vec};
 
 use crate::errors::PaymentError;
 use crate::storage::DataKey;
-use crate::types::{LateFeeConfig, RentAgreement};
+use crate::types::LateFeeConfig;
 
 /// Core calculation: given a config and base rent amount, compute the late fee
 /// for `days_late` days past the original due date (grace period included).
@@ -52,6 +52,42 @@ pub fn compute_fee(config: &LateFeeConfig, base_amount: i128, days_late: u32) ->
     }
 }
 
+/// Check that a tier schedule is non-empty with strictly ascending day thresholds.
+pub fn validate_tiers(tiers: &Vec<(u64, u32)>) -> Result<(), PaymentError> {
+    if tiers.is_empty() {
+        return Err(PaymentError::InvalidLateFeeTiers);
+    }
+
+    let mut previous: Option<u64> = None;
+    for (days, _) in tiers.iter() {
+        if previous.is_some_and(|previous| days <= previous) {
+            return Err(PaymentError::InvalidLateFeeTiers);
+        }
+        previous = Some(days);
+    }
+    Ok(())
+}
+
+/// Fee in bps of the highest tier whose threshold `days_late` has reached (0 if none).
+pub fn tier_fee_bps(tiers: &Vec<(u64, u32)>, days_late: u64) -> u32 {
+    let mut bps = 0;
+    for (threshold, tier_bps) in tiers.iter() {
+        if days_late < threshold {
+            break;
+        }
+        bps = tier_bps;
+    }
+    bps
+}
+
+/// `rent` plus a `fee_bps` surcharge, failing with `Overflow` instead of trapping.
+pub fn rent_with_fee(rent: i128, fee_bps: u32) -> Result<i128, PaymentError> {
+    rent.checked_mul(fee_bps as i128)
+        .map(|scaled| scaled / 10_000)
+        .and_then(|fee| rent.checked_add(fee))
+        .ok_or(PaymentError::Overflow)
+}
+
 /// Load config + agreement from storage and compute the late fee amount.
 pub fn calculate_late_fee_amount(
     env: &Env,
@@ -65,11 +101,7 @@ pub fn calculate_late_fee_amount(
         .get(&DataKey::LateFeeConfig(agreement_id.clone()))
         .ok_or(PaymentError::LateFeeConfigNotFound)?;
 
-    let agreement: RentAgreement = env
-        .storage()
-        .persistent()
-        .get(&DataKey::Agreement(agreement_id.clone()))
-        .ok_or(PaymentError::AgreementNotFound)?;
+    let agreement = crate::payment_impl::load_agreement(env, agreement_id)?;
 
     Ok(compute_fee(&config, agreement.monthly_rent, days_late))
 }
@@ -27,17 +27,24 @@ mod tests_rate_limit;
 
 // Re-export public APIs
 pub use errors::PaymentError;
-pub use payment_impl::{calculate_payment_split, create_payment_record};
+pub use payment_impl::{
+    calculate_fee_split, calculate_fee_split_ppm, calculate_payment_split,
+    calculate_payment_split_ppm, calculate_table_split, create_payment_record,
+    validate_split_table,
+};
 pub use storage::DataKey;
 pub use types::{
-    ExecutionStatus, LateFeeConfig, LateFeeRecord, PaymentExecution, PaymentFrequency,
-    PaymentRecord, PaymentSplit, RecurringPayment, RecurringPaymentEvent, RecurringStatus,
+    CommissionMode, ExecutionStatus, LateFeeConfig, LateFeeRecord, PaymentExecution,
+    PaymentFrequency, PaymentRecord, PaymentSplit, RecurringPayment, RecurringPaymentEvent,
+    RecurringStatus, RemainderBeneficiary,
 };
 
 use crate::errors::PaymentError as Error;
 use crate::storage::DataKey as StorageKey;
 use crate::types::{AgreementStatus, RentAgreement};
 
+const DEFAULT_FAILED_PAYMENT_THRESHOLD: u32 = 3;
+
 #[contract]
 pub struct PaymentContract;
 
@@ -184,6 +191,55 @@ impl PaymentContract {
             .set(&StorageKey::PlatformFeeCollector, &collector);
     }
 
+    /// Waive the platform fee on rent paid before `until` (fee collector only)
+    pub fn set_fee_holiday(env: Env, until: u64) -> Result<(), Error> {
+        let collector: Address = env
+            .storage()
+            .instance()
+            .get(&StorageKey::PlatformFeeCollector)
+            .ok_or(Error::PaymentFailed)?;
+        collector.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&StorageKey::FeeHolidayUntil, &until);
+        Ok(())
+    }
+
+    /// Choose who receives the rounding remainder of rent splits (fee collector only)
+    pub fn set_remainder_beneficiary(
+        env: Env,
+        beneficiary: crate::types::RemainderBeneficiary,
+    ) -> Result<(), Error> {
+        let collector: Address = env
+            .storage()
+            .instance()
+            .get(&StorageKey::PlatformFeeCollector)
+            .ok_or(Error::PaymentFailed)?;
+        collector.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&StorageKey::RemainderBeneficiary, &beneficiary);
+        Ok(())
+    }
+
+    /// Party receiving the rounding remainder of rent splits (landlord by default)
+    pub fn get_remainder_beneficiary(env: Env) -> crate::types::RemainderBeneficiary {
+        env.storage()
+            .instance()
+            .get(&StorageKey::RemainderBeneficiary)
+            .unwrap_or(crate::types::RemainderBeneficiary::Landlord)
+    }
+
+    /// Timestamp until which the platform fee is waived (0 when none is set)
+    pub fn get_fee_holiday_until(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&StorageKey::FeeHolidayUntil)
+            .unwrap_or(0)
+    }
+
     /// Get a payment record by ID
     pub fn get_payment(env: Env, payment_id: String) -> Result<PaymentRecord, Error> {
         env.storage()
@@ -192,6 +248,19 @@ impl PaymentContract {
             .ok_or(Error::PaymentNotFound)
     }
 
+    /// Most recent rent payment recorded for an agreement
+    pub fn get_last_payment(env: Env, agreement_id: String) -> Result<PaymentRecord, Error> {
+        let agreement = payment_impl::load_agreement(&env, &agreement_id)?;
+
+        env.storage()
+            .persistent()
+            .get(&StorageKey::PaymentRecord(
+                agreement_id,
+                agreement.payment_count,
+            ))
+            .ok_or(Error::PaymentNotFound)
+    }
+
     /// Get total payment count
     pub fn get_payment_count(env: Env) -> u32 {
         env.storage()
@@ -243,16 +312,41 @@ impl PaymentContract {
         }
     }
 
-    /// Process rent payment with 90/10 landlord/platform split
+    /// Process rent payment, split between the landlord, the platform (10%) and
+    /// any agent's commission, which a co-agent shares when one is set
     /// Follows checks-effects-interactions pattern for reentrancy safety
+    ///
+    /// `from` is the tenant or the tenant's delegate, and the rent is drawn
+    /// from that address.
+    ///
+    /// `payment_amount` must equal `amount_due`: the monthly rent plus any
+    /// late fee from the agreement's tier schedule, which goes to the landlord.
+    ///
+    /// When `idempotency_key` is given and was already processed for this
+    /// agreement, the call succeeds without moving funds again.
+    ///
+    /// Fails with `InvalidAmount` for a zero or negative amount, before any
+    /// other check.
+    ///
+    /// Fails with `InsufficientBalance` when the payer's balance cannot cover
+    /// the payment, before any transfer is attempted. Once the landlord has set
+    /// a failure threshold, such a payment instead bounces: it returns `false`,
+    /// the bounce is recorded rather than reverted, and reaching the threshold
+    /// moves the agreement to `Disputed`.
     pub fn pay_rent(
         env: Env,
         from: Address,
         agreement_id: String,
         payment_amount: i128,
-    ) -> Result<(), Error> {
+        idempotency_key: Option<String>,
+    ) -> Result<bool, Error> {
         use soroban_sdk::token;
 
+        // Nothing to pay: reject before touching limits, records or funds
+        if payment_amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
         // Authorization
         from.require_auth();
 
@@ -260,45 +354,107 @@ impl PaymentContract {
         crate::rate_limit::check_rate_limit(&env, &from, "pay_rent")?;
 
         // Load agreement
-        let mut agreement: RentAgreement = env
-            .storage()
-            .persistent()
-            .get(&StorageKey::Agreement(agreement_id.clone()))
-            .ok_or(Error::AgreementNotFound)?;
+        let mut agreement = payment_impl::load_agreement(&env, &agreement_id)?;
 
-        // Validation
-        if agreement.status != AgreementStatus::Active {
-            return Err(Error::AgreementNotActive);
+        if from != agreement.tenant
+            && Self::get_tenant_delegate(env.clone(), agreement_id.clone()) != Some(from.clone())
+        {
+            return Err(Error::NotTenant);
         }
 
-        if from != agreement.tenant {
-            return Err(Error::NotTenant);
+        // Replayed submissions return the prior result
+        let processed_key =
+            idempotency_key.map(|key| StorageKey::ProcessedKey(agreement_id.clone(), key));
+        if let Some(key) = &processed_key {
+            if env.storage().persistent().has(key) {
+                return Ok(true);
+            }
         }
 
-        if payment_amount <= 0 {
-            return Err(Error::InvalidPaymentAmount);
+        // Validation
+        if agreement.status != AgreementStatus::Active {
+            return Err(Error::AgreementNotActive);
         }
 
-        if payment_amount != agreement.monthly_rent {
+        // Rent plus any late fee from the agreement's tier schedule
+        if payment_amount != Self::amount_due_for(&env, &agreement_id, &agreement)? {
             return Err(Error::InvalidPaymentAmount);
         }
+        let late_fee = payment_amount - agreement.monthly_rent;
 
         let current_time = env.ledger().timestamp();
         if current_time < agreement.next_payment_due {
             return Err(Error::PaymentNotDue);
         }
 
-        // Calculate 90/10 split
-        let landlord_amount = (payment_amount * 90) / 100;
-        let platform_amount = payment_amount - landlord_amount;
+        if let Some(max_payments) = Self::get_max_payments(env.clone(), agreement_id.clone()) {
+            if agreement.payment_history.len() >= max_payments {
+                return Err(Error::PaymentLimitReached);
+            }
+        }
 
-        let platform_collector: Address = env
+        // A configured split table receives the full payment; otherwise the
+        // platform takes 10% of the rent (waived during a fee holiday), any agent
+        // takes their commission, and the landlord the rest plus any late fee
+        let split_table: Option<Vec<(Address, u32)>> = env
             .storage()
-            .instance()
-            .get(&StorageKey::PlatformFeeCollector)
-            .ok_or(Error::PaymentFailed)?;
+            .persistent()
+            .get(&StorageKey::SplitTable(agreement_id.clone()));
+
+        let fee_holiday = current_time < Self::get_fee_holiday_until(env.clone());
+        let (landlord_amount, platform_amount, agent_amount) = match split_table {
+            Some(_) => (payment_amount, 0, 0),
+            None => {
+                let platform_ppm = if fee_holiday { 0 } else { 100_000 };
+                let agent_ppm = match agreement.agent {
+                    Some(_) => Self::get_commission_ppm(env.clone(), agreement_id.clone())
+                        .unwrap_or(agreement.agent_commission_rate * 100),
+                    None => 0,
+                };
+                let (landlord_amount, platform_amount, agent_amount) =
+                    payment_impl::calculate_fee_split_ppm(
+                        agreement.monthly_rent,
+                        platform_ppm,
+                        agent_ppm,
+                        &Self::get_remainder_beneficiary(env.clone()),
+                    );
+                (landlord_amount + late_fee, platform_amount, agent_amount)
+            }
+        };
+
+        let platform_collector: Option<Address> = match split_table {
+            Some(_) => None,
+            None => Some(
+                env.storage()
+                    .instance()
+                    .get(&StorageKey::PlatformFeeCollector)
+                    .ok_or(Error::PaymentFailed)?,
+            ),
+        };
+
+        let token_client = token::Client::new(&env, &agreement.payment_token);
+        if token_client.balance(&from) < payment_amount {
+            let tracks_bounces = env
+                .storage()
+                .persistent()
+                .has(&StorageKey::FailedPaymentThreshold(agreement_id.clone()));
+            if !tracks_bounces {
+                return Err(Error::InsufficientBalance);
+            }
+            Self::record_failed_payment(&env, &mut agreement);
+            return Ok(false);
+        }
 
         // Effects: Update state BEFORE external calls
+        let late = env
+            .storage()
+            .persistent()
+            .get(&StorageKey::LateFeeConfig(agreement_id.clone()))
+            .is_some_and(|config| {
+                current_time > Self::late_fee_deadline(&env, &agreement, &config)
+            });
+        Self::record_standing(&env, &agreement.tenant, late);
+
         let payment_month = agreement.payment_history.len();
         agreement.payment_history.set(
             payment_month,
@@ -310,30 +466,395 @@ impl PaymentContract {
             },
         );
         agreement.next_payment_due = current_time + 2_592_000; // 30 days
+        agreement.total_rent_paid += payment_amount;
+        agreement.payment_count += 1;
+
+        let payment_record = payment_impl::create_payment_record(
+            &env,
+            &agreement_id,
+            payment_amount,
+            landlord_amount,
+            agent_amount,
+            &agreement.tenant,
+            agreement.payment_count,
+            current_time,
+        )?;
+        env.storage().persistent().set(
+            &StorageKey::PaymentRecord(agreement_id.clone(), agreement.payment_count),
+            &payment_record,
+        );
 
         env.storage()
             .persistent()
             .set(&StorageKey::Agreement(agreement_id.clone()), &agreement);
 
-        // Interactions: External calls AFTER state updates
-        let token_client = token::Client::new(&env, &agreement.payment_token);
-        token_client.transfer(&from, &agreement.landlord, &landlord_amount);
-        token_client.transfer(&from, &platform_collector, &platform_amount);
+        // A co-agent takes their share of the commission from the agent's cut
+        let (primary_amount, co_agent_amount) = match agreement.co_agent {
+            Some(_) => {
+                payment_impl::split_agent_commission(agent_amount, agreement.co_agent_share_bps)
+            }
+            None => (agent_amount, 0),
+        };
+
+        // Accrued commission is held here until the agent claims it
+        let accrue_commission = primary_amount > 0
+            && Self::get_commission_mode(env.clone(), agreement_id.clone())
+                == CommissionMode::Accrue;
+        if accrue_commission {
+            let key = StorageKey::AccruedCommission(
+                agreement_id.clone(),
+                agreement.payment_token.clone(),
+            );
+            let accrued: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+            env.storage()
+                .persistent()
+                .set(&key, &(accrued + primary_amount));
+        }
+
+        if let Some(key) = &processed_key {
+            env.storage().persistent().set(key, &payment_month);
+        }
+        env.storage()
+            .persistent()
+            .remove(&StorageKey::FailedAttempts(agreement_id.clone()));
+
+        // Interactions: External calls AFTER state updates. A failed transfer
+        // reports which leg failed; returning the error reverts the effects above.
+        match (split_table, platform_collector) {
+            (Some(table), _) => {
+                for (recipient, share) in
+                    payment_impl::calculate_table_split(&env, payment_amount, &table).iter()
+                {
+                    if share > 0 {
+                        let error = if recipient == agreement.landlord {
+                            Error::LandlordTransferFailed
+                        } else {
+                            Error::AgentTransferFailed
+                        };
+                        payment_impl::transfer_or(&token_client, &from, &recipient, share, error)?;
+                    }
+                }
+            }
+            (None, Some(platform_collector)) => {
+                payment_impl::transfer_or(
+                    &token_client,
+                    &from,
+                    &agreement.landlord,
+                    landlord_amount,
+                    Error::LandlordTransferFailed,
+                )?;
+                if platform_amount > 0 {
+                    payment_impl::transfer_or(
+                        &token_client,
+                        &from,
+                        &platform_collector,
+                        platform_amount,
+                        Error::PlatformTransferFailed,
+                    )?;
+                }
+                if let Some(agent) = agreement.agent.as_ref().filter(|_| primary_amount > 0) {
+                    let contract = env.current_contract_address();
+                    let recipient = if accrue_commission { &contract } else { agent };
+                    payment_impl::transfer_or(
+                        &token_client,
+                        &from,
+                        recipient,
+                        primary_amount,
+                        Error::AgentTransferFailed,
+                    )?;
+                }
+                if let Some(co_agent) = agreement.co_agent.as_ref().filter(|_| co_agent_amount > 0)
+                {
+                    payment_impl::transfer_or(
+                        &token_client,
+                        &from,
+                        co_agent,
+                        co_agent_amount,
+                        Error::AgentTransferFailed,
+                    )?;
+                }
+            }
+            (None, None) => return Err(Error::PaymentFailed),
+        }
+
+        Ok(true)
+    }
+
+    /// Override the agent's commission with a parts-per-million rate, or drop
+    /// back to the basis-point rate with `None` (landlord and agent)
+    pub fn set_commission_ppm(
+        env: Env,
+        agreement_id: String,
+        commission_ppm: Option<u32>,
+    ) -> Result<(), Error> {
+        let agreement = payment_impl::load_agreement(&env, &agreement_id)?;
+        let agent = agreement.agent.ok_or(Error::NotAgent)?;
+        agreement.landlord.require_auth();
+        agent.require_auth();
+
+        let key = StorageKey::CommissionPpm(agreement_id);
+        match commission_ppm {
+            Some(ppm) if ppm > 900_000 => return Err(Error::InvalidAmount),
+            Some(ppm) => env.storage().persistent().set(&key, &ppm),
+            None => env.storage().persistent().remove(&key),
+        }
+        Ok(())
+    }
+
+    /// Parts-per-million commission override for the agreement's agent, if any
+    pub fn get_commission_ppm(env: Env, agreement_id: String) -> Option<u32> {
+        env.storage()
+            .persistent()
+            .get(&StorageKey::CommissionPpm(agreement_id))
+    }
+
+    /// Choose whether the agent's commission is paid instantly or accrues
+    /// until claimed (agent only)
+    pub fn set_commission_mode(
+        env: Env,
+        agreement_id: String,
+        mode: CommissionMode,
+    ) -> Result<(), Error> {
+        let agreement = payment_impl::load_agreement(&env, &agreement_id)?;
+        let agent = agreement.agent.ok_or(Error::NotAgent)?;
+        agent.require_auth();
+
+        env.storage()
+            .persistent()
+            .set(&StorageKey::CommissionMode(agreement_id), &mode);
+        Ok(())
+    }
+
+    /// How the agreement's agent commission is paid out (instant by default)
+    pub fn get_commission_mode(env: Env, agreement_id: String) -> CommissionMode {
+        env.storage()
+            .persistent()
+            .get(&StorageKey::CommissionMode(agreement_id))
+            .unwrap_or(CommissionMode::Instant)
+    }
+
+    /// Commission accrued for the agreement's agent in `token`
+    pub fn get_accrued_commission(env: Env, agreement_id: String, token: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&StorageKey::AccruedCommission(agreement_id, token))
+            .unwrap_or(0)
+    }
+
+    /// Withdraw all commission accrued in `token` in a single transfer (agent only)
+    ///
+    /// Returns the amount claimed.
+    pub fn claim_commission(
+        env: Env,
+        agreement_id: String,
+        agent: Address,
+        token: Address,
+    ) -> Result<i128, Error> {
+        agent.require_auth();
+
+        let agreement = payment_impl::load_agreement(&env, &agreement_id)?;
+        if agreement.agent != Some(agent.clone()) {
+            return Err(Error::NotAgent);
+        }
+
+        let key = StorageKey::AccruedCommission(agreement_id.clone(), token.clone());
+        let amount: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+        if amount > 0 {
+            env.storage().persistent().remove(&key);
+            payment_impl::transfer_or(
+                &soroban_sdk::token::Client::new(&env, &token),
+                &env.current_contract_address(),
+                &agent,
+                amount,
+                Error::AgentTransferFailed,
+            )?;
+            events::commission_claimed(&env, agreement_id, agent, amount);
+        }
+        Ok(amount)
+    }
+
+    /// Let `delegate` pay rent for the agreement in the tenant's place, or
+    /// revoke delegation with `None` (tenant only)
+    pub fn set_tenant_delegate(
+        env: Env,
+        agreement_id: String,
+        delegate: Option<Address>,
+    ) -> Result<(), Error> {
+        let agreement = payment_impl::load_agreement(&env, &agreement_id)?;
+        agreement.tenant.require_auth();
+
+        let key = StorageKey::TenantDelegate(agreement_id);
+        match delegate {
+            Some(delegate) => env.storage().persistent().set(&key, &delegate),
+            None => env.storage().persistent().remove(&key),
+        }
+        Ok(())
+    }
+
+    /// Address allowed to pay rent on the tenant's behalf, if any
+    pub fn get_tenant_delegate(env: Env, agreement_id: String) -> Option<Address> {
+        env.storage()
+            .persistent()
+            .get(&StorageKey::TenantDelegate(agreement_id))
+    }
+
+    /// Count a completed rent payment as on time or late in the tenant's standing
+    fn record_standing(env: &Env, tenant: &Address, late: bool) {
+        let key = StorageKey::TenantStanding(tenant.clone());
+        let (on_time, late_count) = Self::get_tenant_standing(env.clone(), tenant.clone());
+        let standing = if late {
+            (on_time, late_count + 1)
+        } else {
+            (on_time + 1, late_count)
+        };
+        env.storage().persistent().set(&key, &standing);
+        env.storage().persistent().extend_ttl(&key, 500000, 500000);
+    }
+
+    /// A tenant's rent payment record as `(on_time, late)` counts, where a
+    /// payment is late if it arrived after its late-fee grace window.
+    pub fn get_tenant_standing(env: Env, tenant: Address) -> (u32, u32) {
+        env.storage()
+            .persistent()
+            .get(&StorageKey::TenantStanding(tenant))
+            .unwrap_or((0, 0))
+    }
+
+    /// Count a bounced payment, disputing the agreement once the threshold is hit
+    fn record_failed_payment(env: &Env, agreement: &mut RentAgreement) {
+        let attempts_key = StorageKey::FailedAttempts(agreement.agreement_id.clone());
+        let attempts: u32 = env.storage().persistent().get(&attempts_key).unwrap_or(0) + 1;
+        env.storage().persistent().set(&attempts_key, &attempts);
+
+        let threshold =
+            Self::get_failed_payment_threshold(env.clone(), agreement.agreement_id.clone());
+        if attempts >= threshold {
+            agreement.status = AgreementStatus::Disputed;
+            env.storage().persistent().set(
+                &StorageKey::Agreement(agreement.agreement_id.clone()),
+                &*agreement,
+            );
+            events::payment_disputed(
+                env,
+                agreement.agreement_id.clone(),
+                agreement.landlord.clone(),
+                attempts,
+            );
+        }
+    }
+
+    /// Set how many consecutive bounced payments dispute an agreement (landlord only)
+    ///
+    /// Until a threshold is set, underfunded payments fail with
+    /// `InsufficientBalance` instead of being recorded as bounces.
+    pub fn set_failed_payment_threshold(
+        env: Env,
+        agreement_id: String,
+        threshold: u32,
+    ) -> Result<(), Error> {
+        if threshold == 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        let agreement = payment_impl::load_agreement(&env, &agreement_id)?;
+
+        agreement.landlord.require_auth();
+
+        env.storage().persistent().set(
+            &StorageKey::FailedPaymentThreshold(agreement_id),
+            &threshold,
+        );
+        Ok(())
+    }
+
+    /// Cap the rent payments recorded for an agreement (landlord only)
+    pub fn set_max_payments(
+        env: Env,
+        agreement_id: String,
+        max_payments: u32,
+    ) -> Result<(), Error> {
+        if max_payments == 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        let agreement = payment_impl::load_agreement(&env, &agreement_id)?;
+
+        agreement.landlord.require_auth();
+
+        env.storage()
+            .persistent()
+            .set(&StorageKey::MaxPayments(agreement_id), &max_payments);
+        Ok(())
+    }
+
+    /// Most rent payments an agreement accepts: the landlord's cap if set,
+    /// otherwise one per 30-day period of its term (None when it has no term)
+    pub fn get_max_payments(env: Env, agreement_id: String) -> Option<u32> {
+        if let Some(max_payments) = env
+            .storage()
+            .persistent()
+            .get(&StorageKey::MaxPayments(agreement_id.clone()))
+        {
+            return Some(max_payments);
+        }
 
+        let agreement = payment_impl::load_agreement(&env, &agreement_id).ok()?;
+        if agreement.end_date <= agreement.start_date {
+            return None;
+        }
+        let periods = (agreement.end_date - agreement.start_date).div_ceil(2_592_000);
+        Some(u32::try_from(periods).unwrap_or(u32::MAX))
+    }
+
+    /// Consecutive bounced payments that dispute an agreement (defaults to 3)
+    pub fn get_failed_payment_threshold(env: Env, agreement_id: String) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&StorageKey::FailedPaymentThreshold(agreement_id))
+            .unwrap_or(DEFAULT_FAILED_PAYMENT_THRESHOLD)
+    }
+
+    /// Consecutive bounced payments recorded since the last successful one
+    pub fn get_failed_attempts(env: Env, agreement_id: String) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&StorageKey::FailedAttempts(agreement_id))
+            .unwrap_or(0)
+    }
+
+    /// Route an agreement's rent across basis-point recipients (landlord only)
+    ///
+    /// Shares must sum to 10000. An agent's commission is just one entry.
+    pub fn set_split_table(
+        env: Env,
+        agreement_id: String,
+        table: Vec<(Address, u32)>,
+    ) -> Result<(), Error> {
+        let agreement = payment_impl::load_agreement(&env, &agreement_id)?;
+
+        agreement.landlord.require_auth();
+        payment_impl::validate_split_table(&table)?;
+
+        env.storage()
+            .persistent()
+            .set(&StorageKey::SplitTable(agreement_id), &table);
         Ok(())
     }
 
+    /// Get an agreement's disbursement split table, if configured
+    pub fn get_split_table(env: Env, agreement_id: String) -> Option<Vec<(Address, u32)>> {
+        env.storage()
+            .persistent()
+            .get(&StorageKey::SplitTable(agreement_id))
+    }
+
     /// Get payment details for a specific month
     pub fn get_payment_split(
         env: Env,
         agreement_id: String,
         month: u32,
     ) -> Result<PaymentSplit, Error> {
-        let agreement: RentAgreement = env
-            .storage()
-            .persistent()
-            .get(&StorageKey::Agreement(agreement_id))
-            .ok_or(Error::AgreementNotFound)?;
+        let agreement = payment_impl::load_agreement(&env, &agreement_id)?;
 
         agreement
             .payment_history
@@ -350,11 +871,7 @@ impl PaymentContract {
         end_date: u64,
         auto_renew: bool,
     ) -> Result<String, Error> {
-        let agreement: RentAgreement = env
-            .storage()
-            .persistent()
-            .get(&StorageKey::Agreement(agreement_id.clone()))
-            .ok_or(Error::AgreementNotFound)?;
+        let agreement = payment_impl::load_agreement(&env, &agreement_id)?;
 
         agreement.tenant.require_auth();
 
@@ -643,11 +1160,7 @@ impl PaymentContract {
             return Err(Error::InvalidLateFeePercentage);
         }
 
-        let agreement: crate::types::RentAgreement = env
-            .storage()
-            .persistent()
-            .get(&StorageKey::Agreement(agreement_id.clone()))
-            .ok_or(Error::AgreementNotFound)?;
+        let agreement = payment_impl::load_agreement(&env, &agreement_id)?;
 
         agreement.landlord.require_auth();
 
@@ -684,6 +1197,59 @@ impl PaymentContract {
             .ok_or(Error::LateFeeConfigNotFound)
     }
 
+    /// Set a tiered late fee schedule for an agreement (landlord only).
+    ///
+    /// Each `(days_late, fee_bps)` tier applies once rent is that many days
+    /// overdue, until the next tier takes over. Thresholds must be strictly
+    /// ascending.
+    pub fn set_late_fee_tiers(
+        env: Env,
+        agreement_id: String,
+        tiers: Vec<(u64, u32)>,
+    ) -> Result<(), Error> {
+        crate::late_fee::validate_tiers(&tiers)?;
+
+        let agreement = payment_impl::load_agreement(&env, &agreement_id)?;
+        agreement.landlord.require_auth();
+
+        let key = StorageKey::LateFeeTiers(agreement_id);
+        env.storage().persistent().set(&key, &tiers);
+        env.storage().persistent().extend_ttl(&key, 500000, 500000);
+        Ok(())
+    }
+
+    /// Get an agreement's tiered late fee schedule, if one is set.
+    pub fn get_late_fee_tiers(env: Env, agreement_id: String) -> Option<Vec<(u64, u32)>> {
+        env.storage()
+            .persistent()
+            .get(&StorageKey::LateFeeTiers(agreement_id))
+    }
+
+    /// Rent currently due on an agreement, including the late fee from its
+    /// tier schedule for however many whole days the payment is overdue.
+    /// `pay_rent` requires exactly this amount.
+    pub fn amount_due(env: Env, agreement_id: String) -> Result<i128, Error> {
+        let agreement = payment_impl::load_agreement(&env, &agreement_id)?;
+        Self::amount_due_for(&env, &agreement_id, &agreement)
+    }
+
+    fn amount_due_for(
+        env: &Env,
+        agreement_id: &String,
+        agreement: &RentAgreement,
+    ) -> Result<i128, Error> {
+        let days_late = env
+            .ledger()
+            .timestamp()
+            .saturating_sub(agreement.next_payment_due)
+            / 86_400;
+        let fee_bps = Self::get_late_fee_tiers(env.clone(), agreement_id.clone())
+            .map(|tiers| crate::late_fee::tier_fee_bps(&tiers, days_late))
+            .unwrap_or(0);
+
+        crate::late_fee::rent_with_fee(agreement.monthly_rent, fee_bps)
+    }
+
     /// Calculate the late fee for a payment given how many days late it is.
     /// Returns the late fee amount (not yet persisted).
     pub fn calculate_late_fee(
@@ -715,11 +1281,7 @@ impl PaymentContract {
             return Err(Error::LateFeeAlreadyApplied);
         }
 
-        let agreement: crate::types::RentAgreement = env
-            .storage()
-            .persistent()
-            .get(&StorageKey::Agreement(agreement_id.clone()))
-            .ok_or(Error::AgreementNotFound)?;
+        let agreement = payment_impl::load_agreement(&env, &agreement_id)?;
 
         let config: crate::types::LateFeeConfig = env
             .storage()
@@ -729,8 +1291,7 @@ impl PaymentContract {
 
         let now = env.ledger().timestamp();
         let seconds_per_day: u64 = 86_400;
-        let grace_seconds = (config.grace_period_days as u64) * seconds_per_day;
-        let due_with_grace = agreement.next_payment_due.saturating_add(grace_seconds);
+        let due_with_grace = Self::late_fee_deadline(&env, &agreement, &config);
 
         if now <= due_with_grace {
             return Err(Error::PaymentNotLate);
@@ -769,6 +1330,50 @@ impl PaymentContract {
         Ok(record)
     }
 
+    /// Extend the late-fee grace period for one of a landlord's tenants, in seconds.
+    /// Scoped per landlord so the reward only applies to that landlord's leases.
+    pub fn set_tenant_grace_bonus(
+        env: Env,
+        landlord: Address,
+        tenant: Address,
+        bonus_seconds: u64,
+    ) {
+        landlord.require_auth();
+
+        let key = StorageKey::TenantGraceBonus(landlord, tenant);
+        if bonus_seconds == 0 {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &bonus_seconds);
+        }
+    }
+
+    /// Last moment rent can be paid before a late fee applies
+    fn late_fee_deadline(
+        env: &Env,
+        agreement: &RentAgreement,
+        config: &crate::types::LateFeeConfig,
+    ) -> u64 {
+        let grace_seconds = (config.grace_period_days as u64) * 86_400;
+        let grace_bonus = Self::get_tenant_grace_bonus(
+            env.clone(),
+            agreement.landlord.clone(),
+            agreement.tenant.clone(),
+        );
+        agreement
+            .next_payment_due
+            .saturating_add(grace_seconds)
+            .saturating_add(grace_bonus)
+    }
+
+    /// Extra grace, in seconds, a landlord has granted a tenant (0 when none).
+    pub fn get_tenant_grace_bonus(env: Env, landlord: Address, tenant: Address) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&StorageKey::TenantGraceBonus(landlord, tenant))
+            .unwrap_or(0)
+    }
+
     /// Retrieve a previously applied late fee record.
     pub fn get_late_fee_record(
         env: Env,
@@ -789,11 +1394,7 @@ impl PaymentContract {
     ) -> Result<(), Error> {
         use crate::types::LateFeeRecord;
 
-        let agreement: crate::types::RentAgreement = env
-            .storage()
-            .persistent()
-            .get(&StorageKey::Agreement(agreement_id))
-            .ok_or(Error::AgreementNotFound)?;
+        let agreement = payment_impl::load_agreement(&env, &agreement_id)?;
 
         agreement.landlord.require_auth();
 
@@ -1,9 +1,20 @@
 //! Payment processing implementation.
-use soroban_sdk::{Address, Env, String};
+use soroban_sdk::{Address, Env, String, Vec};
 
 use crate::errors::PaymentError;
 use crate::storage::DataKey;
-use crate::types::{AgreementStatus, PaymentRecord, RentAgreement};
+use crate::types::{AgreementStatus, PaymentRecord, RemainderBeneficiary, RentAgreement};
+
+/// Load an agreement, failing with `AgreementNotFound` if it does not exist
+pub(crate) fn load_agreement(
+    env: &Env,
+    agreement_id: &String,
+) -> Result<RentAgreement, PaymentError> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Agreement(agreement_id.clone()))
+        .ok_or(PaymentError::AgreementNotFound)
+}
 
 /// Create an immutable payment record
 pub fn create_payment_record(
@@ -27,14 +38,126 @@ pub fn create_payment_record(
     })
 }
 
+/// Parts-per-million in one basis point.
+const PPM_PER_BPS: u32 = 100;
+const PPM_DENOMINATOR: u32 = 1_000_000;
+
 /// Calculate payment split between landlord and agent
 pub fn calculate_payment_split(amount: &i128, commission_rate: &u32) -> (i128, i128) {
     // commission_rate is in basis points (1 basis point = 0.01%)
-    let agent_amount = (amount * (*commission_rate as i128)) / 10000;
+    calculate_payment_split_ppm(amount, &(commission_rate * PPM_PER_BPS))
+}
+
+/// Calculate payment split between landlord and agent with the commission in
+/// parts per million, for rates finer than a basis point
+pub fn calculate_payment_split_ppm(amount: &i128, commission_ppm: &u32) -> (i128, i128) {
+    let agent_amount = (amount * (*commission_ppm as i128)) / PPM_DENOMINATOR as i128;
     let landlord_amount = amount - agent_amount;
     (landlord_amount, agent_amount)
 }
 
+/// Divide an agent commission into `(agent, co_agent)` shares, the co-agent
+/// taking `co_agent_share_bps` (capped at 10000) rounded down.
+pub fn split_agent_commission(agent_amount: i128, co_agent_share_bps: u32) -> (i128, i128) {
+    let co_agent_amount = agent_amount * co_agent_share_bps.min(10_000) as i128 / 10_000;
+    (agent_amount - co_agent_amount, co_agent_amount)
+}
+
+/// Split rent into `(landlord, platform, agent)` shares from basis-point rates.
+///
+/// Each share rounds down and the rounding remainder goes to `beneficiary`,
+/// or to the landlord when the beneficiary takes no share of this payment.
+pub fn calculate_fee_split(
+    amount: i128,
+    platform_bps: u32,
+    agent_bps: u32,
+    beneficiary: &RemainderBeneficiary,
+) -> (i128, i128, i128) {
+    calculate_fee_split_ppm(
+        amount,
+        platform_bps * PPM_PER_BPS,
+        agent_bps * PPM_PER_BPS,
+        beneficiary,
+    )
+}
+
+/// `calculate_fee_split` with both rates in parts per million.
+pub fn calculate_fee_split_ppm(
+    amount: i128,
+    platform_ppm: u32,
+    agent_ppm: u32,
+    beneficiary: &RemainderBeneficiary,
+) -> (i128, i128, i128) {
+    let denominator = PPM_DENOMINATOR as i128;
+    let landlord_ppm = PPM_DENOMINATOR.saturating_sub(platform_ppm + agent_ppm);
+    let mut platform_amount = (amount * platform_ppm as i128) / denominator;
+    let mut agent_amount = (amount * agent_ppm as i128) / denominator;
+    let mut landlord_amount = (amount * landlord_ppm as i128) / denominator;
+
+    let remainder = amount - platform_amount - agent_amount - landlord_amount;
+    match beneficiary {
+        RemainderBeneficiary::FeeCollector if platform_ppm > 0 => platform_amount += remainder,
+        RemainderBeneficiary::Agent if agent_ppm > 0 => agent_amount += remainder,
+        _ => landlord_amount += remainder,
+    }
+    (landlord_amount, platform_amount, agent_amount)
+}
+
+/// Transfer tokens, reporting a failed transfer as `error` instead of trapping
+pub fn transfer_or(
+    client: &soroban_sdk::token::Client,
+    from: &Address,
+    to: &Address,
+    amount: i128,
+    error: PaymentError,
+) -> Result<(), PaymentError> {
+    match client.try_transfer(from, to, &amount) {
+        Ok(Ok(())) => Ok(()),
+        _ => Err(error),
+    }
+}
+
+/// Check that a split table is non-empty and its shares sum to exactly 10000 bps
+pub fn validate_split_table(table: &Vec<(Address, u32)>) -> Result<(), PaymentError> {
+    if table.is_empty() {
+        return Err(PaymentError::InvalidSplitTable);
+    }
+
+    let mut total: u32 = 0;
+    for (_, bps) in table.iter() {
+        total = total
+            .checked_add(bps)
+            .ok_or(PaymentError::InvalidSplitTable)?;
+    }
+
+    if total != 10000 {
+        return Err(PaymentError::InvalidSplitTable);
+    }
+    Ok(())
+}
+
+/// Divide `amount` across a split table; any rounding remainder goes to the last recipient
+pub fn calculate_table_split(
+    env: &Env,
+    amount: i128,
+    table: &Vec<(Address, u32)>,
+) -> Vec<(Address, i128)> {
+    let mut shares = Vec::new(env);
+    let mut allocated: i128 = 0;
+    let last = table.len().saturating_sub(1);
+
+    for (i, (recipient, bps)) in table.iter().enumerate() {
+        let share = if i as u32 == last {
+            amount - allocated
+        } else {
+            (amount * bps as i128) / 10000
+        };
+        allocated += share;
+        shares.push_back((recipient, share));
+    }
+    shares
+}
+
 /// Process rent payment with automatic commission splitting
 /// This is the alternate implementation used by RentalContract
 #[allow(deprecated)]
@@ -48,11 +171,7 @@ pub fn pay_rent_with_agent(
     use soroban_sdk::token::Client as TokenClient;
 
     // Load agreement
-    let mut agreement: RentAgreement = env
-        .storage()
-        .persistent()
-        .get(&DataKey::Agreement(agreement_id.clone()))
-        .ok_or(PaymentError::InvalidAmount)?;
+    let mut agreement = load_agreement(&env, &agreement_id)?;
 
     // Validate agreement is active
     if agreement.status != AgreementStatus::Active {
@@ -80,12 +199,24 @@ pub fn pay_rent_with_agent(
     let token_client = TokenClient::new(&env, &token);
 
     // Transfer to landlord
-    token_client.transfer(&agreement.tenant, &agreement.landlord, &landlord_amount);
+    transfer_or(
+        &token_client,
+        &agreement.tenant,
+        &agreement.landlord,
+        landlord_amount,
+        PaymentError::LandlordTransferFailed,
+    )?;
 
     // Transfer to agent if present
     if let Some(agent_address) = &agreement.agent {
         if agent_amount > 0 {
-            token_client.transfer(&agreement.tenant, agent_address, &agent_amount);
+            transfer_or(
+                &token_client,
+                &agreement.tenant,
+                agent_address,
+                agent_amount,
+                PaymentError::AgentTransferFailed,
+            )?;
         }
     }
 
@@ -33,4 +33,32 @@ pub enum DataKey {
     LateFeeConfig(String),
     /// Late fee record per payment
     LateFeeRecord(String),
+    /// Tiered (days-late threshold, fee bps) late fee schedule per agreement
+    LateFeeTiers(String),
+    /// Idempotency key already processed for an agreement's rent payment
+    ProcessedKey(String, String),
+    /// Basis-point disbursement recipients per agreement
+    SplitTable(String),
+    /// Consecutive bounced rent payments per agreement
+    FailedAttempts(String),
+    /// Bounced payments allowed before an agreement is disputed
+    FailedPaymentThreshold(String),
+    /// Platform fee is waived on rent paid before this timestamp
+    FeeHolidayUntil,
+    /// Extra late-fee grace (seconds) granted by a landlord to a tenant
+    TenantGraceBonus(soroban_sdk::Address, soroban_sdk::Address),
+    /// Party receiving the rounding remainder of rent splits
+    RemainderBeneficiary,
+    /// On-time and late rent payment counts per tenant
+    TenantStanding(soroban_sdk::Address),
+    /// Landlord-set cap on rent payments recorded per agreement
+    MaxPayments(String),
+    /// How an agreement's agent commission is paid out
+    CommissionMode(String),
+    /// Commission held for an agreement's agent, per token
+    AccruedCommission(String, soroban_sdk::Address),
+    /// Address allowed to pay rent on the tenant's behalf
+    TenantDelegate(String),
+    /// Agent commission in parts per million, overriding the basis-point rate
+    CommissionPpm(String),
 }
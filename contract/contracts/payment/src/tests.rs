@@ -26,6 +26,8 @@ fn create_test_agreement(
         agent,
         monthly_rent,
         agent_commission_rate: commission_rate,
+        co_agent: None,
+        co_agent_share_bps: 0,
         status,
         total_rent_paid: 0,
         payment_count: 0,
@@ -116,6 +118,25 @@ fn test_calculate_payment_split_2_5_percent() {
     assert_eq!(agent, 250);
 }
 
+#[test]
+fn test_calculate_payment_split_ppm_matches_bps_and_is_finer() {
+    let amount: i128 = 1_000_000_000_007;
+
+    // 2500 ppm and 25 bps are the same rate and split identically
+    let (landlord_ppm, agent_ppm) = calculate_payment_split_ppm(&amount, &2500);
+    let (landlord_bps, agent_bps) = calculate_payment_split(&amount, &25);
+    assert_eq!((landlord_ppm, agent_ppm), (landlord_bps, agent_bps));
+    assert_eq!(landlord_ppm + agent_ppm, amount);
+
+    // 0.2555% is only expressible in ppm; bps must round to 25 or 26
+    let (landlord, agent) = calculate_payment_split_ppm(&amount, &2555);
+    assert_eq!(agent, 2_555_000_000);
+    assert_eq!(landlord + agent, amount);
+    let (_, low) = calculate_payment_split(&amount, &25);
+    let (_, high) = calculate_payment_split(&amount, &26);
+    assert!(low < agent && agent < high);
+}
+
 #[test]
 fn test_create_payment_record() {
     let env = Env::default();
@@ -760,6 +781,55 @@ fn test_apply_late_fee_creates_record() {
     assert!(!record.waived);
 }
 
+#[test]
+fn test_tenant_grace_bonus_avoids_late_fee() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_payment_contract(&env);
+    let landlord = Address::generate(&env);
+    let good_tenant = Address::generate(&env);
+    let other_tenant = Address::generate(&env);
+    let token = create_token(&env, &Address::generate(&env));
+
+    for (key, tenant) in [("lf_bonus_1", &good_tenant), ("lf_bonus_2", &other_tenant)] {
+        let mut agreement = create_test_agreement(
+            &env,
+            key,
+            tenant,
+            &landlord,
+            None,
+            1000,
+            0,
+            AgreementStatus::Active,
+            token.clone(),
+        );
+        agreement.next_payment_due = 1000;
+        seed_agreement(&env, &client, key, &agreement);
+        client.set_late_fee_config(&String::from_str(&env, key), &5, &5, &0, &false);
+    }
+
+    // Two extra days of grace for the good tenant
+    client.set_tenant_grace_bonus(&landlord, &good_tenant, &(2 * 86_400));
+
+    // 1 day past the standard 5-day grace
+    env.ledger().with_mut(|li| {
+        li.timestamp = 1000 + 5 * 86_400 + 86_400 + 1;
+    });
+
+    let result = client.try_apply_late_fee(
+        &String::from_str(&env, "lf_bonus_1"),
+        &String::from_str(&env, "pay_bonus_1"),
+    );
+    assert_eq!(result, Err(Ok(crate::errors::PaymentError::PaymentNotLate)));
+
+    let record = client.apply_late_fee(
+        &String::from_str(&env, "lf_bonus_2"),
+        &String::from_str(&env, "pay_bonus_2"),
+    );
+    assert_eq!(record.late_fee, 50);
+}
+
 #[test]
 fn test_apply_late_fee_not_late_returns_error() {
     let env = Env::default();
@@ -930,3 +1000,810 @@ fn test_compounding_late_fee_via_contract() {
     );
     assert_eq!(fee, 276);
 }
+
+fn setup_rent_payment<'a>(
+    env: &'a Env,
+    agreement_key: &str,
+) -> (
+    crate::PaymentContractClient<'a>,
+    Address,
+    Address,
+    Address,
+    Address,
+) {
+    let client = create_payment_contract(env);
+    let tenant = Address::generate(env);
+    let landlord = Address::generate(env);
+    let collector = Address::generate(env);
+    let token_admin = Address::generate(env);
+    // Revocable so tests can deauthorize a recipient to make a transfer fail
+    let sac = env.register_stellar_asset_contract_v2(token_admin);
+    sac.issuer()
+        .set_flag(soroban_sdk::testutils::IssuerFlags::RevocableFlag);
+    let token = sac.address();
+    TokenAdminClient::new(env, &token).mint(&tenant, &10_000);
+
+    let agreement = create_test_agreement(
+        env,
+        agreement_key,
+        &tenant,
+        &landlord,
+        None,
+        1000,
+        0,
+        AgreementStatus::Active,
+        token.clone(),
+    );
+    seed_agreement(env, &client, agreement_key, &agreement);
+    client.set_platform_fee_collector(&collector);
+
+    (client, tenant, landlord, collector, token)
+}
+
+#[test]
+fn test_pay_rent_with_idempotency_key_moves_funds() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, tenant, landlord, collector, token) = setup_rent_payment(&env, "idem_agr_1");
+    let balances = soroban_sdk::token::Client::new(&env, &token);
+
+    client.pay_rent(
+        &tenant,
+        &String::from_str(&env, "idem_agr_1"),
+        &1000,
+        &Some(String::from_str(&env, "tx-001")),
+    );
+
+    assert_eq!(balances.balance(&tenant), 9_000);
+    assert_eq!(balances.balance(&landlord), 900);
+    assert_eq!(balances.balance(&collector), 100);
+}
+
+#[test]
+fn test_pay_rent_rejects_zero_and_negative_amounts() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, tenant, _landlord, _collector, token) = setup_rent_payment(&env, "zero_agr_1");
+    let balances = soroban_sdk::token::Client::new(&env, &token);
+    let agreement_id = String::from_str(&env, "zero_agr_1");
+
+    for amount in [0, -1000] {
+        assert_eq!(
+            client.try_pay_rent(&tenant, &agreement_id, &amount, &None),
+            Err(Ok(crate::errors::PaymentError::InvalidAmount))
+        );
+    }
+    assert_eq!(balances.balance(&tenant), 10_000);
+    assert!(client.try_get_last_payment(&agreement_id).is_err());
+}
+
+#[test]
+fn test_pay_rent_replayed_idempotency_key_does_not_double_charge() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, tenant, landlord, _collector, token) = setup_rent_payment(&env, "idem_agr_2");
+    let balances = soroban_sdk::token::Client::new(&env, &token);
+    let agreement_id = String::from_str(&env, "idem_agr_2");
+    let key = Some(String::from_str(&env, "tx-002"));
+
+    client.pay_rent(&tenant, &agreement_id, &1000, &key);
+    client.pay_rent(&tenant, &agreement_id, &1000, &key);
+
+    assert_eq!(balances.balance(&tenant), 9_000);
+    assert_eq!(balances.balance(&landlord), 900);
+    assert!(client.try_get_payment_split(&agreement_id, &1).is_err());
+}
+
+#[test]
+fn test_pay_rent_distributes_across_split_table() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, tenant, landlord, collector, token) = setup_rent_payment(&env, "split_agr_1");
+    let balances = soroban_sdk::token::Client::new(&env, &token);
+    let agreement_id = String::from_str(&env, "split_agr_1");
+    let manager = Address::generate(&env);
+    let hoa = Address::generate(&env);
+
+    let table = soroban_sdk::vec![
+        &env,
+        (landlord.clone(), 7000u32),
+        (manager.clone(), 2000u32),
+        (hoa.clone(), 1000u32),
+    ];
+    client.set_split_table(&agreement_id, &table);
+    client.pay_rent(&tenant, &agreement_id, &1000, &None);
+
+    assert_eq!(balances.balance(&landlord), 700);
+    assert_eq!(balances.balance(&manager), 200);
+    assert_eq!(balances.balance(&hoa), 100);
+    assert_eq!(balances.balance(&collector), 0);
+    assert_eq!(balances.balance(&tenant), 9_000);
+}
+
+#[test]
+fn test_split_table_must_sum_to_10000() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _tenant, landlord, _collector, _token) = setup_rent_payment(&env, "split_agr_2");
+    let agreement_id = String::from_str(&env, "split_agr_2");
+    let manager = Address::generate(&env);
+
+    let table = soroban_sdk::vec![&env, (landlord, 7000u32), (manager, 2000u32)];
+    let result = client.try_set_split_table(&agreement_id, &table);
+
+    assert_eq!(
+        result,
+        Err(Ok(crate::errors::PaymentError::InvalidSplitTable))
+    );
+    assert_eq!(client.get_split_table(&agreement_id), None);
+}
+
+#[test]
+fn test_bounced_payment_is_recorded_without_moving_funds() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _tenant, landlord, _collector, token) = setup_rent_payment(&env, "bounce_agr_1");
+    let balances = soroban_sdk::token::Client::new(&env, &token);
+    let agreement_id = String::from_str(&env, "bounce_agr_1");
+    let broke_tenant = Address::generate(&env);
+
+    let agreement = create_test_agreement(
+        &env,
+        "bounce_agr_1",
+        &broke_tenant,
+        &landlord,
+        None,
+        1000,
+        0,
+        AgreementStatus::Active,
+        token,
+    );
+    seed_agreement(&env, &client, "bounce_agr_1", &agreement);
+    client.set_failed_payment_threshold(&agreement_id, &3);
+
+    assert!(!client.pay_rent(&broke_tenant, &agreement_id, &1000, &None));
+    assert_eq!(client.get_failed_attempts(&agreement_id), 1);
+    assert_eq!(balances.balance(&landlord), 0);
+
+    // A successful payment clears the streak
+    TokenAdminClient::new(&env, &balances.address).mint(&broke_tenant, &1000);
+    assert!(client.pay_rent(&broke_tenant, &agreement_id, &1000, &None));
+    assert_eq!(client.get_failed_attempts(&agreement_id), 0);
+}
+
+#[test]
+fn test_underfunded_payment_fails_with_insufficient_balance() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _tenant, landlord, _collector, token) = setup_rent_payment(&env, "broke_agr_1");
+    let balances = soroban_sdk::token::Client::new(&env, &token);
+    let agreement_id = String::from_str(&env, "broke_agr_1");
+    let broke_tenant = Address::generate(&env);
+    TokenAdminClient::new(&env, &token).mint(&broke_tenant, &999);
+
+    let agreement = create_test_agreement(
+        &env,
+        "broke_agr_1",
+        &broke_tenant,
+        &landlord,
+        None,
+        1000,
+        0,
+        AgreementStatus::Active,
+        token,
+    );
+    seed_agreement(&env, &client, "broke_agr_1", &agreement);
+
+    assert_eq!(
+        client.try_pay_rent(&broke_tenant, &agreement_id, &1000, &None),
+        Err(Ok(crate::errors::PaymentError::InsufficientBalance))
+    );
+    assert_eq!(client.get_failed_attempts(&agreement_id), 0);
+    assert_eq!(balances.balance(&broke_tenant), 999);
+
+    // Topping up lets the same payment through
+    TokenAdminClient::new(&env, &balances.address).mint(&broke_tenant, &1);
+    assert!(client.pay_rent(&broke_tenant, &agreement_id, &1000, &None));
+    assert_eq!(balances.balance(&broke_tenant), 0);
+}
+
+#[test]
+fn test_repeated_bounces_dispute_agreement() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _tenant, landlord, _collector, token) = setup_rent_payment(&env, "bounce_agr_2");
+    let agreement_id = String::from_str(&env, "bounce_agr_2");
+    let broke_tenant = Address::generate(&env);
+
+    let agreement = create_test_agreement(
+        &env,
+        "bounce_agr_2",
+        &broke_tenant,
+        &landlord,
+        None,
+        1000,
+        0,
+        AgreementStatus::Active,
+        token,
+    );
+    seed_agreement(&env, &client, "bounce_agr_2", &agreement);
+    client.set_failed_payment_threshold(&agreement_id, &2);
+
+    assert!(!client.pay_rent(&broke_tenant, &agreement_id, &1000, &None));
+    assert!(!client.pay_rent(&broke_tenant, &agreement_id, &1000, &None));
+
+    let stored: RentAgreement = env.as_contract(&client.address, || {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Agreement(agreement_id.clone()))
+            .unwrap()
+    });
+    assert_eq!(stored.status, AgreementStatus::Disputed);
+
+    // Further attempts are rejected once disputed
+    assert_eq!(
+        client.try_pay_rent(&broke_tenant, &agreement_id, &1000, &None),
+        Err(Ok(crate::errors::PaymentError::AgreementNotActive))
+    );
+}
+
+#[test]
+fn test_pay_rent_during_fee_holiday_skips_platform_fee() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, tenant, landlord, collector, token) = setup_rent_payment(&env, "holiday_agr_1");
+    let balances = soroban_sdk::token::Client::new(&env, &token);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    client.set_fee_holiday(&5_000);
+    client.pay_rent(
+        &tenant,
+        &String::from_str(&env, "holiday_agr_1"),
+        &1000,
+        &None,
+    );
+
+    assert_eq!(balances.balance(&landlord), 1000);
+    assert_eq!(balances.balance(&collector), 0);
+}
+
+#[test]
+fn test_pay_rent_after_fee_holiday_charges_platform_fee() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, tenant, landlord, collector, token) = setup_rent_payment(&env, "holiday_agr_2");
+    let balances = soroban_sdk::token::Client::new(&env, &token);
+
+    client.set_fee_holiday(&5_000);
+    env.ledger().with_mut(|li| li.timestamp = 5_000);
+    client.pay_rent(
+        &tenant,
+        &String::from_str(&env, "holiday_agr_2"),
+        &1000,
+        &None,
+    );
+
+    assert_eq!(balances.balance(&landlord), 900);
+    assert_eq!(balances.balance(&collector), 100);
+}
+
+#[test]
+fn test_failed_landlord_transfer_reports_specific_error() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, tenant, landlord, collector, token) = setup_rent_payment(&env, "xfer_agr_1");
+    let balances = soroban_sdk::token::Client::new(&env, &token);
+    let agreement_id = String::from_str(&env, "xfer_agr_1");
+
+    // A deauthorized landlord balance makes the landlord leg fail
+    TokenAdminClient::new(&env, &token).set_authorized(&landlord, &false);
+
+    let result = client.try_pay_rent(&tenant, &agreement_id, &1000, &None);
+    assert_eq!(
+        result,
+        Err(Ok(crate::errors::PaymentError::LandlordTransferFailed))
+    );
+
+    assert_eq!(balances.balance(&tenant), 10_000);
+    assert_eq!(balances.balance(&collector), 0);
+    assert!(client.try_get_payment_split(&agreement_id, &0).is_err());
+}
+
+#[test]
+fn test_failed_split_recipient_transfer_reports_agent_error() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, tenant, landlord, _collector, token) = setup_rent_payment(&env, "xfer_agr_2");
+    let balances = soroban_sdk::token::Client::new(&env, &token);
+    let agreement_id = String::from_str(&env, "xfer_agr_2");
+    let agent = Address::generate(&env);
+
+    client.set_split_table(
+        &agreement_id,
+        &soroban_sdk::vec![&env, (landlord.clone(), 9000u32), (agent.clone(), 1000u32)],
+    );
+    TokenAdminClient::new(&env, &token).set_authorized(&agent, &false);
+
+    let result = client.try_pay_rent(&tenant, &agreement_id, &1000, &None);
+    assert_eq!(
+        result,
+        Err(Ok(crate::errors::PaymentError::AgentTransferFailed))
+    );
+    assert_eq!(balances.balance(&landlord), 0);
+    assert_eq!(balances.balance(&tenant), 10_000);
+}
+
+#[test]
+fn test_tenant_standing_defaults_to_zero() {
+    let env = Env::default();
+    let client = create_payment_contract(&env);
+
+    assert_eq!(client.get_tenant_standing(&Address::generate(&env)), (0, 0));
+}
+
+#[test]
+fn test_tenant_standing_counts_on_time_and_late_payments() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, tenant, _landlord, _collector, _token) = setup_rent_payment(&env, "standing");
+    let agreement_id = String::from_str(&env, "standing");
+    client.set_late_fee_config(&agreement_id, &5, &5, &0, &false);
+
+    // Paid on the due date
+    client.pay_rent(&tenant, &agreement_id, &1000, &None);
+    assert_eq!(client.get_tenant_standing(&tenant), (1, 0));
+
+    // Next period paid six days late, past the five-day grace window
+    env.ledger()
+        .with_mut(|l| l.timestamp = 2_592_000 + 6 * 86_400);
+    client.pay_rent(&tenant, &agreement_id, &1000, &None);
+    assert_eq!(client.get_tenant_standing(&tenant), (1, 1));
+}
+
+/// Seed an agent-brokered 1001 rent agreement whose 10% platform fee and 2.5%
+/// commission leave a one-unit rounding remainder.
+fn setup_three_way_split<'a>(
+    env: &'a Env,
+) -> (
+    crate::PaymentContractClient<'a>,
+    Address,
+    Address,
+    Address,
+    Address,
+    soroban_sdk::token::Client<'a>,
+) {
+    let client = create_payment_contract(env);
+    let tenant = Address::generate(env);
+    let landlord = Address::generate(env);
+    let agent = Address::generate(env);
+    let collector = Address::generate(env);
+    let token = create_token(env, &Address::generate(env));
+    TokenAdminClient::new(env, &token).mint(&tenant, &10_000);
+
+    let agreement = create_test_agreement(
+        env,
+        "split3",
+        &tenant,
+        &landlord,
+        Some(agent.clone()),
+        1001,
+        250,
+        AgreementStatus::Active,
+        token.clone(),
+    );
+    seed_agreement(env, &client, "split3", &agreement);
+    client.set_platform_fee_collector(&collector);
+
+    let balances = soroban_sdk::token::Client::new(env, &token);
+    (client, tenant, landlord, agent, collector, balances)
+}
+
+#[test]
+fn test_rounding_remainder_goes_to_landlord_by_default() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, tenant, landlord, agent, collector, balances) = setup_three_way_split(&env);
+
+    client.pay_rent(&tenant, &String::from_str(&env, "split3"), &1001, &None);
+
+    assert_eq!(balances.balance(&landlord), 876);
+    assert_eq!(balances.balance(&collector), 100);
+    assert_eq!(balances.balance(&agent), 25);
+    assert_eq!(balances.balance(&tenant), 10_000 - 1001);
+}
+
+#[test]
+fn test_rounding_remainder_goes_to_configured_beneficiary() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, tenant, landlord, agent, collector, balances) = setup_three_way_split(&env);
+    client.set_remainder_beneficiary(&crate::RemainderBeneficiary::Agent);
+
+    client.pay_rent(&tenant, &String::from_str(&env, "split3"), &1001, &None);
+
+    assert_eq!(balances.balance(&landlord), 875);
+    assert_eq!(balances.balance(&collector), 100);
+    assert_eq!(balances.balance(&agent), 26);
+    assert_eq!(
+        balances.balance(&landlord) + balances.balance(&collector) + balances.balance(&agent),
+        1001
+    );
+}
+
+#[test]
+fn test_amount_due_applies_late_fee_tiers() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _tenant, _landlord, _collector, _token) = setup_rent_payment(&env, "tiers");
+    let agreement_id = String::from_str(&env, "tiers");
+
+    // 2% from three days late, 5% from ten days late
+    client.set_late_fee_tiers(
+        &agreement_id,
+        &soroban_sdk::vec![&env, (3u64, 200u32), (10u64, 500u32)],
+    );
+
+    assert_eq!(client.amount_due(&agreement_id), 1000);
+
+    env.ledger().with_mut(|l| l.timestamp = 2 * 86_400);
+    assert_eq!(client.amount_due(&agreement_id), 1000);
+
+    env.ledger().with_mut(|l| l.timestamp = 3 * 86_400);
+    assert_eq!(client.amount_due(&agreement_id), 1020);
+
+    env.ledger().with_mut(|l| l.timestamp = 12 * 86_400);
+    assert_eq!(client.amount_due(&agreement_id), 1050);
+}
+
+#[test]
+fn test_amount_due_overflow_is_reported() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, tenant, landlord, _collector, token) = setup_rent_payment(&env, "huge");
+    let agreement_id = String::from_str(&env, "huge");
+
+    let agreement = create_test_agreement(
+        &env,
+        "huge",
+        &tenant,
+        &landlord,
+        None,
+        i128::MAX / 2,
+        0,
+        AgreementStatus::Active,
+        token,
+    );
+    seed_agreement(&env, &client, "huge", &agreement);
+    client.set_late_fee_tiers(&agreement_id, &soroban_sdk::vec![&env, (1u64, 5000u32)]);
+
+    // Hundreds of days overdue at a 50% tier
+    env.ledger().with_mut(|l| l.timestamp = 400 * 86_400);
+    assert_eq!(
+        client.try_amount_due(&agreement_id),
+        Err(Ok(crate::errors::PaymentError::Overflow))
+    );
+}
+
+#[test]
+fn test_pay_rent_charges_tiered_late_fee() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, tenant, landlord, collector, token) = setup_rent_payment(&env, "tiers");
+    let agreement_id = String::from_str(&env, "tiers");
+    let balances = soroban_sdk::token::Client::new(&env, &token);
+
+    client.set_late_fee_tiers(&agreement_id, &soroban_sdk::vec![&env, (3u64, 200u32)]);
+    env.ledger().with_mut(|l| l.timestamp = 5 * 86_400);
+
+    // Plain rent no longer settles an overdue period
+    assert_eq!(
+        client.try_pay_rent(&tenant, &agreement_id, &1000, &None),
+        Err(Ok(crate::errors::PaymentError::InvalidPaymentAmount))
+    );
+
+    client.pay_rent(&tenant, &agreement_id, &1020, &None);
+
+    // The platform fee is taken on the rent; the late fee goes to the landlord
+    assert_eq!(balances.balance(&tenant), 10_000 - 1020);
+    assert_eq!(balances.balance(&collector), 100);
+    assert_eq!(balances.balance(&landlord), 920);
+}
+
+#[test]
+fn test_unsorted_late_fee_tiers_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _tenant, _landlord, _collector, _token) = setup_rent_payment(&env, "tiers");
+    let agreement_id = String::from_str(&env, "tiers");
+
+    let result = client.try_set_late_fee_tiers(
+        &agreement_id,
+        &soroban_sdk::vec![&env, (10u64, 500u32), (3u64, 200u32)],
+    );
+
+    assert_eq!(
+        result,
+        Err(Ok(crate::errors::PaymentError::InvalidLateFeeTiers))
+    );
+    assert!(client.get_late_fee_tiers(&agreement_id).is_none());
+}
+
+#[test]
+fn test_pay_rent_stops_at_max_payments() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, tenant, _landlord, _collector, _token) = setup_rent_payment(&env, "cap_agr_1");
+    let agreement_id = String::from_str(&env, "cap_agr_1");
+    assert_eq!(client.get_max_payments(&agreement_id), None);
+    client.set_max_payments(&agreement_id, &2);
+
+    assert!(client.pay_rent(&tenant, &agreement_id, &1000, &None));
+    env.ledger().with_mut(|li| li.timestamp += 2_592_000);
+    assert!(client.pay_rent(&tenant, &agreement_id, &1000, &None));
+
+    env.ledger().with_mut(|li| li.timestamp += 2_592_000);
+    assert_eq!(
+        client.try_pay_rent(&tenant, &agreement_id, &1000, &None),
+        Err(Ok(crate::errors::PaymentError::PaymentLimitReached))
+    );
+}
+
+#[test]
+fn test_max_payments_derived_from_term() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, tenant, landlord, _collector, token) = setup_rent_payment(&env, "cap_agr_2");
+    let agreement_id = String::from_str(&env, "cap_agr_2");
+    let mut agreement = create_test_agreement(
+        &env,
+        "cap_agr_2",
+        &tenant,
+        &landlord,
+        None,
+        1000,
+        0,
+        AgreementStatus::Active,
+        token,
+    );
+    // One full period plus a partial one
+    agreement.end_date = 2_592_000 + 1;
+    seed_agreement(&env, &client, "cap_agr_2", &agreement);
+    assert_eq!(client.get_max_payments(&agreement_id), Some(2));
+
+    assert!(client.pay_rent(&tenant, &agreement_id, &1000, &None));
+    env.ledger().with_mut(|li| li.timestamp += 2_592_000);
+    assert!(client.pay_rent(&tenant, &agreement_id, &1000, &None));
+
+    env.ledger().with_mut(|li| li.timestamp += 2_592_000);
+    assert_eq!(
+        client.try_pay_rent(&tenant, &agreement_id, &1000, &None),
+        Err(Ok(crate::errors::PaymentError::PaymentLimitReached))
+    );
+}
+
+fn setup_co_agent_split<'a>(
+    env: &'a Env,
+    co_agent: Option<Address>,
+    co_agent_share_bps: u32,
+) -> (
+    crate::PaymentContractClient<'a>,
+    Address,
+    Address,
+    soroban_sdk::token::Client<'a>,
+) {
+    let client = create_payment_contract(env);
+    let tenant = Address::generate(env);
+    let landlord = Address::generate(env);
+    let agent = Address::generate(env);
+    let token = create_token(env, &Address::generate(env));
+    TokenAdminClient::new(env, &token).mint(&tenant, &10_000);
+
+    let mut agreement = create_test_agreement(
+        env,
+        "co_agent",
+        &tenant,
+        &landlord,
+        Some(agent.clone()),
+        1000,
+        1000,
+        AgreementStatus::Active,
+        token.clone(),
+    );
+    agreement.co_agent = co_agent;
+    agreement.co_agent_share_bps = co_agent_share_bps;
+    seed_agreement(env, &client, "co_agent", &agreement);
+    client.set_platform_fee_collector(&Address::generate(env));
+
+    let balances = soroban_sdk::token::Client::new(env, &token);
+    (client, tenant, agent, balances)
+}
+
+#[test]
+fn test_co_agent_splits_agent_commission_evenly() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let co_agent = Address::generate(&env);
+    let (client, tenant, agent, balances) =
+        setup_co_agent_split(&env, Some(co_agent.clone()), 5000);
+
+    client.pay_rent(&tenant, &String::from_str(&env, "co_agent"), &1000, &None);
+
+    // 10% commission of 1000, halved
+    assert_eq!(balances.balance(&agent), 50);
+    assert_eq!(balances.balance(&co_agent), 50);
+    let record = client.get_last_payment(&String::from_str(&env, "co_agent"));
+    assert_eq!(record.agent_amount, 100);
+}
+
+#[test]
+fn test_without_co_agent_primary_agent_takes_full_commission() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, tenant, agent, balances) = setup_co_agent_split(&env, None, 5000);
+
+    client.pay_rent(&tenant, &String::from_str(&env, "co_agent"), &1000, &None);
+
+    assert_eq!(balances.balance(&agent), 100);
+}
+
+#[test]
+fn test_accrued_commission_claimed_in_one_transfer() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, tenant, _landlord, agent, _collector, balances) = setup_three_way_split(&env);
+    let agreement_id = String::from_str(&env, "split3");
+    client.set_commission_mode(&agreement_id, &crate::types::CommissionMode::Accrue);
+
+    client.pay_rent(&tenant, &agreement_id, &1001, &None);
+    env.ledger().with_mut(|li| li.timestamp += 2_592_000);
+    client.pay_rent(&tenant, &agreement_id, &1001, &None);
+
+    // Commission is held by the contract, not paid out
+    assert_eq!(balances.balance(&agent), 0);
+    assert_eq!(
+        client.get_accrued_commission(&agreement_id, &balances.address),
+        50
+    );
+    assert_eq!(balances.balance(&client.address), 50);
+
+    let claimed = client.claim_commission(&agreement_id, &agent, &balances.address);
+    assert_eq!(claimed, 50);
+    assert_eq!(balances.balance(&agent), 50);
+    assert_eq!(
+        client.get_accrued_commission(&agreement_id, &balances.address),
+        0
+    );
+}
+
+#[test]
+fn test_claim_commission_rejects_other_callers() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _tenant, landlord, _agent, _collector, balances) = setup_three_way_split(&env);
+
+    assert_eq!(
+        client.try_claim_commission(
+            &String::from_str(&env, "split3"),
+            &landlord,
+            &balances.address
+        ),
+        Err(Ok(crate::errors::PaymentError::NotAgent))
+    );
+}
+
+#[test]
+fn test_delegate_can_pay_rent_for_tenant() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, tenant, landlord, _collector, token) = setup_rent_payment(&env, "delegated");
+    let agreement_id = String::from_str(&env, "delegated");
+    let delegate = Address::generate(&env);
+    TokenAdminClient::new(&env, &token).mint(&delegate, &1000);
+
+    client.set_tenant_delegate(&agreement_id, &Some(delegate.clone()));
+    assert_eq!(
+        client.get_tenant_delegate(&agreement_id),
+        Some(delegate.clone())
+    );
+
+    assert!(client.pay_rent(&delegate, &agreement_id, &1000, &None));
+
+    let balances = soroban_sdk::token::Client::new(&env, &token);
+    assert_eq!(balances.balance(&delegate), 0);
+    assert_eq!(balances.balance(&tenant), 10_000);
+    assert_eq!(balances.balance(&landlord), 900);
+    assert_eq!(client.get_tenant_standing(&tenant), (1, 0));
+}
+
+#[test]
+fn test_non_delegate_cannot_pay_rent() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _tenant, _landlord, _collector, token) = setup_rent_payment(&env, "undelegated");
+    let agreement_id = String::from_str(&env, "undelegated");
+    let delegate = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    TokenAdminClient::new(&env, &token).mint(&stranger, &1000);
+
+    client.set_tenant_delegate(&agreement_id, &Some(delegate));
+
+    assert_eq!(
+        client.try_pay_rent(&stranger, &agreement_id, &1000, &None),
+        Err(Ok(crate::errors::PaymentError::NotTenant))
+    );
+}
+
+#[test]
+fn test_get_last_payment_returns_latest_record() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, tenant, _landlord, _collector, _token) = setup_rent_payment(&env, "latest");
+    let agreement_id = String::from_str(&env, "latest");
+
+    assert_eq!(
+        client.try_get_last_payment(&agreement_id),
+        Err(Ok(crate::errors::PaymentError::PaymentNotFound))
+    );
+
+    client.pay_rent(&tenant, &agreement_id, &1000, &None);
+    env.ledger().with_mut(|l| l.timestamp += 2_592_000);
+    client.pay_rent(&tenant, &agreement_id, &1000, &None);
+
+    let last = client.get_last_payment(&agreement_id);
+    assert_eq!(last.payment_number, 2);
+    assert_eq!(last.amount, 1000);
+    assert_eq!(last.landlord_amount, 900);
+    assert_eq!(last.tenant, tenant);
+    assert_eq!(last.timestamp, env.ledger().timestamp());
+}
+
+#[test]
+fn test_missing_agreement_reports_agreement_not_found() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_payment_contract(&env);
+    let missing = String::from_str(&env, "missing");
+    let not_found = crate::errors::PaymentError::AgreementNotFound;
+
+    assert_eq!(client.try_amount_due(&missing), Err(Ok(not_found)));
+    assert_eq!(client.try_get_last_payment(&missing), Err(Ok(not_found)));
+    assert_eq!(
+        client.try_set_tenant_delegate(&missing, &None),
+        Err(Ok(not_found))
+    );
+    assert_eq!(
+        client.try_set_max_payments(&missing, &3),
+        Err(Ok(not_found))
+    );
+}
+
+#[test]
+fn test_commission_ppm_override_applies_to_rent_split() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, tenant, landlord, agent, collector, balances) = setup_three_way_split(&env);
+    let agreement_id = String::from_str(&env, "split3");
+
+    client.set_commission_ppm(&agreement_id, &Some(30_000));
+    assert_eq!(client.get_commission_ppm(&agreement_id), Some(30_000));
+
+    client.pay_rent(&tenant, &agreement_id, &1001, &None);
+
+    assert_eq!(balances.balance(&agent), 30);
+    assert_eq!(balances.balance(&collector), 100);
+    assert_eq!(balances.balance(&landlord), 871);
+}
@@ -21,6 +21,8 @@ fn create_test_agreement(
         agent: None,
         monthly_rent,
         agent_commission_rate: 0,
+        co_agent: None,
+        co_agent_share_bps: 0,
         status: AgreementStatus::Active,
         total_rent_paid: 0,
         payment_count: 0,
@@ -53,6 +53,25 @@ pub struct PaymentSplit {
     pub payment_date: u64,
 }
 
+/// Party that receives the rounding remainder of a rent split
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RemainderBeneficiary {
+    Landlord,
+    FeeCollector,
+    Agent,
+}
+
+/// How an agreement's agent commission is paid out
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CommissionMode {
+    /// Transferred to the agent with each rent payment
+    Instant,
+    /// Held by the contract until the agent claims it
+    Accrue,
+}
+
 /// Agreement status enum (needed for payment validation)
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -79,6 +98,10 @@ pub struct RentAgreement {
     pub start_date: u64,
     pub end_date: u64,
     pub agent_commission_rate: u32,
+    /// Second agent splitting the commission with `agent`.
+    pub co_agent: Option<Address>,
+    /// Co-agent's share of the agent commission, in basis points.
+    pub co_agent_share_bps: u32,
     pub status: AgreementStatus,
     pub total_rent_paid: i128,
     pub payment_count: u32,
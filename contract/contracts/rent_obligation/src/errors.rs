@@ -14,4 +14,8 @@ pub enum ObligationError {
     BurnRecordNotFound = 8,
     CannotBurnActiveObligation = 9,
     InvalidBurnReason = 10,
+    InvalidAmount = 11,
+    TokenUriTooLong = 12,
+    ChiomaNotSet = 14,
+    Locked = 15,
 }
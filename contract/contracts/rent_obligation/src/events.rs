@@ -26,6 +26,17 @@ pub struct ObligationTransferred {
     pub agreement_id: String,
 }
 
+/// Event emitted when an owner approves a spender for an obligation
+/// Topics: ["approved", owner: Address, spender: Address]
+#[contractevent(topics = ["approved"])]
+pub struct ObligationApproved {
+    #[topic]
+    pub owner: Address,
+    #[topic]
+    pub spender: Address,
+    pub agreement_id: String,
+}
+
 /// Event emitted when a rent obligation NFT is burned
 /// Topics: ["burned", owner: Address]
 #[contractevent(topics = ["burned"])]
@@ -71,3 +82,18 @@ pub(crate) fn nft_burned(env: &Env, token_id: String, owner: Address, reason: St
     }
     .publish(env);
 }
+
+/// Helper function to emit obligation approved event
+pub(crate) fn obligation_approved(
+    env: &Env,
+    agreement_id: String,
+    owner: Address,
+    spender: Address,
+) {
+    ObligationApproved {
+        owner,
+        spender,
+        agreement_id,
+    }
+    .publish(env);
+}
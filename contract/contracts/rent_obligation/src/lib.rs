@@ -1,6 +1,8 @@
 #![no_std]
 
-use soroban_sdk::{contract, contractimpl, Address, Env, String, Vec};
+use soroban_sdk::{
+    contract, contractimpl, vec, Address, Env, IntoVal, Map, String, Symbol, Val, Vec,
+};
 
 mod errors;
 mod events;
@@ -14,6 +16,11 @@ pub use errors::ObligationError;
 pub use storage::DataKey;
 pub use types::{BurnRecord, RentObligation};
 
+const NAME: &str = "Chioma Rent Obligation";
+const SYMBOL: &str = "CRO";
+const BASE_URI: &str = "chioma://obligation/";
+const MAX_TOKEN_URI_LEN: usize = 256;
+
 #[contract]
 pub struct TokenizedRentObligationContract;
 
@@ -28,13 +35,26 @@ impl TokenizedRentObligationContract {
 
     /// Initialize the contract.
     ///
+    /// # Arguments
+    /// * `chioma` - Address of the chioma rental agreement contract holding
+    ///   the underlying agreements, if any. It can only be linked here.
+    ///
     /// # Errors
     /// * `AlreadyInitialized` - If the contract has already been initialized
-    pub fn initialize(env: Env) -> Result<(), ObligationError> {
+    pub fn initialize(env: Env, chioma: Option<Address>) -> Result<(), ObligationError> {
         if env.storage().persistent().has(&DataKey::Initialized) {
             return Err(ObligationError::AlreadyInitialized);
         }
 
+        if let Some(chioma) = chioma {
+            env.storage()
+                .persistent()
+                .set(&DataKey::ChiomaContract, &chioma);
+            env.storage()
+                .persistent()
+                .extend_ttl(&DataKey::ChiomaContract, 500000, 500000);
+        }
+
         env.storage().persistent().set(&DataKey::Initialized, &true);
         env.storage()
             .persistent()
@@ -47,9 +67,75 @@ impl TokenizedRentObligationContract {
             .persistent()
             .extend_ttl(&DataKey::ObligationCount, 500000, 500000);
 
+        env.storage()
+            .instance()
+            .set(&DataKey::Name, &String::from_str(&env, NAME));
+        env.storage()
+            .instance()
+            .set(&DataKey::Symbol, &String::from_str(&env, SYMBOL));
+        env.storage()
+            .instance()
+            .set(&DataKey::BaseUri, &String::from_str(&env, BASE_URI));
+        env.storage().instance().extend_ttl(500000, 500000);
+
         Ok(())
     }
 
+    /// Collection name shown by wallets.
+    ///
+    /// # Errors
+    /// * `NotInitialized` - If contract hasn't been initialized
+    pub fn name(env: Env) -> Result<String, ObligationError> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Name)
+            .ok_or(ObligationError::NotInitialized)
+    }
+
+    /// Collection ticker symbol shown by wallets.
+    ///
+    /// # Errors
+    /// * `NotInitialized` - If contract hasn't been initialized
+    pub fn symbol(env: Env) -> Result<String, ObligationError> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Symbol)
+            .ok_or(ObligationError::NotInitialized)
+    }
+
+    /// Metadata URI for an obligation: the base URI followed by its agreement ID.
+    ///
+    /// # Errors
+    /// * `NotInitialized` - If contract hasn't been initialized
+    /// * `ObligationNotFound` - If the obligation doesn't exist
+    /// * `TokenUriTooLong` - If the resulting URI exceeds 256 bytes
+    pub fn token_uri(env: Env, agreement_id: String) -> Result<String, ObligationError> {
+        let base: String = env
+            .storage()
+            .instance()
+            .get(&DataKey::BaseUri)
+            .ok_or(ObligationError::NotInitialized)?;
+
+        if !env
+            .storage()
+            .persistent()
+            .has(&DataKey::Obligation(agreement_id.clone()))
+        {
+            return Err(ObligationError::ObligationNotFound);
+        }
+
+        let base_len = base.len() as usize;
+        let total_len = base_len + agreement_id.len() as usize;
+        if total_len > MAX_TOKEN_URI_LEN {
+            return Err(ObligationError::TokenUriTooLong);
+        }
+
+        let mut buf = [0u8; MAX_TOKEN_URI_LEN];
+        base.copy_into_slice(&mut buf[..base_len]);
+        agreement_id.copy_into_slice(&mut buf[base_len..total_len]);
+        Ok(String::from_bytes(&env, &buf[..total_len]))
+    }
+
     /// Mint a new tokenized rent obligation NFT for a rent agreement.
     ///
     /// # Arguments
@@ -63,6 +149,35 @@ impl TokenizedRentObligationContract {
         env: Env,
         agreement_id: String,
         landlord: Address,
+    ) -> Result<(), ObligationError> {
+        Self::mint(env, agreement_id, landlord, 0)
+    }
+
+    /// Mint a rent obligation NFT that cannot be transferred before
+    /// `lockup_until`, to keep it from being flipped straight away.
+    ///
+    /// # Arguments
+    /// * `agreement_id` - Unique identifier for the rent agreement
+    /// * `landlord` - Address of the landlord who will receive the NFT
+    /// * `lockup_until` - Timestamp from which transfers are allowed (0 = no lockup)
+    ///
+    /// # Errors
+    /// * `NotInitialized` - If contract hasn't been initialized
+    /// * `ObligationAlreadyExists` - If an obligation for this agreement already exists
+    pub fn mint_locked_obligation(
+        env: Env,
+        agreement_id: String,
+        landlord: Address,
+        lockup_until: u64,
+    ) -> Result<(), ObligationError> {
+        Self::mint(env, agreement_id, landlord, lockup_until)
+    }
+
+    fn mint(
+        env: Env,
+        agreement_id: String,
+        landlord: Address,
+        lockup_until: u64,
     ) -> Result<(), ObligationError> {
         if !env.storage().persistent().has(&DataKey::Initialized) {
             return Err(ObligationError::NotInitialized);
@@ -81,6 +196,9 @@ impl TokenizedRentObligationContract {
             agreement_id: agreement_id.clone(),
             owner: landlord.clone(),
             minted_at: env.ledger().timestamp(),
+            monthly_rent: 0,
+            remaining_payments: 0,
+            lockup_until,
         };
 
         env.storage().persistent().set(&obligation_key, &obligation);
@@ -93,6 +211,24 @@ impl TokenizedRentObligationContract {
             .persistent()
             .extend_ttl(&owner_key, 500000, 500000);
 
+        // Index slots are never reused, so burned obligations leave gaps
+        let index: u32 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::IndexedCount)
+            .unwrap_or(0);
+        let index_key = DataKey::ObligationIndex(index);
+        env.storage().persistent().set(&index_key, &agreement_id);
+        env.storage()
+            .persistent()
+            .extend_ttl(&index_key, 500000, 500000);
+        env.storage()
+            .persistent()
+            .set(&DataKey::IndexedCount, &(index + 1));
+        env.storage()
+            .persistent()
+            .extend_ttl(&DataKey::IndexedCount, 500000, 500000);
+
         let mut count: u32 = env
             .storage()
             .persistent()
@@ -111,17 +247,71 @@ impl TokenizedRentObligationContract {
         Ok(())
     }
 
+    /// Approve `spender` to transfer an obligation on the owner's behalf.
+    ///
+    /// Replaces any previous approval; it is cleared on the next transfer.
+    ///
+    /// # Arguments
+    /// * `owner` - Current owner of the obligation
+    /// * `spender` - Address allowed to transfer it
+    /// * `agreement_id` - Agreement identifier for the obligation
+    ///
+    /// # Errors
+    /// * `NotInitialized` - If contract hasn't been initialized
+    /// * `ObligationNotFound` - If the obligation doesn't exist
+    /// * `Unauthorized` - If `owner` is not the current owner
+    pub fn approve(
+        env: Env,
+        owner: Address,
+        spender: Address,
+        agreement_id: String,
+    ) -> Result<(), ObligationError> {
+        if !env.storage().persistent().has(&DataKey::Initialized) {
+            return Err(ObligationError::NotInitialized);
+        }
+
+        owner.require_auth();
+
+        let obligation: RentObligation = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Obligation(agreement_id.clone()))
+            .ok_or(ObligationError::ObligationNotFound)?;
+
+        if obligation.owner != owner {
+            return Err(ObligationError::Unauthorized);
+        }
+
+        let approval_key = DataKey::Approval(agreement_id.clone());
+        env.storage().persistent().set(&approval_key, &spender);
+        env.storage()
+            .persistent()
+            .extend_ttl(&approval_key, 500000, 500000);
+
+        events::obligation_approved(&env, agreement_id, owner, spender);
+
+        Ok(())
+    }
+
+    /// Get the address approved to transfer an obligation, if any.
+    pub fn get_approved(env: Env, agreement_id: String) -> Option<Address> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Approval(agreement_id))
+    }
+
     /// Transfer ownership of a tokenized rent obligation to another address.
     ///
     /// # Arguments
-    /// * `from` - Current owner of the obligation
+    /// * `from` - Current owner of the obligation, or its approved spender
     /// * `to` - New owner to transfer to
     /// * `agreement_id` - Agreement identifier for the obligation
     ///
     /// # Errors
     /// * `NotInitialized` - If contract hasn't been initialized
     /// * `ObligationNotFound` - If the obligation doesn't exist
-    /// * `Unauthorized` - If the caller is not the current owner
+    /// * `Unauthorized` - If the caller is neither the owner nor approved
+    /// * `Locked` - If the obligation's lockup has not yet passed
     pub fn transfer_obligation(
         env: Env,
         from: Address,
@@ -143,11 +333,21 @@ impl TokenizedRentObligationContract {
             .get(&obligation_key)
             .ok_or(ObligationError::ObligationNotFound)?;
 
+        let approval_key = DataKey::Approval(agreement_id.clone());
         if obligation.owner != from {
-            return Err(ObligationError::Unauthorized);
+            let approved: Option<Address> = env.storage().persistent().get(&approval_key);
+            if approved != Some(from.clone()) {
+                return Err(ObligationError::Unauthorized);
+            }
         }
 
+        if env.ledger().timestamp() < obligation.lockup_until {
+            return Err(ObligationError::Locked);
+        }
+
+        let owner = obligation.owner.clone();
         obligation.owner = to.clone();
+        env.storage().persistent().remove(&approval_key);
 
         env.storage().persistent().set(&obligation_key, &obligation);
         env.storage()
@@ -159,7 +359,7 @@ impl TokenizedRentObligationContract {
             .persistent()
             .extend_ttl(&owner_key, 500000, 500000);
 
-        events::obligation_transferred(&env, agreement_id, from, to);
+        events::obligation_transferred(&env, agreement_id, owner, to);
 
         Ok(())
     }
@@ -188,6 +388,48 @@ impl TokenizedRentObligationContract {
         env.storage().persistent().get(&obligation_key)
     }
 
+    /// Get the linked chioma contract, if any.
+    pub fn get_chioma_contract(env: Env) -> Option<Address> {
+        env.storage().persistent().get(&DataKey::ChiomaContract)
+    }
+
+    /// Look up the lease terms behind an obligation on the chioma contract.
+    ///
+    /// # Arguments
+    /// * `agreement_id` - Agreement identifier for the obligation
+    ///
+    /// # Returns
+    /// `(landlord, tenant, monthly_rent, security_deposit, start_date, end_date)`,
+    /// or None if there is no obligation, no linked chioma contract, or no such
+    /// agreement there
+    pub fn get_underlying_agreement(
+        env: Env,
+        agreement_id: String,
+    ) -> Option<(Address, Address, i128, i128, u64, u64)> {
+        if !Self::has_obligation(env.clone(), agreement_id.clone()) {
+            return None;
+        }
+        let chioma = Self::get_chioma_contract(env.clone())?;
+
+        // The agreement struct lives in another crate, so read it as a field map
+        let agreement: Option<Map<Symbol, Val>> = env.invoke_contract(
+            &chioma,
+            &Symbol::new(&env, "get_agreement"),
+            vec![&env, agreement_id.into_val(&env)],
+        );
+        let agreement = agreement?;
+        let field = |name: &str| agreement.get(Symbol::new(&env, name));
+
+        Some((
+            field("landlord")?.into_val(&env),
+            field("tenant")?.into_val(&env),
+            field("monthly_rent")?.into_val(&env),
+            field("security_deposit")?.into_val(&env),
+            field("start_date")?.into_val(&env),
+            field("end_date")?.into_val(&env),
+        ))
+    }
+
     /// Check if an obligation exists for a given agreement.
     ///
     /// # Arguments
@@ -211,6 +453,116 @@ impl TokenizedRentObligationContract {
             .unwrap_or(0)
     }
 
+    /// List obligations in mint order.
+    ///
+    /// Pages over mint slots `start..start + limit`; slots whose obligation
+    /// has since been burned are skipped, so a page may hold fewer than `limit`.
+    ///
+    /// # Arguments
+    /// * `start` - First mint slot to read
+    /// * `limit` - Maximum number of slots to read
+    pub fn list_obligations(env: Env, start: u32, limit: u32) -> Vec<RentObligation> {
+        let total: u32 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::IndexedCount)
+            .unwrap_or(0);
+        let end = start.saturating_add(limit).min(total);
+
+        let mut obligations = Vec::new(&env);
+        for i in start..end {
+            let agreement_id: String =
+                match env.storage().persistent().get(&DataKey::ObligationIndex(i)) {
+                    Some(id) => id,
+                    None => continue,
+                };
+            if let Some(obligation) = env
+                .storage()
+                .persistent()
+                .get(&DataKey::Obligation(agreement_id))
+            {
+                obligations.push_back(obligation);
+            }
+        }
+        obligations
+    }
+
+    /// Record the rent schedule backing an obligation.
+    ///
+    /// # Arguments
+    /// * `agreement_id` - Agreement identifier for the obligation
+    /// * `monthly_rent` - Rent due each period
+    /// * `remaining_payments` - Number of payments still outstanding
+    ///
+    /// # Errors
+    /// * `NotInitialized` - If contract hasn't been initialized
+    /// * `ObligationNotFound` - If the obligation doesn't exist
+    /// * `InvalidAmount` - If `monthly_rent` is negative
+    pub fn set_rent_schedule(
+        env: Env,
+        agreement_id: String,
+        monthly_rent: i128,
+        remaining_payments: u32,
+    ) -> Result<(), ObligationError> {
+        if !env.storage().persistent().has(&DataKey::Initialized) {
+            return Err(ObligationError::NotInitialized);
+        }
+
+        if monthly_rent < 0 {
+            return Err(ObligationError::InvalidAmount);
+        }
+
+        let obligation_key = DataKey::Obligation(agreement_id);
+        let mut obligation: RentObligation = env
+            .storage()
+            .persistent()
+            .get(&obligation_key)
+            .ok_or(ObligationError::ObligationNotFound)?;
+
+        obligation.owner.require_auth();
+
+        obligation.monthly_rent = monthly_rent;
+        obligation.remaining_payments = remaining_payments;
+
+        env.storage().persistent().set(&obligation_key, &obligation);
+        env.storage()
+            .persistent()
+            .extend_ttl(&obligation_key, 500000, 500000);
+
+        Ok(())
+    }
+
+    /// Estimate the fair value of an obligation's remaining rent.
+    ///
+    /// Each remaining payment `k` (1-based) is discounted with simple discounting:
+    /// `monthly_rent * 10000 / (10000 + discount_bps * k)`.
+    ///
+    /// # Arguments
+    /// * `agreement_id` - Agreement identifier for the obligation
+    /// * `discount_bps` - Discount rate per period in basis points
+    ///
+    /// # Errors
+    /// * `ObligationNotFound` - If the obligation doesn't exist
+    pub fn estimate_value(
+        env: Env,
+        agreement_id: String,
+        discount_bps: u32,
+    ) -> Result<i128, ObligationError> {
+        let obligation: RentObligation = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Obligation(agreement_id))
+            .ok_or(ObligationError::ObligationNotFound)?;
+
+        let mut value: i128 = 0;
+        for k in 1..=obligation.remaining_payments {
+            let divisor = 10_000 + discount_bps as i128 * k as i128;
+            value += obligation.monthly_rent * 10_000 / divisor;
+        }
+
+        Ok(value)
+    }
+
     /// Burn a tokenized rent obligation NFT.
     ///
     /// # Arguments
@@ -231,18 +583,18 @@ impl TokenizedRentObligationContract {
             return Err(ObligationError::InvalidBurnReason);
         }
 
-        let obligation_key = DataKey::Obligation(token_id.clone());
-        let owner_key = DataKey::Owner(token_id.clone());
-        let burn_record_key = DataKey::BurnRecord(token_id.clone());
-
-        if env.storage().persistent().has(&burn_record_key) {
+        if env
+            .storage()
+            .persistent()
+            .has(&DataKey::BurnRecord(token_id.clone()))
+        {
             return Err(ObligationError::AlreadyBurned);
         }
 
         let obligation: RentObligation = env
             .storage()
             .persistent()
-            .get(&obligation_key)
+            .get(&DataKey::Obligation(token_id.clone()))
             .ok_or(ObligationError::ObligationNotFound)?;
 
         if env.ledger().timestamp() <= obligation.minted_at {
@@ -251,6 +603,78 @@ impl TokenizedRentObligationContract {
 
         obligation.owner.require_auth();
 
+        Self::burn_obligation(&env, token_id, obligation, reason);
+        Ok(())
+    }
+
+    /// Settle the obligation behind a completed lease by burning it.
+    ///
+    /// Rent is never custodied here: chioma disburses any escrowed rent when it
+    /// settles the agreement, so by completion nothing remains to pay out and
+    /// settlement only retires the token.
+    ///
+    /// # Arguments
+    /// * `agreement_id` - Agreement identifier for the obligation
+    ///
+    /// # Errors
+    /// * `NotInitialized` - If contract hasn't been initialized
+    /// * `ObligationNotFound` - If the obligation doesn't exist
+    /// * `ChiomaNotSet` - If no chioma contract is linked
+    /// * `CannotBurnActiveObligation` - If the agreement is missing or not `Completed`
+    pub fn settle_obligation(env: Env, agreement_id: String) -> Result<(), ObligationError> {
+        if !env.storage().persistent().has(&DataKey::Initialized) {
+            return Err(ObligationError::NotInitialized);
+        }
+
+        let obligation: RentObligation = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Obligation(agreement_id.clone()))
+            .ok_or(ObligationError::ObligationNotFound)?;
+        let chioma = Self::get_chioma_contract(env.clone()).ok_or(ObligationError::ChiomaNotSet)?;
+
+        let agreement: Option<Map<Symbol, Val>> = env.invoke_contract(
+            &chioma,
+            &Symbol::new(&env, "get_agreement"),
+            vec![&env, agreement_id.clone().into_val(&env)],
+        );
+        let status: Option<Val> = match agreement {
+            Some(agreement) => agreement.get(Symbol::new(&env, "status")),
+            // A closed agreement is deleted; chioma keeps its final status
+            None => env.invoke_contract(
+                &chioma,
+                &Symbol::new(&env, "get_closed_status"),
+                vec![&env, agreement_id.clone().into_val(&env)],
+            ),
+        };
+        // Unit enum variants arrive as a one-element vector holding the variant name
+        let status: Option<Symbol> = status
+            .and_then(|status| {
+                let status: Vec<Val> = status.into_val(&env);
+                status.get(0)
+            })
+            .map(|name| name.into_val(&env));
+        if status != Some(Symbol::new(&env, "Completed")) {
+            return Err(ObligationError::CannotBurnActiveObligation);
+        }
+
+        obligation.owner.require_auth();
+
+        Self::burn_obligation(
+            &env,
+            agreement_id,
+            obligation,
+            String::from_str(&env, "LeaseCompleted"),
+        );
+        Ok(())
+    }
+
+    /// Record the burn, drop the obligation and emit the burn event.
+    fn burn_obligation(env: &Env, token_id: String, obligation: RentObligation, reason: String) {
+        let obligation_key = DataKey::Obligation(token_id.clone());
+        let owner_key = DataKey::Owner(token_id.clone());
+        let burn_record_key = DataKey::BurnRecord(token_id.clone());
+
         let burn_record = BurnRecord {
             token_id: token_id.clone(),
             burned_by: obligation.owner.clone(),
@@ -270,7 +694,7 @@ impl TokenizedRentObligationContract {
             .storage()
             .persistent()
             .get(&burned_nfts_key)
-            .unwrap_or_else(|| Vec::new(&env));
+            .unwrap_or_else(|| Vec::new(env));
         burned_nfts.push_back(token_id.clone());
         env.storage()
             .persistent()
@@ -281,6 +705,9 @@ impl TokenizedRentObligationContract {
 
         env.storage().persistent().remove(&obligation_key);
         env.storage().persistent().remove(&owner_key);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Approval(token_id.clone()));
 
         let mut count: u32 = env
             .storage()
@@ -295,9 +722,7 @@ impl TokenizedRentObligationContract {
             .persistent()
             .extend_ttl(&DataKey::ObligationCount, 500000, 500000);
 
-        events::nft_burned(&env, token_id, obligation.owner, burn_record.reason);
-
-        Ok(())
+        events::nft_burned(env, token_id, obligation.owner, burn_record.reason);
     }
 
     /// Check if an NFT can be burned.
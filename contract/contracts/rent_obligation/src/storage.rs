@@ -10,4 +10,11 @@ pub enum DataKey {
     BurnRecord(String),
     BurnedNfts(String),
     BurnCount,
+    Name,
+    Symbol,
+    BaseUri,
+    ObligationIndex(u32),
+    IndexedCount,
+    Approval(String),
+    ChiomaContract,
 }
@@ -14,7 +14,7 @@ fn test_successful_initialization() {
     let env = Env::default();
     let client = create_contract(&env);
 
-    let result = client.try_initialize();
+    let result = client.try_initialize(&None);
     assert!(result.is_ok());
 
     let count = client.get_obligation_count();
@@ -27,8 +27,8 @@ fn test_double_initialization_fails() {
     let env = Env::default();
     let client = create_contract(&env);
 
-    client.initialize();
-    client.initialize();
+    client.initialize(&None);
+    client.initialize(&None);
 }
 
 #[test]
@@ -37,7 +37,7 @@ fn test_mint_obligation() {
     env.mock_all_auths();
 
     let client = create_contract(&env);
-    client.initialize();
+    client.initialize(&None);
 
     let landlord = Address::generate(&env);
     let agreement_id = String::from_str(&env, "agreement_001");
@@ -68,7 +68,7 @@ fn test_mint_obligation_requires_auth() {
     let env = Env::default();
 
     let client = create_contract(&env);
-    client.initialize();
+    client.initialize(&None);
 
     let landlord = Address::generate(&env);
     let agreement_id = String::from_str(&env, "agreement_001");
@@ -83,7 +83,7 @@ fn test_mint_duplicate_obligation_fails() {
     env.mock_all_auths();
 
     let client = create_contract(&env);
-    client.initialize();
+    client.initialize(&None);
 
     let landlord = Address::generate(&env);
     let agreement_id = String::from_str(&env, "agreement_001");
@@ -112,7 +112,7 @@ fn test_transfer_obligation() {
     env.mock_all_auths();
 
     let client = create_contract(&env);
-    client.initialize();
+    client.initialize(&None);
 
     let landlord = Address::generate(&env);
     let new_owner = Address::generate(&env);
@@ -132,13 +132,58 @@ fn test_transfer_obligation() {
     assert_eq!(obligation.owner, new_owner);
 }
 
+#[test]
+fn test_transfer_rejected_during_lockup() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+
+    let client = create_contract(&env);
+    client.initialize(&None);
+
+    let landlord = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let agreement_id = String::from_str(&env, "agreement_001");
+
+    client.mint_locked_obligation(&agreement_id, &landlord, &5_000);
+    assert_eq!(
+        client.get_obligation(&agreement_id).unwrap().lockup_until,
+        5_000
+    );
+
+    env.ledger().with_mut(|li| li.timestamp = 4_999);
+    let result = client.try_transfer_obligation(&landlord, &buyer, &agreement_id);
+    assert_eq!(result, Err(Ok(ObligationError::Locked)));
+    assert_eq!(client.get_obligation_owner(&agreement_id), Some(landlord));
+}
+
+#[test]
+fn test_transfer_allowed_once_lockup_passes() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+
+    let client = create_contract(&env);
+    client.initialize(&None);
+
+    let landlord = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let agreement_id = String::from_str(&env, "agreement_001");
+
+    client.mint_locked_obligation(&agreement_id, &landlord, &5_000);
+
+    env.ledger().with_mut(|li| li.timestamp = 5_000);
+    client.transfer_obligation(&landlord, &buyer, &agreement_id);
+    assert_eq!(client.get_obligation_owner(&agreement_id), Some(buyer));
+}
+
 #[test]
 #[should_panic]
 fn test_transfer_obligation_requires_auth() {
     let env = Env::default();
 
     let client = create_contract(&env);
-    client.initialize();
+    client.initialize(&None);
 
     let landlord = Address::generate(&env);
     let new_owner = Address::generate(&env);
@@ -166,7 +211,7 @@ fn test_transfer_nonexistent_obligation_fails() {
     env.mock_all_auths();
 
     let client = create_contract(&env);
-    client.initialize();
+    client.initialize(&None);
 
     let landlord = Address::generate(&env);
     let new_owner = Address::generate(&env);
@@ -182,7 +227,7 @@ fn test_transfer_from_non_owner_fails() {
     env.mock_all_auths();
 
     let client = create_contract(&env);
-    client.initialize();
+    client.initialize(&None);
 
     let landlord = Address::generate(&env);
     let fake_owner = Address::generate(&env);
@@ -200,7 +245,7 @@ fn test_multiple_obligations() {
     env.mock_all_auths();
 
     let client = create_contract(&env);
-    client.initialize();
+    client.initialize(&None);
 
     let landlord1 = Address::generate(&env);
     let landlord2 = Address::generate(&env);
@@ -225,7 +270,7 @@ fn test_multiple_obligations() {
 fn test_get_nonexistent_obligation() {
     let env = Env::default();
     let client = create_contract(&env);
-    client.initialize();
+    client.initialize(&None);
 
     let agreement_id = String::from_str(&env, "nonexistent");
 
@@ -245,7 +290,7 @@ fn test_transfer_chain() {
     env.mock_all_auths();
 
     let client = create_contract(&env);
-    client.initialize();
+    client.initialize(&None);
 
     let landlord = Address::generate(&env);
     let buyer1 = Address::generate(&env);
@@ -286,7 +331,7 @@ fn test_events_emitted() {
     env.mock_all_auths();
 
     let client = create_contract(&env);
-    client.initialize();
+    client.initialize(&None);
 
     let landlord = Address::generate(&env);
     let new_owner = Address::generate(&env);
@@ -305,7 +350,7 @@ fn test_nft_burn_by_owner() {
     env.mock_all_auths();
 
     let client = create_contract(&env);
-    client.initialize();
+    client.initialize(&None);
 
     let landlord = Address::generate(&env);
     let agreement_id = String::from_str(&env, "agreement_001");
@@ -331,7 +376,7 @@ fn test_nft_burn_already_burned_fails() {
     env.mock_all_auths();
 
     let client = create_contract(&env);
-    client.initialize();
+    client.initialize(&None);
 
     let landlord = Address::generate(&env);
     let agreement_id = String::from_str(&env, "agreement_001");
@@ -352,7 +397,7 @@ fn test_nft_burn_record_not_found() {
     let env = Env::default();
 
     let client = create_contract(&env);
-    client.initialize();
+    client.initialize(&None);
 
     let agreement_id = String::from_str(&env, "nonexistent");
 
@@ -366,7 +411,7 @@ fn test_nft_burn_nonexistent_fails() {
     env.mock_all_auths();
 
     let client = create_contract(&env);
-    client.initialize();
+    client.initialize(&None);
 
     let agreement_id = String::from_str(&env, "nonexistent");
 
@@ -379,7 +424,7 @@ fn test_nft_burn_requires_auth() {
     let env = Env::default();
 
     let client = create_contract(&env);
-    client.initialize();
+    client.initialize(&None);
 
     let landlord = Address::generate(&env);
     let agreement_id = String::from_str(&env, "agreement_001");
@@ -409,7 +454,7 @@ fn test_nft_burn_can_burn_after_lease_end() {
     env.mock_all_auths();
 
     let client = create_contract(&env);
-    client.initialize();
+    client.initialize(&None);
 
     let landlord = Address::generate(&env);
     let agreement_id = String::from_str(&env, "agreement_001");
@@ -430,7 +475,7 @@ fn test_nft_burn_can_burn_nonexistent_fails() {
     let env = Env::default();
 
     let client = create_contract(&env);
-    client.initialize();
+    client.initialize(&None);
 
     let agreement_id = String::from_str(&env, "nonexistent");
 
@@ -444,7 +489,7 @@ fn test_nft_burn_can_burn_already_burned_fails() {
     env.mock_all_auths();
 
     let client = create_contract(&env);
-    client.initialize();
+    client.initialize(&None);
 
     let landlord = Address::generate(&env);
     let agreement_id = String::from_str(&env, "agreement_001");
@@ -465,7 +510,7 @@ fn test_nft_burn_with_allowed_reasons() {
     env.mock_all_auths();
 
     let client = create_contract(&env);
-    client.initialize();
+    client.initialize(&None);
 
     let landlord = Address::generate(&env);
 
@@ -523,7 +568,7 @@ fn test_nft_burn_events_emitted() {
     env.mock_all_auths();
 
     let client = create_contract(&env);
-    client.initialize();
+    client.initialize(&None);
 
     let landlord = Address::generate(&env);
     let agreement_id = String::from_str(&env, "agreement_001");
@@ -544,7 +589,7 @@ fn test_nft_burn_history_tracking() {
     env.mock_all_auths();
 
     let client = create_contract(&env);
-    client.initialize();
+    client.initialize(&None);
 
     let landlord = Address::generate(&env);
     let agreement_id = String::from_str(&env, "agreement_001");
@@ -567,7 +612,7 @@ fn test_nft_burn_cannot_burn_active_obligation() {
     env.mock_all_auths();
 
     let client = create_contract(&env);
-    client.initialize();
+    client.initialize(&None);
 
     let landlord = Address::generate(&env);
     let agreement_id = String::from_str(&env, "agreement_001");
@@ -583,7 +628,7 @@ fn test_nft_burn_invalid_reason_fails() {
     env.mock_all_auths();
 
     let client = create_contract(&env);
-    client.initialize();
+    client.initialize(&None);
 
     let landlord = Address::generate(&env);
     let agreement_id = String::from_str(&env, "agreement_001");
@@ -601,7 +646,7 @@ fn test_burn_after_transfer_tracks_new_owner() {
     env.mock_all_auths();
 
     let client = create_contract(&env);
-    client.initialize();
+    client.initialize(&None);
 
     let landlord = Address::generate(&env);
     let new_owner = Address::generate(&env);
@@ -628,7 +673,7 @@ fn test_get_burned_nfts_returns_multiple_records_for_owner() {
     env.mock_all_auths();
 
     let client = create_contract(&env);
-    client.initialize();
+    client.initialize(&None);
 
     let landlord = Address::generate(&env);
     let agreement_one = String::from_str(&env, "agreement_burned_001");
@@ -654,3 +699,362 @@ fn test_get_burned_nfts_returns_multiple_records_for_owner() {
     assert_eq!(burned.get(0).unwrap(), agreement_one);
     assert_eq!(burned.get(1).unwrap(), agreement_two);
 }
+
+#[test]
+fn test_estimate_value_without_discount_equals_remaining_rent() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    client.initialize(&None);
+
+    let landlord = Address::generate(&env);
+    let agreement_id = String::from_str(&env, "agreement_value_001");
+
+    client.mint_obligation(&agreement_id, &landlord);
+    client.set_rent_schedule(&agreement_id, &1_000, &6);
+
+    assert_eq!(client.estimate_value(&agreement_id, &0), 6_000);
+}
+
+#[test]
+fn test_estimate_value_with_discount_is_less_than_remaining_rent() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    client.initialize(&None);
+
+    let landlord = Address::generate(&env);
+    let agreement_id = String::from_str(&env, "agreement_value_002");
+
+    client.mint_obligation(&agreement_id, &landlord);
+    client.set_rent_schedule(&agreement_id, &1_000, &6);
+
+    let value = client.estimate_value(&agreement_id, &100);
+    assert!(value < 6_000);
+    assert!(value > 0);
+
+    let missing = client.try_estimate_value(&String::from_str(&env, "missing"), &0);
+    assert_eq!(missing, Err(Ok(ObligationError::ObligationNotFound)));
+}
+
+#[test]
+fn test_name_and_symbol_after_initialize() {
+    let env = Env::default();
+    let client = create_contract(&env);
+
+    client.initialize(&None);
+
+    assert_eq!(
+        client.name(),
+        String::from_str(&env, "Chioma Rent Obligation")
+    );
+    assert_eq!(client.symbol(), String::from_str(&env, "CRO"));
+}
+
+#[test]
+fn test_token_uri_for_minted_obligation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    client.initialize(&None);
+
+    let landlord = Address::generate(&env);
+    let agreement_id = String::from_str(&env, "agreement_001");
+    client.mint_obligation(&agreement_id, &landlord);
+
+    assert_eq!(
+        client.token_uri(&agreement_id),
+        String::from_str(&env, "chioma://obligation/agreement_001")
+    );
+    assert_eq!(
+        client.try_token_uri(&String::from_str(&env, "missing")),
+        Err(Ok(ObligationError::ObligationNotFound))
+    );
+}
+
+#[test]
+fn test_list_obligations_skips_burned_entries() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    client.initialize(&None);
+
+    let landlord = Address::generate(&env);
+    let first = String::from_str(&env, "agreement_list_1");
+    let second = String::from_str(&env, "agreement_list_2");
+    let third = String::from_str(&env, "agreement_list_3");
+
+    client.mint_obligation(&first, &landlord);
+    client.mint_obligation(&second, &landlord);
+    client.mint_obligation(&third, &landlord);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = li.timestamp.saturating_add(1);
+    });
+    client.burn_nft(&second, &String::from_str(&env, "UserRequested"));
+
+    let all = client.list_obligations(&0, &10);
+    assert_eq!(all.len(), 2);
+    assert_eq!(all.get(0).unwrap().agreement_id, first);
+    assert_eq!(all.get(1).unwrap().agreement_id, third);
+
+    let first_page = client.list_obligations(&0, &1);
+    assert_eq!(first_page.len(), 1);
+    assert_eq!(first_page.get(0).unwrap().agreement_id, first);
+
+    let second_page = client.list_obligations(&1, &2);
+    assert_eq!(second_page.len(), 1);
+    assert_eq!(second_page.get(0).unwrap().agreement_id, third);
+
+    assert_eq!(client.list_obligations(&3, &5).len(), 0);
+}
+
+#[test]
+fn test_approved_spender_can_transfer() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    client.initialize(&None);
+
+    let landlord = Address::generate(&env);
+    let marketplace = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let agreement_id = String::from_str(&env, "agreement_approved");
+
+    client.mint_obligation(&agreement_id, &landlord);
+    client.approve(&landlord, &marketplace, &agreement_id);
+    assert_eq!(
+        client.get_approved(&agreement_id),
+        Some(marketplace.clone())
+    );
+
+    client.transfer_obligation(&marketplace, &buyer, &agreement_id);
+
+    assert_eq!(client.get_obligation_owner(&agreement_id), Some(buyer));
+    assert_eq!(client.get_approved(&agreement_id), None);
+
+    // The approval was consumed by the transfer
+    let result = client.try_transfer_obligation(&marketplace, &landlord, &agreement_id);
+    assert_eq!(result, Err(Ok(ObligationError::Unauthorized)));
+}
+
+#[test]
+fn test_unapproved_spender_cannot_transfer() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    client.initialize(&None);
+
+    let landlord = Address::generate(&env);
+    let marketplace = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    let agreement_id = String::from_str(&env, "agreement_unapproved");
+
+    client.mint_obligation(&agreement_id, &landlord);
+    client.approve(&landlord, &marketplace, &agreement_id);
+
+    let result = client.try_transfer_obligation(&stranger, &stranger, &agreement_id);
+    assert_eq!(result, Err(Ok(ObligationError::Unauthorized)));
+    assert_eq!(client.get_obligation_owner(&agreement_id), Some(landlord));
+}
+
+/// Stand-in for the chioma agreement status, encoded the same way.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MockStatus {
+    Active,
+    Completed,
+}
+
+/// Stand-in for the chioma agreement struct; only the fields read across
+/// contracts matter, plus the id to show others are ignored.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MockAgreement {
+    pub agreement_id: String,
+    pub landlord: Address,
+    pub tenant: Address,
+    pub monthly_rent: i128,
+    pub security_deposit: i128,
+    pub start_date: u64,
+    pub end_date: u64,
+    pub status: MockStatus,
+}
+
+#[soroban_sdk::contract]
+pub struct MockChioma;
+
+#[soroban_sdk::contractimpl]
+impl MockChioma {
+    pub fn add(env: Env, agreement: MockAgreement) {
+        env.storage()
+            .instance()
+            .set(&agreement.agreement_id.clone(), &agreement);
+    }
+
+    pub fn get_agreement(env: Env, agreement_id: String) -> Option<MockAgreement> {
+        env.storage().instance().get(&agreement_id)
+    }
+
+    pub fn complete(env: Env, agreement_id: String) {
+        let mut agreement: MockAgreement = env.storage().instance().get(&agreement_id).unwrap();
+        agreement.status = MockStatus::Completed;
+        env.storage().instance().set(&agreement_id, &agreement);
+    }
+
+    pub fn close(env: Env, agreement_id: String) {
+        let agreement: MockAgreement = env.storage().instance().get(&agreement_id).unwrap();
+        env.storage().instance().remove(&agreement_id);
+        env.storage().instance().set(
+            &(Symbol::new(&env, "closed"), agreement_id),
+            &agreement.status,
+        );
+    }
+
+    pub fn get_closed_status(env: Env, agreement_id: String) -> Option<MockStatus> {
+        env.storage()
+            .instance()
+            .get(&(Symbol::new(&env, "closed"), agreement_id))
+    }
+}
+
+fn setup_linked_chioma(
+    env: &Env,
+    client: &TokenizedRentObligationContractClient<'_>,
+) -> MockAgreement {
+    let chioma_id = env.register(MockChioma, ());
+    client.initialize(&Some(chioma_id.clone()));
+
+    let agreement = MockAgreement {
+        agreement_id: String::from_str(env, "agreement_1"),
+        landlord: Address::generate(env),
+        tenant: Address::generate(env),
+        monthly_rent: 1500,
+        security_deposit: 3000,
+        start_date: 100,
+        end_date: 31_536_100,
+        status: MockStatus::Active,
+    };
+    MockChiomaClient::new(env, &chioma_id).add(&agreement);
+    agreement
+}
+
+#[test]
+fn test_get_underlying_agreement_returns_terms() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let agreement = setup_linked_chioma(&env, &client);
+    client.mint_obligation(&agreement.agreement_id, &agreement.landlord);
+
+    assert_eq!(
+        client.get_underlying_agreement(&agreement.agreement_id),
+        Some((
+            agreement.landlord.clone(),
+            agreement.tenant.clone(),
+            1500,
+            3000,
+            100,
+            31_536_100
+        ))
+    );
+}
+
+#[test]
+fn test_get_underlying_agreement_unlinked_is_none() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let agreement = setup_linked_chioma(&env, &client);
+
+    // No obligation minted for the agreement
+    assert_eq!(
+        client.get_underlying_agreement(&agreement.agreement_id),
+        None
+    );
+
+    // Obligation minted, but chioma has no such agreement
+    let orphan = String::from_str(&env, "agreement_2");
+    client.mint_obligation(&orphan, &agreement.landlord);
+    assert_eq!(client.get_underlying_agreement(&orphan), None);
+}
+
+#[test]
+fn test_chioma_contract_linked_only_at_initialization() {
+    let env = Env::default();
+    let chioma = Address::generate(&env);
+
+    let linked = create_contract(&env);
+    linked.initialize(&Some(chioma.clone()));
+    assert_eq!(linked.get_chioma_contract(), Some(chioma));
+    assert_eq!(
+        linked.try_initialize(&Some(Address::generate(&env))),
+        Err(Ok(ObligationError::AlreadyInitialized))
+    );
+
+    let unlinked = create_contract(&env);
+    unlinked.initialize(&None);
+    assert_eq!(unlinked.get_chioma_contract(), None);
+}
+
+#[test]
+fn test_settle_obligation_burns_after_lease_completes() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let agreement = setup_linked_chioma(&env, &client);
+    client.mint_obligation(&agreement.agreement_id, &agreement.landlord);
+    assert_eq!(client.get_obligation_count(), 1);
+
+    MockChiomaClient::new(&env, &client.get_chioma_contract().unwrap())
+        .complete(&agreement.agreement_id);
+    client.settle_obligation(&agreement.agreement_id);
+
+    assert!(!client.has_obligation(&agreement.agreement_id));
+    assert_eq!(client.get_obligation_count(), 0);
+    let record = client.get_burn_record(&agreement.agreement_id);
+    assert_eq!(record.burned_by, agreement.landlord);
+    assert_eq!(record.reason, String::from_str(&env, "LeaseCompleted"));
+}
+
+#[test]
+fn test_settle_obligation_after_agreement_closed() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let agreement = setup_linked_chioma(&env, &client);
+    client.mint_obligation(&agreement.agreement_id, &agreement.landlord);
+
+    let chioma = MockChiomaClient::new(&env, &client.get_chioma_contract().unwrap());
+    chioma.complete(&agreement.agreement_id);
+    chioma.close(&agreement.agreement_id);
+    client.settle_obligation(&agreement.agreement_id);
+
+    assert!(!client.has_obligation(&agreement.agreement_id));
+}
+
+#[test]
+fn test_settle_obligation_requires_completed_lease() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let agreement = setup_linked_chioma(&env, &client);
+    client.mint_obligation(&agreement.agreement_id, &agreement.landlord);
+
+    assert_eq!(
+        client.try_settle_obligation(&agreement.agreement_id),
+        Err(Ok(ObligationError::CannotBurnActiveObligation))
+    );
+    assert!(client.has_obligation(&agreement.agreement_id));
+}
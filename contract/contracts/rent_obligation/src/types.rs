@@ -6,6 +6,12 @@ pub struct RentObligation {
     pub agreement_id: String,
     pub owner: Address,
     pub minted_at: u64,
+    /// Rent due each period, set by the owner via `set_rent_schedule`.
+    pub monthly_rent: i128,
+    /// Number of rent payments still outstanding on the obligation.
+    pub remaining_payments: u32,
+    /// Timestamp before which the obligation cannot be transferred (0 = never locked).
+    pub lockup_until: u64,
 }
 
 #[contracttype]